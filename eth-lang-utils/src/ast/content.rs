@@ -0,0 +1,273 @@
+use serde::{
+    de::{self, value::StringDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use std::{cell::RefCell, fmt};
+
+//
+// A buffered AST node. Unlike serde_json::Value, objects keep their fields in a plain vector and strings are
+// moved rather than copied when the buffer is deserialized again, which keeps buffering every node cheap.
+//
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeContent {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Seq(Vec<NodeContent>),
+    Map(Vec<(String, NodeContent)>),
+}
+
+impl NodeContent {
+    pub fn get(&self, key: &str) -> Option<&NodeContent> {
+        match self {
+            NodeContent::Map(fields) => fields.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            NodeContent::String(string) => Some(string.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            NodeContent::I64(value) => Some(*value),
+            NodeContent::U64(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+//
+// Nested nodes are deserialized from a NodeContent which was already buffered by their parent. Buffering them
+// again would copy every subtree once per level of nesting, so a NodeContent deserializer hands itself over through
+// a thread-local slot when asked for this newtype name, and other deserializers are read as usual. The slot is only
+// filled for the duration of that one visit and is always empty again when the deserializer returns.
+//
+
+const NODE_CONTENT_NAME: &str = "$eth_lang_utils::NodeContent";
+
+thread_local! {
+    static BUFFERED_CONTENT: RefCell<Option<NodeContent>> = const { RefCell::new(None) };
+}
+
+impl<'de> Deserialize<'de> for NodeContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(NODE_CONTENT_NAME, NodeContentVisitor { buffered: true })
+    }
+}
+
+struct NodeContentVisitor {
+    buffered: bool,
+}
+
+impl<'de> Visitor<'de> for NodeContentVisitor {
+    type Value = NodeContent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(NodeContent::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(NodeContent::I64(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(NodeContent::U64(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(NodeContent::F64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(NodeContent::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(NodeContent::String(value))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        if self.buffered {
+            if let Some(content) = BUFFERED_CONTENT.with(|content| content.borrow_mut().take()) {
+                return Ok(content);
+            }
+        }
+
+        Ok(NodeContent::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(NodeContent::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        NodeContent::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(NodeContentVisitor { buffered: false })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(NodeContent::Seq(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some(field) = map.next_entry()? {
+            fields.push(field);
+        }
+
+        Ok(NodeContent::Map(fields))
+    }
+}
+
+impl<'de> Deserializer<'de> for NodeContent {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            NodeContent::Null => visitor.visit_unit(),
+            NodeContent::Bool(value) => visitor.visit_bool(value),
+            NodeContent::U64(value) => visitor.visit_u64(value),
+            NodeContent::I64(value) => visitor.visit_i64(value),
+            NodeContent::F64(value) => visitor.visit_f64(value),
+            NodeContent::String(value) => visitor.visit_string(value),
+
+            NodeContent::Seq(values) => {
+                let length = values.len();
+                let mut seq = NodeContentSeq { values: values.into_iter() };
+                let result = visitor.visit_seq(&mut seq)?;
+
+                if seq.values.len() != 0 {
+                    return Err(de::Error::invalid_length(length, &"fewer elements in array"));
+                }
+
+                Ok(result)
+            }
+
+            NodeContent::Map(fields) => visitor.visit_map(NodeContentMap { fields: fields.into_iter(), value: None }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            NodeContent::Null => visitor.visit_none(),
+            content => visitor.visit_some(content),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            NodeContent::String(variant) => visitor.visit_enum(IntoDeserializer::<Self::Error>::into_deserializer(variant)),
+            content => serde_json::Value::deserialize(content)?.deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        if name == NODE_CONTENT_NAME {
+            BUFFERED_CONTENT.with(|content| *content.borrow_mut() = Some(self));
+            let result = visitor.visit_unit();
+
+            if BUFFERED_CONTENT.with(|content| content.borrow_mut().take()).is_some() {
+                return Err(de::Error::custom("buffered node content was not taken by its visitor"));
+            }
+
+            return result;
+        }
+
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for NodeContent {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct NodeContentSeq {
+    values: std::vec::IntoIter<NodeContent>,
+}
+
+impl<'de> SeqAccess<'de> for NodeContentSeq {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        match self.values.next() {
+            Some(value) => seed.deserialize(value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct NodeContentMap {
+    fields: std::vec::IntoIter<(String, NodeContent)>,
+    value: Option<NodeContent>,
+}
+
+impl<'de> MapAccess<'de> for NodeContentMap {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        match self.fields.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(StringDeserializer::new(key)).map(Some)
+            }
+
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
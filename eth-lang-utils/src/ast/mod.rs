@@ -1,5 +1,12 @@
 
-use serde::{Deserialize, Serialize};
+mod content;
+mod node;
+mod source_location;
+
+pub use self::{content::*, node::*, source_location::*};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::{Cell, RefCell};
 
 pub type NodeID = i64;
 
@@ -46,19 +53,21 @@ pub enum NodeType {
     UnaryOperation,
     BinaryOperation,
     EventDefinition,
-    ErrorDefiniton,
+    ErrorDefinition,
     EmitStatement,
     PlaceholderStatement,
     TryStatement,
     RevertStatement,
     ForStatement,
     WhileStatement,
+    DoWhileStatement,
     ModifierDefinition,
     ModifierInvocation,
     EnumDefinition,
     EnumValue,
     StructDefinition,
     UserDefinedTypeName,
+    UserDefinedValueTypeDefinition,
     InlineAssembly,
     YulLiteral,
     YulTypedName,
@@ -70,4 +79,157 @@ pub enum NodeType {
     YulIdentifier,
     YulVariableDeclaration,
     YulBlock,
+    YulIf,
+    YulForLoop,
+    YulFunctionDefinition,
+    YulBreak,
+    YulContinue,
+    YulLeave,
+}
+
+//
+// Nodes which are not modeled yet keep their original JSON, so their subtree survives analysis and serialization
+//
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnhandledNode {
+    pub node_type: String,
+    pub src: Option<String>,
+    pub id: Option<NodeID>,
+    pub json: serde_json::Value,
+}
+
+impl UnhandledNode {
+    pub fn new(node_type: &str, src: Option<String>, id: Option<NodeID>) -> Self {
+        let mut json = serde_json::Map::new();
+        json.insert("nodeType".into(), node_type.into());
+
+        if let Some(src) = src.as_ref() {
+            json.insert("src".into(), src.as_str().into());
+        }
+
+        if let Some(id) = id {
+            json.insert("id".into(), id.into());
+        }
+
+        Self {
+            node_type: node_type.into(),
+            src,
+            id,
+            json: serde_json::Value::Object(json),
+        }
+    }
+}
+
+impl Serialize for UnhandledNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.json.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnhandledNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = serde_json::Value::deserialize(deserializer)?;
+
+        let node_type = match json.get("nodeType").and_then(serde_json::Value::as_str) {
+            Some(node_type) => node_type.to_string(),
+            None => return Err(D::Error::missing_field("nodeType")),
+        };
+
+        Ok(Self {
+            node_type,
+            src: json.get("src").and_then(serde_json::Value::as_str).map(str::to_string),
+            id: json.get("id").and_then(serde_json::Value::as_i64),
+            json,
+        })
+    }
+}
+
+//
+// The innermost tagged node which failed to deserialize on the current thread, so an error can be reported against
+// that node without deserializing its ancestors again
+//
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaggedNodeError {
+    pub node_type: Option<String>,
+    pub id: Option<NodeID>,
+    pub src: Option<String>,
+    pub message: String,
+}
+
+thread_local! {
+    static NEXT_TAGGED_NODE: Cell<u64> = const { Cell::new(0) };
+    static TAGGED_NODE_ERROR: RefCell<Option<(u64, TaggedNodeError)>> = const { RefCell::new(None) };
+}
+
+pub fn take_tagged_node_error() -> Option<TaggedNodeError> {
+    TAGGED_NODE_ERROR.with(|slot| slot.borrow_mut().take()).map(|(_, error)| error)
+}
+
+//
+// Nodes are numbered in the order they are entered, so a recorded failure with a higher number and the same message
+// happened inside the failing node and is kept. Anything else was recovered from elsewhere and is replaced.
+//
+
+fn record_tagged_node_error(sequence: u64, error: TaggedNodeError) {
+    TAGGED_NODE_ERROR.with(|slot| {
+        let mut slot = slot.borrow_mut();
+
+        if !matches!(slot.as_ref(), Some((recorded, recorded_error)) if *recorded > sequence && recorded_error.message == error.message) {
+            *slot = Some((sequence, error));
+        }
+    });
+}
+
+//
+// AST enums are deserialized by buffering each node once and dispatching on its node type,
+// instead of trying every variant in order like untagged enums do
+//
+
+pub fn deserialize_tagged<'de, D, T, F>(deserializer: D, dispatch: F) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: FnOnce(Option<&str>, NodeContent) -> Result<T, serde_json::Error>,
+{
+    let content = NodeContent::deserialize(deserializer)?;
+    let node_type = content.get("nodeType").and_then(NodeContent::as_str).map(str::to_string);
+    let id = content.get("id").and_then(NodeContent::as_i64);
+    let src = content.get("src").and_then(NodeContent::as_str).map(str::to_string);
+
+    let sequence = NEXT_TAGGED_NODE.with(|next| {
+        let sequence = next.get();
+        next.set(sequence + 1);
+        sequence
+    });
+
+    dispatch(node_type.as_deref(), content).map_err(|error| {
+        let message = error.to_string();
+        record_tagged_node_error(sequence, TaggedNodeError { node_type, id, src, message });
+        D::Error::custom(error)
+    })
+}
+
+pub fn unexpected_node_type(node_type: Option<&str>, expected: &str) -> serde_json::Error {
+    match node_type {
+        Some(node_type) => serde_json::Error::custom(format!("unexpected {} node in {}", node_type, expected)),
+        None => serde_json::Error::custom(format!("missing node type in {}", expected)),
+    }
+}
+
+#[derive(Serialize)]
+struct TaggedNode<'a, T> {
+    #[serde(rename = "nodeType")]
+    node_type: NodeType,
+
+    #[serde(flatten)]
+    node: &'a T,
+}
+
+//
+// Nodes without a node type field of their own are serialized with one, so they can be deserialized again
+//
+
+pub fn serialize_tagged<S: Serializer, T: Serialize>(serializer: S, node_type: NodeType, node: &T) -> Result<S::Ok, S::Error> {
+    TaggedNode { node_type, node }.serialize(serializer)
 }
@@ -0,0 +1,51 @@
+use super::*;
+
+//
+// Common access to any AST node, so generic tooling such as printing and querying can be written once for
+// both Solidity and Yul nodes. Enums of nodes implement it by forwarding to the node they contain.
+//
+
+pub trait AstNode {
+    fn id(&self) -> Option<NodeID>;
+    fn src(&self) -> Option<&str>;
+    fn node_type(&self) -> &str;
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![]
+    }
+
+    fn source_location(&self) -> Option<SourceLocation> {
+        self.src()?.parse().ok()
+    }
+
+    fn descendants(&self) -> Vec<&dyn AstNode> {
+        let mut descendants = vec![];
+        let mut stack = self.children();
+        stack.reverse();
+
+        while let Some(node) = stack.pop() {
+            descendants.push(node);
+            stack.extend(node.children().into_iter().rev());
+        }
+
+        descendants
+    }
+
+    fn find_descendant(&self, id: NodeID) -> Option<&dyn AstNode> {
+        self.descendants().into_iter().find(|node| node.id() == Some(id))
+    }
+}
+
+impl AstNode for UnhandledNode {
+    fn id(&self) -> Option<NodeID> {
+        self.id
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        self.node_type.as_str()
+    }
+}
@@ -0,0 +1,175 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, io, str::FromStr};
+
+//
+// A parsed `src` attribute in the `start:length:file_index` form, with offsets in bytes.
+// A file index of -1 (used for generated code) is stored as None.
+//
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceLocation {
+    pub start: usize,
+    pub length: usize,
+    pub file_index: Option<usize>,
+}
+
+impl SourceLocation {
+    pub fn end(&self) -> usize {
+        self.start + self.length
+    }
+
+    pub fn contains(&self, other: &SourceLocation) -> bool {
+        self.file_index == other.file_index && self.start <= other.start && other.end() <= self.end()
+    }
+}
+
+impl FromStr for SourceLocation {
+    type Err = io::Error;
+
+    fn from_str(src: &str) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("Invalid source location: `{}`", src));
+
+        let mut tokens = src.split(':');
+
+        let start = tokens.next().and_then(|token| token.parse().ok()).ok_or_else(invalid)?;
+
+        let length = match tokens.next() {
+            Some(token) => token.parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        let file_index = match tokens.next() {
+            Some(token) => match token.parse::<i64>().map_err(|_| invalid())? {
+                index if index < 0 => None,
+                index => Some(index as usize),
+            },
+            None => None,
+        };
+
+        if tokens.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(SourceLocation { start, length, file_index })
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.file_index {
+            Some(file_index) => write!(f, "{}:{}:{}", self.start, self.length, file_index),
+            None => write!(f, "{}:{}:-1", self.start, self.length),
+        }
+    }
+}
+
+impl Serialize for SourceLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceLocation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+//
+// A 1-based line and column. Columns count characters rather than bytes.
+//
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceRange {
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
+impl fmt::Display for SourceRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+//
+// The byte offset of the start of every line in a source file, so positions can be looked up
+// with a binary search instead of counting newlines from the start of the file every time.
+//
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineTable {
+    line_starts: Vec<usize>,
+    source_length: usize,
+}
+
+impl LineTable {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+
+        for (offset, byte) in source.bytes().enumerate() {
+            if byte == b'\n' {
+                line_starts.push(offset + 1);
+            }
+        }
+
+        LineTable {
+            line_starts,
+            source_length: source.len(),
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn line(&self, offset: usize) -> io::Result<usize> {
+        if offset > self.source_length {
+            return Err(out_of_bounds(offset));
+        }
+
+        Ok(match self.line_starts.binary_search(&offset) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        })
+    }
+
+    pub fn position(&self, source: &str, offset: usize) -> io::Result<SourcePosition> {
+        if offset > source.len() || !source.is_char_boundary(offset) {
+            return Err(out_of_bounds(offset));
+        }
+
+        let line = self.line(offset)?;
+        let line_start = self.line_starts[line - 1];
+
+        Ok(SourcePosition {
+            line,
+            column: source[line_start..offset].chars().count() + 1,
+        })
+    }
+
+    pub fn range(&self, source: &str, location: &SourceLocation) -> io::Result<SourceRange> {
+        Ok(SourceRange {
+            start: self.position(source, location.start)?,
+            end: self.position(source, location.end())?,
+        })
+    }
+}
+
+fn out_of_bounds(offset: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Source offset {} is out of bounds", offset),
+    )
+}
@@ -0,0 +1,62 @@
+use eth_lang_utils::ast::NodeContent;
+use serde::{de::Visitor, Deserialize, Deserializer};
+use std::fmt;
+
+//
+// Mirrors an AST node with a nested node, which is deserialized from the content already buffered by its parent
+//
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Node {
+    id: u64,
+    child: Option<Box<Child>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Child(NodeContent);
+
+impl<'de> Deserialize<'de> for Child {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NodeContent::deserialize(deserializer).map(Child)
+    }
+}
+
+struct RejectingVisitor;
+
+impl<'de> Visitor<'de> for RejectingVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("nothing")
+    }
+}
+
+fn map(fields: &[(&str, NodeContent)]) -> NodeContent {
+    NodeContent::Map(fields.iter().map(|(key, value)| (key.to_string(), value.clone())).collect())
+}
+
+#[test]
+fn nested_content_is_handed_over_to_the_child() {
+    let child = map(&[("id", NodeContent::U64(2)), ("name", NodeContent::String("x".into()))]);
+    let content = map(&[("id", NodeContent::U64(1)), ("child", child.clone())]);
+
+    let node = Node::deserialize(content).unwrap();
+
+    assert_eq!(node, Node { id: 1, child: Some(Box::new(Child(child))) });
+}
+
+#[test]
+fn null_is_read_as_null_after_a_failed_handoff() {
+    let content = map(&[("id", NodeContent::U64(3))]);
+    let result = content.deserialize_newtype_struct("$eth_lang_utils::NodeContent", RejectingVisitor);
+
+    assert!(result.is_err());
+    assert_eq!(serde_json::from_str::<NodeContent>("null").unwrap(), NodeContent::Null);
+    assert_eq!(NodeContent::deserialize(NodeContent::Null).unwrap(), NodeContent::Null);
+}
+
+#[test]
+fn null_child_is_read_as_none() {
+    let node = Node::deserialize(map(&[("id", NodeContent::U64(4)), ("child", NodeContent::Null)])).unwrap();
+    assert_eq!(node, Node { id: 4, child: None });
+}
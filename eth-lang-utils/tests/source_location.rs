@@ -0,0 +1,78 @@
+use eth_lang_utils::ast::{LineTable, SourceLocation, SourcePosition};
+
+fn position(line: usize, column: usize) -> SourcePosition {
+    SourcePosition { line, column }
+}
+
+#[test]
+fn source_locations_are_parsed() {
+    assert_eq!("12:34:5".parse::<SourceLocation>().unwrap(), SourceLocation { start: 12, length: 34, file_index: Some(5) });
+    assert_eq!("12:34".parse::<SourceLocation>().unwrap(), SourceLocation { start: 12, length: 34, file_index: None });
+    assert_eq!("12".parse::<SourceLocation>().unwrap(), SourceLocation { start: 12, length: 0, file_index: None });
+}
+
+#[test]
+fn a_negative_file_index_is_stored_as_none() {
+    let location: SourceLocation = "12:34:-1".parse().unwrap();
+
+    assert_eq!(location.file_index, None);
+    assert_eq!(location.to_string(), "12:34:-1");
+}
+
+#[test]
+fn malformed_source_locations_are_an_error() {
+    for src in ["", ":", "a:1:0", "1:b:0", "1:2:c", "-1:2:0", "1:-2:0", "1:2:3:4", "1:2:"] {
+        assert!(src.parse::<SourceLocation>().is_err(), "{:?} should not parse", src);
+    }
+}
+
+#[test]
+fn lines_start_after_each_newline() {
+    let source = "ab\ncd\n\nef";
+    let line_table = LineTable::new(source);
+
+    assert_eq!(line_table.line_count(), 4);
+
+    assert_eq!(line_table.line(0).unwrap(), 1);
+    assert_eq!(line_table.line(2).unwrap(), 1);
+    assert_eq!(line_table.line(3).unwrap(), 2);
+    assert_eq!(line_table.line(5).unwrap(), 2);
+    assert_eq!(line_table.line(6).unwrap(), 3);
+    assert_eq!(line_table.line(7).unwrap(), 4);
+    assert_eq!(line_table.line(source.len()).unwrap(), 4);
+
+    assert_eq!(line_table.position(source, 2).unwrap(), position(1, 3));
+    assert_eq!(line_table.position(source, 3).unwrap(), position(2, 1));
+    assert_eq!(line_table.position(source, 6).unwrap(), position(3, 1));
+    assert_eq!(line_table.position(source, source.len()).unwrap(), position(4, 3));
+}
+
+#[test]
+fn offsets_past_the_end_of_the_source_are_an_error() {
+    let source = "ab\ncd";
+    let line_table = LineTable::new(source);
+
+    assert!(line_table.line(source.len() + 1).is_err());
+    assert!(line_table.position(source, source.len() + 1).is_err());
+
+    let location = SourceLocation { start: 3, length: 3, file_index: Some(0) };
+    assert!(line_table.range(source, &location).is_err());
+}
+
+#[test]
+fn columns_count_characters_after_multibyte_characters() {
+    let source = "a\u{e9}\u{1f600}b\n\u{4e2d}c";
+    let line_table = LineTable::new(source);
+
+    let b_offset = source.find('b').unwrap();
+    let c_offset = source.find('c').unwrap();
+
+    assert_eq!(line_table.position(source, b_offset).unwrap(), position(1, 4));
+    assert_eq!(line_table.position(source, c_offset).unwrap(), position(2, 2));
+
+    //
+    // Offsets inside a multibyte character do not point at a column
+    //
+
+    assert!(line_table.position(source, 2).is_err());
+}
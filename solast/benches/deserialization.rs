@@ -0,0 +1,53 @@
+use solast::foundry;
+use std::{env, io, path::PathBuf, time::Instant};
+
+//
+// Measures how long it takes to deserialize a build-info file produced by solc through Hardhat or Foundry.
+// The file is supplied through the SOLAST_BUILD_INFO environment variable. Only the current deserializer is timed,
+// so comparing against an earlier version means running the benchmark on both checkouts.
+//
+
+const ITERATIONS: usize = 5;
+
+fn main() -> io::Result<()> {
+    let path = match env::var_os("SOLAST_BUILD_INFO").map(PathBuf::from) {
+        Some(path) if path.is_file() => path,
+
+        _ => {
+            println!("deserialization: skipped, set SOLAST_BUILD_INFO to the path of a Hardhat or Foundry build-info file");
+            return Ok(());
+        }
+    };
+
+    let data = std::fs::read(path.as_path())?;
+    let mut durations = vec![];
+    let mut source_count = 0;
+
+    for _ in 0..ITERATIONS {
+        let mut buffer = data.clone();
+        let start = Instant::now();
+
+        let build_info: foundry::BuildInfo = simd_json::from_slice(buffer.as_mut_slice())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))?;
+
+        durations.push(start.elapsed());
+        source_count = build_info.output.sources.len();
+    }
+
+    durations.sort();
+
+    let megabytes = data.len() as f64 / (1024.0 * 1024.0);
+    let median = durations[durations.len() / 2].as_secs_f64();
+
+    println!(
+        "deserialize {} ({:.1} MiB, {} sources): median {:.1} ms, min {:.1} ms, {:.1} MiB/s",
+        path.to_string_lossy(),
+        megabytes,
+        source_count,
+        median * 1000.0,
+        durations[0].as_secs_f64() * 1000.0,
+        megabytes / median
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use solidity::ast::StateMutability;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiEntryKind {
+    Function,
+    Constructor,
+    Receive,
+    Fallback,
+    Event,
+    Error,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AbiParameter {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub internal_type: Option<String>,
+    pub indexed: Option<bool>,
+    pub components: Option<Vec<AbiParameter>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AbiEntry {
+    #[serde(rename = "type", default = "AbiEntry::default_kind")]
+    pub kind: AbiEntryKind,
+    pub name: Option<String>,
+    pub inputs: Option<Vec<AbiParameter>>,
+    pub outputs: Option<Vec<AbiParameter>>,
+    pub state_mutability: Option<StateMutability>,
+    pub anonymous: Option<bool>,
+    pub constant: Option<bool>,
+    pub payable: Option<bool>,
+}
+
+impl AbiEntry {
+    fn default_kind() -> AbiEntryKind {
+        AbiEntryKind::Function
+    }
+}
@@ -10,14 +10,14 @@ pub struct AbiEncodingVisitor {
 impl AbiEncodingVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display,
     ) {
         println!(
             "\t{} contains the potential for hash collisions: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             expression,
         );
     }
@@ -6,13 +6,13 @@ impl AbstractContractsVisitor {
     fn print_message(
         &mut self,
         contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        definition_node: DefinitionNode,
         function_definition: &FunctionDefinition,
         source_line: usize,
     ) {
         println!(
             "\t{} is marked {} instead of marking `{}` as abstract",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, Some(contract_definition), definition_node),
             function_definition.visibility,
             contract_definition.name,
         );
@@ -41,9 +41,14 @@ impl AstVisitor for AbstractContractsVisitor {
         // If the constructor is marked internal and the contract is not abstract, print a message
         //
 
-        if let None | Some(false) = context.contract_definition.is_abstract {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        if let None | Some(false) = contract_definition.is_abstract {
             self.print_message(
-                context.contract_definition,
+                contract_definition,
                 context.definition_node,
                 context.function_definition,
                 context.current_source_unit.source_line(context.function_definition.src.as_str())?,
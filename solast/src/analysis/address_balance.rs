@@ -5,15 +5,15 @@ pub struct AddressBalanceVisitor;
 impl AddressBalanceVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display,
         external: bool,
     ) {
         println!(
             "\t{} contains `{}` usage, which can be optimized with assembly: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             expression,
             if external {
                 "assembly { bal := balance(addr); }"
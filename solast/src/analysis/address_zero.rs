@@ -5,16 +5,15 @@ pub struct AddressZeroVisitor;
 impl AddressZeroVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display,
     ) {
         println!(
-            "\t{} contains `{}` usage, which can be optimized with assembly: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            "\t{} contains `{}` usage, which can be optimized with assembly: `assembly {{ if iszero(addr) {{ ... }} }}`",
+            definition_node_location(source_line, contract_definition, definition_node),
             expression,
-            "assembly { if iszero(addr) { ... } }",
         );
     }
 }
@@ -42,13 +41,12 @@ impl AstVisitor for AddressZeroVisitor {
                                 name: type_name, ..
                             }),
                         ..
-                    }) if type_name == "address" => match &arguments[0] {
+                    }) if type_name == "address" => matches!(
+                        &arguments[0],
                         Expression::Literal(Literal {
                             value: Some(value), ..
-                        }) if value == "0" => true,
-
-                        _ => false,
-                    },
+                        }) if value == "0"
+                    ),
 
                     _ => false,
                 },
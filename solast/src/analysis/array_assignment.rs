@@ -5,8 +5,8 @@ pub struct ArrayAssignmentVisitor;
 impl ArrayAssignmentVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         index_access: &IndexAccess,
         operator: &str,
@@ -14,7 +14,7 @@ impl ArrayAssignmentVisitor {
     ) {
         println!(
             "\t{} contains an inefficient array assignment which can be optimized to `{} {}= {};`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             index_access,
             operator,
             expression,
@@ -42,6 +42,14 @@ impl AstVisitor for ArrayAssignmentVisitor {
             return Ok(());
         }
 
+        //
+        // Compound assignments are not available for user-defined operators
+        //
+
+        if binary_operation.function.is_some() {
+            return Ok(());
+        }
+
         let index_access2 = match binary_operation.left_expression.as_ref() {
             Expression::IndexAccess(index_access2) => index_access2,
             _ => return Ok(()),
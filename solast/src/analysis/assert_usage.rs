@@ -10,14 +10,14 @@ pub struct AssertUsageVisitor {
 impl AssertUsageVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display,
     ) {
         println!(
             "\t{} contains assert usage: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             expression,
         );
     }
@@ -30,8 +30,8 @@ impl AstVisitor for AssertUsageVisitor {
         //
 
         let definition_id = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) |
-            ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => *id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) |
+            DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => *id,
 
             _ => return Ok(())
         };
@@ -6,15 +6,15 @@ pub struct AssignmentComparisonsVisitor;
 impl AssignmentComparisonsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         message: String,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} contains {} that performs an assignment: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             message,
             expression
         );
@@ -85,6 +85,20 @@ impl AstVisitor for AssignmentComparisonsVisitor {
         Ok(())
     }
 
+    fn visit_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> {
+        if context.do_while_statement.condition.contains_operation("=") {
+            self.print_message(
+                context.contract_definition,
+                context.definition_node,
+                context.current_source_unit.source_line(context.do_while_statement.src.as_str())?,
+                "a do-while statement".to_string(),
+                &context.do_while_statement.condition
+            );
+        }
+
+        Ok(())
+    }
+
     fn visit_conditional<'a, 'b>(&mut self, context: &mut ConditionalContext<'a, 'b>) -> io::Result<()> {
         if context.conditional.condition.contains_operation("=") {
             self.print_message(
@@ -24,13 +24,13 @@ pub struct CheckEffectsInteractionsVisitor {
 
 impl CheckEffectsInteractionsVisitor {
     fn get_state_variable_id(
-        source_units: &[SourceUnit],
+        ast_index: &AstIndex,
         contract_definition: &ContractDefinition,
         function_info: &mut FunctionInfo,
         block_id: NodeID,
         referenced_declaration: NodeID
     ) -> Option<NodeID> {
-        if contract_definition.hierarchy_contains_state_variable(source_units, referenced_declaration) {
+        if ast_index.hierarchy_contains_state_variable(contract_definition, referenced_declaration) {
             Some(referenced_declaration)
         } else {
             let mut state_variable_id = None;
@@ -68,9 +68,9 @@ impl CheckEffectsInteractionsVisitor {
     }
 
     fn check_expression(
-        source_units: &[SourceUnit],
+        ast_index: &AstIndex,
         contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        definition_node: DefinitionNode,
         function_info: &mut FunctionInfo,
         block_id: NodeID,
         expression: &Expression,
@@ -79,7 +79,7 @@ impl CheckEffectsInteractionsVisitor {
         let mut makes_post_external_call_assignment = false;
         
         for id in expression.referenced_declarations() {
-            if !contract_definition.hierarchy_contains_state_variable(source_units, id) {
+            if !ast_index.hierarchy_contains_state_variable(contract_definition, id) {
                 continue;
             }
 
@@ -120,20 +120,20 @@ impl CheckEffectsInteractionsVisitor {
             let block_info = function_info.block_info.get_mut(&block_id).unwrap();
             block_info.makes_post_external_call_assignment = true;
 
-            Self::print_message(contract_definition, definition_node, source_line);
+            Self::print_message(Some(contract_definition), definition_node, source_line);
         }
 
         Ok(())
     }
 
     fn print_message(
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
     ) {
         println!(
             "\t{} ignores the Check-Effects-Interactions pattern",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
         );
     }
 }
@@ -148,7 +148,12 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
     }
 
     fn visit_function_definition<'a>(&mut self, context: &mut FunctionDefinitionContext<'a>) -> io::Result<()> {
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
 
         contract_info.function_info.entry(context.function_definition.id).or_insert_with(|| FunctionInfo {
             block_info: HashMap::new(),
@@ -158,7 +163,12 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
     }
 
     fn visit_modifier_definition<'a>(&mut self, context: &mut ModifierDefinitionContext<'a>) -> io::Result<()> {
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
 
         contract_info.function_info.entry(context.modifier_definition.id).or_insert_with(|| FunctionInfo {
             block_info: HashMap::new(),
@@ -169,12 +179,17 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
 
     fn visit_block<'a, 'b>(&mut self, context: &mut BlockContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
-            ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
+            DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
         let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
 
         function_info.block_info.entry(context.block.id).or_insert_with(|| BlockInfo {
@@ -193,12 +208,17 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         }
         
         let definition_id = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
-            ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
+            DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
         let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_id = context.blocks.last().unwrap().id;
         
@@ -221,8 +241,8 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
                 };
 
                 let state_variable_id = match Self::get_state_variable_id(
-                    context.source_units,
-                    context.contract_definition,
+                    context.ast_index,
+                    contract_definition,
                     function_info,
                     block_id,
                     referenced_declaration
@@ -233,7 +253,7 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
 
                 let block_info = function_info.block_info.get_mut(&block_id).unwrap();
 
-                let variable_bindings = block_info.variable_bindings.entry(state_variable_id).or_insert_with(Vec::new);
+                let variable_bindings = block_info.variable_bindings.entry(state_variable_id).or_default();
 
                 if !variable_bindings.contains(&local_variable_id) {
                     variable_bindings.push(local_variable_id);
@@ -264,8 +284,8 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
                     };
 
                     let state_variable_id = match Self::get_state_variable_id(
-                        context.source_units,
-                        context.contract_definition,
+                        context.ast_index,
+                        contract_definition,
                         function_info,
                         block_id,
                         referenced_declaration
@@ -276,7 +296,7 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
 
                     let block_info = function_info.block_info.get_mut(&block_id).unwrap();
 
-                    let variable_bindings = block_info.variable_bindings.entry(state_variable_id).or_insert_with(Vec::new);
+                    let variable_bindings = block_info.variable_bindings.entry(state_variable_id).or_default();
 
                     if !variable_bindings.contains(&local_variable_id) {
                         variable_bindings.push(local_variable_id);
@@ -296,12 +316,17 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         }
 
         let definition_id = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
-            ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
+            DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
         let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_info = function_info.block_info.get_mut(&context.blocks.last().unwrap().id).unwrap();
 
@@ -317,14 +342,11 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         // Check if the identifier references an external function
         //
 
-        for source_unit in context.source_units.iter() {
-            if let Some(FunctionDefinition {
-                visibility: Visibility::External,
-                ..
-            }) = source_unit.function_definition(context.identifier.referenced_declaration) {
-                block_info.makes_external_call = true;
-                break;
-            }
+        if let Some(FunctionDefinition {
+            visibility: Visibility::External,
+            ..
+        }) = context.ast_index.function_definition(context.identifier.referenced_declaration) {
+            block_info.makes_external_call = true;
         }
 
         Ok(())
@@ -336,12 +358,17 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         }
         
         let definition_id = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
-            ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. }) => id,
+            DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
         let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_info = function_info.block_info.get_mut(&context.blocks.last().unwrap().id).unwrap();
 
@@ -357,16 +384,11 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         // Check if the member access references an external function
         //
 
-        if let Some(id) = context.member_access.referenced_declaration {
-            for source_unit in context.source_units.iter() {
-                if let Some(FunctionDefinition {
-                    visibility: Visibility::External,
-                    ..
-                }) = source_unit.function_definition(id) {
-                    block_info.makes_external_call = true;
-                    break;
-                }
-            }
+        if let Some(FunctionDefinition {
+            visibility: Visibility::External,
+            ..
+        }) = context.member_access.referenced_declaration.and_then(|id| context.ast_index.function_definition(id)) {
+            block_info.makes_external_call = true;
         }
 
         Ok(())
@@ -374,13 +396,18 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
 
     fn visit_assignment<'a, 'b>(&mut self, context: &mut AssignmentContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            &ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
-            | &ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
+            | DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
-        let function_info = contract_info.function_info.get_mut(&definition_id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
+        let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_id = context.blocks.last().unwrap().id;
         let block_info = function_info.block_info.get(&block_id).unwrap();
 
@@ -419,8 +446,8 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         //
 
         Self::check_expression(
-            context.source_units,
-            context.contract_definition,
+            context.ast_index,
+            contract_definition,
             context.definition_node,
             function_info,
             block_id,
@@ -431,13 +458,18 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
     
     fn visit_unary_operation<'a, 'b>(&mut self, context: &mut UnaryOperationContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            &ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
-            | &ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
+            | DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
-        let function_info = contract_info.function_info.get_mut(&definition_id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
+        let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_id = context.blocks.last().unwrap().id;
         let block_info = function_info.block_info.get(&block_id).unwrap();
 
@@ -476,8 +508,8 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         //
         
         Self::check_expression(
-            context.source_units,
-            context.contract_definition,
+            context.ast_index,
+            contract_definition,
             context.definition_node,
             function_info,
             block_id,
@@ -492,13 +524,18 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         }
 
         let definition_id = match context.definition_node {
-            &ContractDefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
-            | &ContractDefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
+            DefinitionNode::FunctionDefinition(FunctionDefinition { id, .. })
+            | DefinitionNode::ModifierDefinition(ModifierDefinition { id, .. }) => id,
             _ => return Ok(())
         };
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
-        let function_info = contract_info.function_info.get_mut(&definition_id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
+        let function_info = contract_info.function_info.get_mut(definition_id).unwrap();
         let block_id = context.blocks.last().unwrap().id;
         let block_info = function_info.block_info.get(&block_id).unwrap();
 
@@ -548,8 +585,8 @@ impl AstVisitor for CheckEffectsInteractionsVisitor {
         };
 
         Self::check_expression(
-            context.source_units,
-            context.contract_definition,
+            context.ast_index,
+            contract_definition,
             context.definition_node,
             function_info,
             block_id,
@@ -6,13 +6,13 @@ pub struct DivideBeforeMultiplyVisitor;
 impl DivideBeforeMultiplyVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
     ) {
         println!(
             "\t{} performs a multiplication on the result of a division",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
         );
     }
 }
@@ -29,6 +29,14 @@ impl AstVisitor for DivideBeforeMultiplyVisitor {
             return Ok(())
         }
 
+        //
+        // User-defined operators do not necessarily perform arithmetic
+        //
+
+        if context.binary_operation.function.is_some() {
+            return Ok(())
+        }
+
         if let Expression::BinaryOperation(left_operation) = context.binary_operation.left_expression.as_ref() {
             if left_operation.contains_operation("/") {
                 self.print_message(
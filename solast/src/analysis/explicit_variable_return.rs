@@ -10,15 +10,15 @@ pub struct ExplicitVariableReturnVisitor{
 impl ExplicitVariableReturnVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         description: &str,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} returns {} explicitly: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             description,
             expression
         );
@@ -38,16 +38,14 @@ impl AstVisitor for ExplicitVariableReturnVisitor {
 
     fn visit_return<'a, 'b>(&mut self, context: &mut ReturnContext<'a, 'b>) -> io::Result<()> {
         match context.return_statement.expression.as_ref() {
-            Some(Expression::Identifier(identifier)) => {
-                if self.local_variable_ids.contains(&identifier.referenced_declaration) {
-                    self.print_message(
-                        context.contract_definition,
-                        context.definition_node,
-                        context.current_source_unit.source_line(context.return_statement.src.as_str())?,
-                        "a local variable",
-                        context.return_statement
-                    );
-                }
+            Some(Expression::Identifier(identifier)) if self.local_variable_ids.contains(&identifier.referenced_declaration) => {
+                self.print_message(
+                    context.contract_definition,
+                    context.definition_node,
+                    context.current_source_unit.source_line(context.return_statement.src.as_str())?,
+                    "a local variable",
+                    context.return_statement
+                );
             }
 
             Some(Expression::TupleExpression(tuple_expression)) => {
@@ -2,137 +2,83 @@ use eth_lang_utils::ast::*;
 use solidity::ast::*;
 use std::io;
 
-#[derive(Default)]
-pub struct ExternalCallsInLoopVisitor {
-    loop_ids: Vec<NodeID>,
-    function_calls: Vec<FunctionCall>,
-}
+pub struct ExternalCallsInLoopVisitor;
 
 impl ExternalCallsInLoopVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} makes an external call inside a loop: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             expression
         );
     }
-}
 
-impl AstVisitor for ExternalCallsInLoopVisitor {
-    fn visit_function_call<'a, 'b>(&mut self, context: &mut FunctionCallContext<'a, 'b>) -> io::Result<()> {
-        self.function_calls.push(context.function_call.clone());
-
-        Ok(())
-    }
-
-    fn leave_function_call<'a, 'b>(&mut self, context: &mut FunctionCallContext<'a, 'b>) -> io::Result<()> {
-        match self.function_calls.pop() {
-            Some(function_call) => {
-                if function_call.id != context.function_call.id {
-                    return Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid function call id stack"));
-                }
-            },
-
-            None => return Err(io::Error::new(io::ErrorKind::InvalidData, "Not currently in a function call"))
+    //
+    // Returns the call made through the referencing node when it calls an external function from inside a loop
+    //
+
+    fn external_call_in_loop<'a>(
+        ast_index: &AstIndex<'a>,
+        definition_node: DefinitionNode,
+        id: NodeID,
+        referenced_declaration: NodeID,
+    ) -> Option<&'a FunctionCall> {
+        match definition_node {
+            DefinitionNode::FunctionDefinition(_) |
+            DefinitionNode::ModifierDefinition(_) if ast_index.is_inside_loop(id) => (),
+            _ => return None
         }
 
-        Ok(())
-    }
-
-    fn visit_for_statement<'a, 'b>(&mut self, context: &mut ForStatementContext<'a, 'b>) -> io::Result<()> {
-        self.loop_ids.push(context.for_statement.id);
-
-        Ok(())
-    }
-
-    fn leave_for_statement<'a, 'b>(&mut self, context: &mut ForStatementContext<'a, 'b>) -> io::Result<()> {
-        match self.loop_ids.pop() {
-            Some(loop_id) => {
-                if loop_id != context.for_statement.id {
-                    return Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid loop id stack"));
-                }
-            }
-
-            None => return Err(io::Error::new(io::ErrorKind::InvalidData, "Not currently in a loop"))
+        match ast_index.function_definition(referenced_declaration)?.visibility {
+            Visibility::External => ast_index.enclosing_function_call(id),
+            _ => None
         }
-
-        Ok(())
-    }
-
-    fn visit_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> io::Result<()> {
-        self.loop_ids.push(context.while_statement.id);
-
-        Ok(())
-    }
-
-    fn leave_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> io::Result<()> {
-        match self.loop_ids.pop() {
-            Some(loop_id) => if loop_id != context.while_statement.id {
-                return Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid loop id stack"));
-            }
-
-            None => return Err(io::Error::new(io::ErrorKind::InvalidData, "Not currently in a loop"))
-        }
-
-        Ok(())
     }
+}
 
+impl AstVisitor for ExternalCallsInLoopVisitor {
     fn visit_identifier<'a, 'b>(&mut self, context: &mut IdentifierContext<'a, 'b>) -> io::Result<()> {
-        match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(_) |
-            ContractDefinitionNode::ModifierDefinition(_) if !self.loop_ids.is_empty() => (),
-            _ => return Ok(())
-        }
-
-        for source_unit in context.source_units.iter() {
-            let called_function_definition = match source_unit.function_definition(context.identifier.referenced_declaration) {
-                Some(function_definition) => function_definition,
-                None => continue,
-            };
-
-            if let Visibility::External = called_function_definition.visibility {
-                self.print_message(
-                    context.contract_definition,
-                    context.definition_node,
-                    context.current_source_unit.source_line(context.identifier.src.as_str())?,
-                    &self.function_calls.last().unwrap().clone(),
-                );
-                break;
-            }
+        if let Some(function_call) = Self::external_call_in_loop(
+            context.ast_index,
+            context.definition_node,
+            context.identifier.id,
+            context.identifier.referenced_declaration,
+        ) {
+            self.print_message(
+                context.contract_definition,
+                context.definition_node,
+                context.current_source_unit.source_line(context.identifier.src.as_str())?,
+                function_call,
+            );
         }
 
         Ok(())
     }
 
     fn visit_member_access<'a, 'b>(&mut self, context: &mut MemberAccessContext<'a, 'b>) -> io::Result<()> {
-        match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(_) |
-            ContractDefinitionNode::ModifierDefinition(_) if !self.loop_ids.is_empty() => (),
-            _ => return Ok(())
-        }
-
-        if let Some(referenced_declaration) = context.member_access.referenced_declaration {
-            for source_unit in context.source_units.iter() {
-                if let Some(function_definition) =
-                    source_unit.function_definition(referenced_declaration)
-                {
-                    if let Visibility::External = function_definition.visibility {
-                        self.print_message(
-                            context.contract_definition,
-                            context.definition_node,
-                            context.current_source_unit.source_line(context.member_access.src.as_str())?,
-                            &self.function_calls.last().unwrap().clone(),
-                        );
-                        break;
-                    }
-                }
-            }
+        let referenced_declaration = match context.member_access.referenced_declaration {
+            Some(referenced_declaration) => referenced_declaration,
+            None => return Ok(()),
+        };
+
+        if let Some(function_call) = Self::external_call_in_loop(
+            context.ast_index,
+            context.definition_node,
+            context.member_access.id,
+            referenced_declaration,
+        ) {
+            self.print_message(
+                context.contract_definition,
+                context.definition_node,
+                context.current_source_unit.source_line(context.member_access.src.as_str())?,
+                function_call,
+            );
         }
 
         Ok(())
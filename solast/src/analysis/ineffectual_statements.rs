@@ -6,15 +6,15 @@ pub struct IneffectualStatementsVisitor;
 impl IneffectualStatementsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         description: &str,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} contains an ineffectual {} statement: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             description,
             expression
         );
@@ -24,7 +24,7 @@ impl IneffectualStatementsVisitor {
 impl AstVisitor for IneffectualStatementsVisitor {
     fn visit_statement<'a, 'b>(&mut self, context: &mut StatementContext<'a, 'b>) -> io::Result<()> {
         let expression = match context.statement {
-            Statement::ExpressionStatement(ExpressionStatement { expression }) => expression,
+            Statement::ExpressionStatement(ExpressionStatement { expression, .. }) => expression,
             _ => return Ok(())
         };
     
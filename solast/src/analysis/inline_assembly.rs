@@ -1,3 +1,4 @@
+use eth_lang_utils::ast::*;
 use solidity::ast::*;
 use std::io;
 use yul::ast::*;
@@ -7,21 +8,46 @@ pub struct InlineAssemblyVisitor;
 impl InlineAssemblyVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         description: &str
     ) {
         println!(
             "\t{} contains {}",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             description
         );
     }
+
+    //
+    // Yul nodes only have a usable location when it comes from the compiler or the native parser. Nodes reparsed from
+    // legacy `operations` have none, so findings in them are reported at the assembly block instead.
+    //
+
+    fn yul_node_src<'a>(inline_assembly: &'a InlineAssembly, src: Option<&'a String>) -> &'a str {
+        let is_within_block = match (src.and_then(|src| src.parse::<SourceLocation>().ok()), inline_assembly.src.parse::<SourceLocation>()) {
+            (Some(location), Ok(inline_assembly_location)) => inline_assembly_location.contains(&location),
+            _ => false,
+        };
+
+        match src {
+            Some(src) if is_within_block => src.as_str(),
+            _ => inline_assembly.src.as_str(),
+        }
+    }
 }
 
 impl AstVisitor for InlineAssemblyVisitor {
     fn visit_inline_assembly<'a, 'b>(&mut self, context: &mut InlineAssemblyContext<'a, 'b>) -> io::Result<()> {
+        //
+        // Standalone Yul objects consist of nothing but assembly, so only the findings inside them are reported
+        //
+
+        if let Some(ContractKind::YulObject) = context.contract_definition.map(|contract_definition| &contract_definition.kind) {
+            return Ok(());
+        }
+
         self.print_message(
             context.contract_definition,
             context.definition_node,
@@ -33,6 +59,8 @@ impl AstVisitor for InlineAssemblyVisitor {
     }
 
     fn visit_yul_function_call<'a, 'b, 'c>(&mut self, context: &mut YulFunctionCallContext<'a, 'b, 'c>) -> io::Result<()> {
+        let src = Self::yul_node_src(context.inline_assembly, context.yul_function_call.src.as_ref());
+
         match context.yul_function_call.function_name.name.as_str() {
             "mload" => {
                 let value = match context.yul_function_call.arguments.first() {
@@ -58,7 +86,7 @@ impl AstVisitor for InlineAssemblyVisitor {
                     self.print_message(
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(context.inline_assembly.src.as_str())?,
+                        context.current_source_unit.source_line(src)?,
                         "inline assembly which loads the free memory pointer"
                     );
                 }
@@ -67,16 +95,17 @@ impl AstVisitor for InlineAssemblyVisitor {
             "calldatacopy" => {
                 let arguments = match context.yul_function_call.arguments.get(2) {
                     Some(YulExpression::YulFunctionCall(YulFunctionCall {
-                        function_name: YulIdentifier { name },
+                        function_name: YulIdentifier { name, .. },
                         arguments,
+                        ..
                     })) if name == "sub" => arguments,
 
                     _ => return Ok(())
                 };
 
-                match arguments.get(0) {
+                match arguments.first() {
                     Some(YulExpression::YulFunctionCall(YulFunctionCall {
-                        function_name: YulIdentifier { name },
+                        function_name: YulIdentifier { name, .. },
                         ..
                     })) if name == "calldatasize" => {}
 
@@ -106,7 +135,7 @@ impl AstVisitor for InlineAssemblyVisitor {
                     self.print_message(
                         context.contract_definition,
                         context.definition_node,
-                        context.current_source_unit.source_line(context.inline_assembly.src.as_str())?,
+                        context.current_source_unit.source_line(src)?,
                         "inline assembly which copies arbitrary function arguments"
                     );
                 }
@@ -6,53 +6,74 @@ pub struct InvalidUsingForDirectivesVisitor;
 impl InvalidUsingForDirectivesVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         using_for_directive: &UsingForDirective
     ) {
         println!(
             "\t{} contains an invalid using-for directive: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             using_for_directive,
         );
     }
-}
 
-impl AstVisitor for InvalidUsingForDirectivesVisitor {
-    fn visit_using_for_directive<'a>(&mut self, context: &mut UsingForDirectiveContext<'a>) -> io::Result<()> {
+    fn is_usable_function(
+        function_definition: &FunctionDefinition,
+        for_type_name: &TypeName,
+        for_contract_definition: Option<&ContractDefinition>,
+    ) -> bool {
         //
-        // Get the identifier of the contract definition associated with the used library
+        // Check to see if the parameter type matches the requested type
         //
 
-        let using_contract_id = match context.using_for_directive.library_name.referenced_declaration.as_ref() {
-            Some(&id) => id,
-            None => return Ok(())
+        let parameter_list = if function_definition.parameters.parameters.is_empty() {
+            &function_definition.return_parameters
+        } else {
+            &function_definition.parameters
+        };
+
+        let parameter_type_name = match parameter_list.parameters.first().map(|p| p.type_name.as_ref()) {
+            Some(Some(type_name)) => type_name,
+            _ => return false
         };
 
+        if parameter_type_name == for_type_name {
+            return true;
+        }
+
         //
-        // Attempt to retrieve the contract definition associated with the used library
+        // Check to see if the requested type inherits from the parameter type
         //
 
-        let using_contract_definition = {
-            let mut using_contract_definition = None;
-    
-            for source_unit in context.source_units.iter() {
-                if let Some(contract_definition) = source_unit.contract_definition(using_contract_id) {
-                    using_contract_definition = Some(contract_definition);
-                    break;
-                }
-            }
-    
-            if using_contract_definition.is_none() {
-                return Ok(())
-            }
-            
-            using_contract_definition.unwrap()
+        let parameter_type_id = match parameter_type_name {
+            TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) => referenced_declaration,
+            _ => return false
         };
 
+        if let Some(Some(linearized_base_contracts)) = for_contract_definition.map(|x| x.linearized_base_contracts.as_ref()) {
+            if linearized_base_contracts.contains(parameter_type_id) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_usable_operator(function_definition: &FunctionDefinition, for_type_name: &TypeName) -> bool {
         //
-        // Get the type name of the requested type to use the library for
+        // Functions bound to operators must be pure and only operate on the requested type
+        //
+
+        function_definition.state_mutability == StateMutability::Pure
+            && function_definition.parameters.parameters.iter().all(|parameter| parameter.type_name.as_ref() == Some(for_type_name))
+    }
+}
+
+impl AstVisitor for InvalidUsingForDirectivesVisitor {
+    fn visit_using_for_directive<'a>(&mut self, context: &mut UsingForDirectiveContext<'a>) -> io::Result<()> {
+        //
+        // Get the type name of the requested type to use the library or functions for
         //
 
         let for_type_name = match context.using_for_directive.type_name.as_ref() {
@@ -61,69 +82,77 @@ impl AstVisitor for InvalidUsingForDirectivesVisitor {
         };
 
         //
-        // Get the contract definition of the requested type to use the library for (if any)
+        // Get the contract definition of the requested type to use the library or functions for (if any)
         //
 
-        let mut for_contract_definition = None;
+        let for_contract_definition = match for_type_name {
+            &TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) => context.ast_index.contract_definition(referenced_declaration),
+            _ => None
+        };
 
-        if let &TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) = for_type_name {
-            for source_unit in context.source_units.iter() {
-                if let Some(contract_definition) = source_unit.contract_definition(referenced_declaration) {
-                    for_contract_definition = Some(contract_definition);
-                    break;
-                }
+        let usable = match context.using_for_directive.library_name.as_ref() {
+            Some(library_name) => {
+                //
+                // Get the identifier of the contract definition associated with the used library
+                //
+
+                let using_contract_id = match library_name.referenced_declaration.as_ref() {
+                    Some(&id) => id,
+                    None => return Ok(())
+                };
+
+                //
+                // Attempt to retrieve the contract definition associated with the used library
+                //
+
+                let using_contract_definition = match context.ast_index.contract_definition(using_contract_id) {
+                    Some(contract_definition) => contract_definition,
+                    None => return Ok(())
+                };
+
+                //
+                // Determine if the library contains any functions usable with the requested type
+                //
+
+                using_contract_definition
+                    .function_definitions()
+                    .iter()
+                    .any(|function_definition| Self::is_usable_function(function_definition, for_type_name, for_contract_definition))
             }
-        }
-
-        //
-        // Determine if the library contains any functions usable with the requested type
-        //
-
-        let mut usable_function_found = false;
 
-        for function_definition in using_contract_definition.function_definitions() {
-            //
-            // Check to see if the parameter type matches the requested type
-            //
+            None => {
+                //
+                // Determine if every listed function is usable with the requested type
+                //
 
-            let parameter_list = if function_definition.parameters.parameters.is_empty() {
-                &function_definition.return_parameters
-            } else {
-                &function_definition.parameters
-            };
+                let mut usable = true;
 
-            let parameter_type_name = match parameter_list.parameters.first().map(|p| p.type_name.as_ref()) {
-                Some(Some(type_name)) => type_name,
-                _ => continue
-            };
-
-            if parameter_type_name == for_type_name {
-                usable_function_found = true;
-                break;
-            }
+                for function in context.using_for_directive.function_list.iter() {
+                    let function_id = match function.identifier_path().referenced_declaration {
+                        Some(id) => id,
+                        None => continue
+                    };
 
-            //
-            // Check to see if the requested type inherits from the parameter type
-            //
+                    let function_definition = match context.ast_index.function_definition(function_id) {
+                        Some(function_definition) => function_definition,
+                        None => continue
+                    };
 
-            let parameter_type_id = match parameter_type_name {
-                TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) => referenced_declaration,
-                _ => continue
-            };
-
-            if let Some(Some(linearized_base_contracts)) = for_contract_definition.map(|x| x.linearized_base_contracts.as_ref()) {
-                if linearized_base_contracts.contains(parameter_type_id) {
-                    usable_function_found = true;
-                    break;
+                    usable &= match function {
+                        UsingForFunction::Operator { .. } => Self::is_usable_operator(function_definition, for_type_name),
+                        UsingForFunction::Function { .. } => Self::is_usable_function(function_definition, for_type_name, for_contract_definition),
+                    };
                 }
+
+                usable
             }
-        }
+        };
 
         //
-        // If the library does not contain any usable functions for the requested type, print a message
+        // If the library or function list does not contain any usable functions for the requested type, print a message
         //
 
-        if !usable_function_found {
+        if !usable {
             self.print_message(
                 context.contract_definition,
                 context.definition_node,
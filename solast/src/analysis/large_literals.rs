@@ -6,14 +6,14 @@ pub struct LargeLiteralsVisitor;
 impl LargeLiteralsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         literal: &Literal
     ) {
         println!(
             "\t{} contains a large literal, which may be difficult to read: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             literal
         );
     }
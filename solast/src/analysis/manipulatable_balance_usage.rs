@@ -12,14 +12,14 @@ pub struct ManipulatableBalanceUsageVisitor;
 impl ManipulatableBalanceUsageVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} contains manipulatable balance usage: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             expression
         );
     }
@@ -14,13 +14,13 @@ pub struct MissingReturnVisitor {
 impl MissingReturnVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize
     ) {
         println!(
             "\t{} is missing an explicit return statement",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
         );
     }
 }
@@ -71,7 +71,7 @@ impl AstVisitor for MissingReturnVisitor {
 
     fn visit_assignment<'a, 'b>(&mut self, context: &mut AssignmentContext<'a, 'b>) -> io::Result<()> {
         let function_definition = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(function_definition) => function_definition,
+            DefinitionNode::FunctionDefinition(function_definition) => function_definition,
             _ => return Ok(())
         };
 
@@ -91,7 +91,7 @@ impl AstVisitor for MissingReturnVisitor {
 
     fn visit_yul_assignment<'a, 'b, 'c>(&mut self, context: &mut YulAssignmentContext<'a, 'b, 'c>) -> io::Result<()> {
         let function_definition = match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(function_definition) => function_definition,
+            DefinitionNode::FunctionDefinition(function_definition) => function_definition,
             _ => return Ok(())
         };
 
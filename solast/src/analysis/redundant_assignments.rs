@@ -12,14 +12,14 @@ pub struct RedundantAssignmentsVisitor;
 impl RedundantAssignmentsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         assignment: &Assignment
     ) {
         println!(
             "\t{} contains a redundant assignment: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             assignment,
         );
     }
@@ -7,14 +7,14 @@ pub struct RedundantComparisonsVisitor;
 impl RedundantComparisonsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         binary_operation: &BinaryOperation
     ) {
         println!(
             "\t{} contains a redundant comparison: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             binary_operation
         );
     }
@@ -88,6 +88,10 @@ impl AstVisitor for RedundantComparisonsVisitor {
             _ => return Ok(())
         }
 
+        if context.binary_operation.function.is_some() {
+            return Ok(())
+        }
+
         if match (context.binary_operation.left_expression.as_ref(), context.binary_operation.right_expression.as_ref()) {
             (Expression::Literal(_), Expression::Literal(_)) => true,
             (_, Expression::Literal(_)) => self.is_right_literal_redundant(context),
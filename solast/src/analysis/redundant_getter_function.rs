@@ -7,13 +7,13 @@ impl RedundantGetterFunctionVisitor {
     fn print_message(
         &mut self,
         contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        definition_node: DefinitionNode,
         variable_declaration: &VariableDeclaration,
         source_line: usize,
     ) {
         println!(
             "\t{} is a redundant getter function for the {} `{}.{}` state variable",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, Some(contract_definition), definition_node),
             variable_declaration.visibility,
             contract_definition.name,
             variable_declaration.name,
@@ -23,6 +23,11 @@ impl RedundantGetterFunctionVisitor {
 
 impl AstVisitor for RedundantGetterFunctionVisitor {
     fn visit_function_definition<'a>(&mut self, context: &mut FunctionDefinitionContext<'a>) -> io::Result<()> {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(()),
+        };
+
         if context.function_definition.name.is_empty() || context.function_definition.body.is_none() {
             return Ok(());
         }
@@ -53,7 +58,7 @@ impl AstVisitor for RedundantGetterFunctionVisitor {
 
         let variable_declaration = match return_statement.expression.as_ref() {
             Some(Expression::Identifier(identifier)) => {
-                match context.contract_definition.variable_declaration(identifier.referenced_declaration) {
+                match contract_definition.variable_declaration(identifier.referenced_declaration) {
                     Some(variable_declaration) => variable_declaration,
                     None => return Ok(()),
                 }
@@ -69,7 +74,7 @@ impl AstVisitor for RedundantGetterFunctionVisitor {
         }
 
         self.print_message(
-            context.contract_definition,
+            contract_definition,
             context.definition_node,
             variable_declaration,
             context.current_source_unit.source_line(context.function_definition.src.as_str())?,
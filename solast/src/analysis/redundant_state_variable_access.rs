@@ -10,15 +10,15 @@ pub struct RedundantStateVariableAccessVisitor;
 
 impl RedundantStateVariableAccessVisitor {
     fn print_message(
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         message: &str,
         expression: &dyn std::fmt::Display
     ) {
         println!(
             "\t{} contains {} which redundantly accesses storage: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             message,
             expression
         );
@@ -27,6 +27,11 @@ impl RedundantStateVariableAccessVisitor {
 
 impl AstVisitor for RedundantStateVariableAccessVisitor {
     fn visit_for_statement<'a, 'b>(&mut self, context: &mut ForStatementContext<'a, 'b>) -> std::io::Result<()> {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
         //
         // Check if the for statement's condition directly references a state variable
         //
@@ -37,9 +42,9 @@ impl AstVisitor for RedundantStateVariableAccessVisitor {
         };
 
         for id in condition.referenced_declarations() {
-            if context.contract_definition.hierarchy_contains_state_variable(context.source_units, id) {
+            if contract_definition.hierarchy_contains_state_variable(context.source_units, id) {
                 Self::print_message(
-                    context.contract_definition,
+                    Some(contract_definition),
                     context.definition_node,
                     condition.source_line(context.current_source_unit)?,
                     "a for statement with a condition",
@@ -53,14 +58,19 @@ impl AstVisitor for RedundantStateVariableAccessVisitor {
     }
 
     fn visit_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> std::io::Result<()> {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
         //
         // Check if the while statement's condition directly references a state variable
         //
 
         for id in context.while_statement.condition.referenced_declarations() {
-            if context.contract_definition.hierarchy_contains_state_variable(context.source_units, id) {
+            if contract_definition.hierarchy_contains_state_variable(context.source_units, id) {
                 Self::print_message(
-                    context.contract_definition,
+                    Some(contract_definition),
                     context.definition_node,
                     context.while_statement.condition.source_line(context.current_source_unit)?,
                     "a while statement with a condition",
@@ -72,4 +82,30 @@ impl AstVisitor for RedundantStateVariableAccessVisitor {
 
         Ok(())
     }
+
+    fn visit_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> std::io::Result<()> {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        //
+        // Check if the do-while statement's condition directly references a state variable
+        //
+
+        for id in context.do_while_statement.condition.referenced_declarations() {
+            if contract_definition.hierarchy_contains_state_variable(context.source_units, id) {
+                Self::print_message(
+                    Some(contract_definition),
+                    context.definition_node,
+                    context.do_while_statement.condition.source_line(context.current_source_unit)?,
+                    "a do-while statement with a condition",
+                    &context.do_while_statement.condition
+                );
+                return Ok(())
+            }
+        }
+
+        Ok(())
+    }
 }
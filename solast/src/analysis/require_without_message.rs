@@ -6,14 +6,14 @@ pub struct RequireWithoutMessageVisitor;
 impl RequireWithoutMessageVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         function_call: &FunctionCall
     ) {
         println!(
             "\t{} contains a requirement without a message: `{}`",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             function_call
         );
     }
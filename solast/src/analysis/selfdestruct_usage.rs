@@ -5,13 +5,13 @@ pub struct SelfdestructUsageVisitor;
 impl SelfdestructUsageVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
     ) {
         println!(
             "\t{} contains `selfdestruct` usage",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
         );
     }
 }
@@ -23,7 +23,8 @@ impl AstVisitor for SelfdestructUsageVisitor {
                 expression,
                 src,
                 ..
-            })
+            }),
+            ..
         }) = context.statement {
             if let Expression::Identifier(Identifier { name, .. }) = expression.as_ref() {
                 if name == "selfdestruct" {
@@ -1,27 +1,54 @@
 use solidity::ast::*;
-use std::io;
+use std::{cell::Cell, collections::HashSet, io, rc::Rc};
+
+//
+// The first file flag is shared by every visitor printing file headers, so files are separated by exactly one blank
+// line even when they are visited by different sets of analyzers
+//
 
 pub struct SourceUnitVisitor {
-    first_file: bool,
+    first_file: Rc<Cell<bool>>,
+    conflicting_paths: HashSet<String>,
+    compilation_description: Option<String>,
 }
 
 impl Default for SourceUnitVisitor {
     fn default() -> Self {
         Self {
-            first_file: true,
+            first_file: Rc::new(Cell::new(true)),
+            conflicting_paths: HashSet::new(),
+            compilation_description: None,
+        }
+    }
+}
+
+impl SourceUnitVisitor {
+    pub fn new(first_file: Rc<Cell<bool>>, conflicting_paths: HashSet<String>, compilation_description: Option<String>) -> Self {
+        Self {
+            first_file,
+            conflicting_paths,
+            compilation_description,
         }
     }
 }
 
 impl AstVisitor for SourceUnitVisitor {
     fn visit_source_unit<'a>(&mut self, context: &mut SourceUnitContext<'a>) -> io::Result<()> {
-        if self.first_file {
-            self.first_file = false;
-        } else {
+        if !self.first_file.replace(false) {
             println!();
         }
 
-        println!("{}:", context.current_source_unit.absolute_path.as_deref().unwrap_or("<ABSOLUTE_PATH_NOT_SET/>"));
+        let absolute_path = context.current_source_unit.absolute_path.as_deref().unwrap_or("<ABSOLUTE_PATH_NOT_SET/>");
+
+        match self.compilation_description.as_ref() {
+            Some(compilation_description) if self.conflicting_paths.contains(absolute_path) => {
+                println!("{} ({}):", absolute_path, compilation_description);
+            }
+
+            _ => {
+                println!("{}:", absolute_path);
+            }
+        }
 
         Ok(())
     }
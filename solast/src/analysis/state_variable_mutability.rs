@@ -92,7 +92,7 @@ impl AstVisitor for StateVariableMutabilityVisitor {
         };
 
         match definition_node {
-            ContractDefinitionNode::VariableDeclaration(_) => {
+            DefinitionNode::VariableDeclaration(_) => {
                 contract_info.variable_info.entry(context.variable_declaration.id).or_insert_with(|| VariableInfo {
                     assigned: false,
                     constant: context.variable_declaration.value.is_some(),
@@ -101,7 +101,7 @@ impl AstVisitor for StateVariableMutabilityVisitor {
                 contract_info.variable_aliases.entry(context.variable_declaration.id).or_insert_with(HashSet::new);
             }
 
-            ContractDefinitionNode::FunctionDefinition(_) | ContractDefinitionNode::ModifierDefinition(_) => {
+            DefinitionNode::FunctionDefinition(_) | DefinitionNode::ModifierDefinition(_) => {
                 if let StorageLocation::Storage = context.variable_declaration.storage_location {
                     if let Some(value) = context.variable_declaration.value.as_ref() {
                         for id in value.referenced_declarations() {
@@ -122,14 +122,19 @@ impl AstVisitor for StateVariableMutabilityVisitor {
     }
 
     fn visit_assignment<'a, 'b>(&mut self, context: &mut AssignmentContext<'a, 'b>) -> io::Result<()> {
-        if let ContractDefinitionNode::FunctionDefinition(FunctionDefinition {
+        if let DefinitionNode::FunctionDefinition(FunctionDefinition {
             kind: FunctionKind::Constructor,
             ..
         }) = context.definition_node {
             return Ok(())
         }
 
-        let contract_info = match self.contract_info.get_mut(&context.contract_definition.id) {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = match self.contract_info.get_mut(&contract_definition.id) {
             Some(contract_info) => contract_info,
             None => return Ok(())
         };
@@ -144,7 +149,7 @@ impl AstVisitor for StateVariableMutabilityVisitor {
             }
         }
 
-        let ids = context.contract_definition.get_assigned_state_variables(
+        let ids = contract_definition.get_assigned_state_variables(
             context.source_units,
             context.definition_node,
             context.assignment.left_hand_side.as_ref(),
@@ -160,21 +165,26 @@ impl AstVisitor for StateVariableMutabilityVisitor {
     }
 
     fn visit_unary_operation<'a, 'b>(&mut self, context: &mut UnaryOperationContext<'a, 'b>) -> io::Result<()> {
-        if let ContractDefinitionNode::FunctionDefinition(FunctionDefinition {
+        if let DefinitionNode::FunctionDefinition(FunctionDefinition {
             kind: FunctionKind::Constructor,
             ..
         }) = context.definition_node {
             return Ok(())
         }
-        
-        let ids = context.contract_definition.get_assigned_state_variables(
+
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let ids = contract_definition.get_assigned_state_variables(
             context.source_units,
             context.definition_node,
             context.unary_operation.sub_expression.as_ref(),
         );
 
         for id in ids {
-            let contract_info = match self.contract_info.get_mut(&context.contract_definition.id) {
+            let contract_info = match self.contract_info.get_mut(&contract_definition.id) {
                 Some(contract_info) => contract_info,
                 None => continue
             };
@@ -190,21 +200,26 @@ impl AstVisitor for StateVariableMutabilityVisitor {
     fn visit_function_call<'a, 'b>(&mut self, context: &mut FunctionCallContext<'a, 'b>) -> io::Result<()> {
         if let Expression::MemberAccess(member_access) = context.function_call.expression.as_ref() {
             if member_access.referenced_declaration.is_none() && (member_access.member_name == "push" || member_access.member_name == "pop") {
-                if let ContractDefinitionNode::FunctionDefinition(FunctionDefinition {
+                if let DefinitionNode::FunctionDefinition(FunctionDefinition {
                     kind: FunctionKind::Constructor,
                     ..
                 }) = context.definition_node {
                     return Ok(())
                 }
+
+                let contract_definition = match context.contract_definition {
+                    Some(contract_definition) => contract_definition,
+                    None => return Ok(())
+                };
                 
-                let ids = context.contract_definition.get_assigned_state_variables(
+                let ids = contract_definition.get_assigned_state_variables(
                     context.source_units,
                     context.definition_node,
                     member_access.expression.as_ref(),
                 );
                 
                 for id in ids {
-                    let contract_info = match self.contract_info.get_mut(&context.contract_definition.id) {
+                    let contract_info = match self.contract_info.get_mut(&contract_definition.id) {
                         Some(contract_info) => contract_info,
                         None => continue
                     };
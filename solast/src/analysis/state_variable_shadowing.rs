@@ -5,22 +5,18 @@ pub struct StateVariableShadowingVisitor;
 
 impl AstVisitor for StateVariableShadowingVisitor {
     fn visit_function_definition<'a>(&mut self, context: &mut FunctionDefinitionContext<'a>) -> io::Result<()> {
-        let contract_ids = match context.contract_definition.linearized_base_contracts.as_ref() {
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(()),
+        };
+
+        let contract_ids = match contract_definition.linearized_base_contracts.as_ref() {
             Some(contract_ids) => contract_ids,
             None => return Ok(()),
         };
         
         for &base_contract_id in contract_ids.iter() {
-            let mut base_contract_definition = None;
-
-            for source_unit in context.source_units.iter() {
-                if let Some(contract_definition) = source_unit.contract_definition(base_contract_id) {
-                    base_contract_definition = Some(contract_definition);
-                    break;
-                }
-            }
-
-            if let Some(base_contract_definition) = base_contract_definition {
+            if let Some(base_contract_definition) = context.ast_index.contract_definition(base_contract_id) {
                 for variable_declaration in context.function_definition.parameters.parameters.iter() {
                     for base_variable_declaration in base_contract_definition.variable_declarations() {
                         if let solidity::ast::Visibility::Private = base_variable_declaration.visibility {
@@ -36,9 +32,9 @@ impl AstVisitor for StateVariableShadowingVisitor {
                                 context.function_definition.visibility,
 
                                 if context.function_definition.name.is_empty() {
-                                    context.contract_definition.name.to_string()
+                                    contract_definition.name.to_string()
                                 } else {
-                                    format!("{}.{}", context.contract_definition.name, context.function_definition.name)
+                                    format!("{}.{}", contract_definition.name, context.function_definition.name)
                                 },
 
                                 context.function_definition.kind,
@@ -94,10 +94,10 @@ impl AstVisitor for StorageArrayLoopVisitor {
 
                     context.function_definition.visibility,
 
-                    if context.function_definition.name.is_empty() {
-                        context.contract_definition.name.to_string()
-                    } else {
-                        format!("{}.{}", context.contract_definition.name, context.function_definition.name)
+                    match context.contract_definition {
+                        Some(contract_definition) if context.function_definition.name.is_empty() => contract_definition.name.to_string(),
+                        Some(contract_definition) => format!("{}.{}", contract_definition.name, context.function_definition.name),
+                        None => context.function_definition.name.to_string(),
                     },
 
                     context.function_definition.kind
@@ -127,8 +127,8 @@ impl AstVisitor for StorageArrayLoopVisitor {
 
     fn visit_for_statement<'a, 'b>(&mut self, context: &mut ForStatementContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            solidity::ast::ContractDefinitionNode::FunctionDefinition(definition) => definition.id,
-            solidity::ast::ContractDefinitionNode::ModifierDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::FunctionDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::ModifierDefinition(definition) => definition.id,
             _ => return Ok(())
         };
 
@@ -146,8 +146,8 @@ impl AstVisitor for StorageArrayLoopVisitor {
 
     fn visit_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            solidity::ast::ContractDefinitionNode::FunctionDefinition(definition) => definition.id,
-            solidity::ast::ContractDefinitionNode::ModifierDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::FunctionDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::ModifierDefinition(definition) => definition.id,
             _ => return Ok(())
         };
 
@@ -160,4 +160,21 @@ impl AstVisitor for StorageArrayLoopVisitor {
 
         Ok(())
     }
+
+    fn visit_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> {
+        let definition_id = match context.definition_node {
+            solidity::ast::DefinitionNode::FunctionDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::ModifierDefinition(definition) => definition.id,
+            _ => return Ok(())
+        };
+
+        if self.expression_contains_storage_array_length(&context.do_while_statement.condition) {
+            self.functions
+                .get_mut(&definition_id)
+                .unwrap()
+                .loops_over_storage_array = true;
+        }
+
+        Ok(())
+    }
 }
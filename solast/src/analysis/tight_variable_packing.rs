@@ -11,7 +11,7 @@ pub struct TightVariablePackingVisitor {
     storage_slots: HashMap<NodeID, Vec<StorageSlot>>
 }
 
-fn type_name_size(source_units: &[SourceUnit], type_name: &TypeName) -> std::io::Result<usize> {
+fn type_name_size(ast_index: &AstIndex, type_name: &TypeName) -> std::io::Result<usize> {
     Ok(match type_name {
         TypeName::ElementaryTypeName(ElementaryTypeName { name, .. }) => match name.as_str() {
             "bool" => 1,
@@ -66,32 +66,16 @@ fn type_name_size(source_units: &[SourceUnit], type_name: &TypeName) -> std::io:
         TypeName::UserDefinedTypeName(UserDefinedTypeName { referenced_declaration, .. }) => {
             let id = *referenced_declaration;
 
-            for source_unit in source_units.iter() {
-                if source_unit.enum_definition(id).is_some() {
-                    return Ok(1)
-                } else if let Some(struct_definition) = source_unit.struct_definition(id) {
-                    let mut size = 0;
-
-                    for member in struct_definition.members.iter() {
-                        size += type_name_size(source_units, member.type_name.as_ref().unwrap())?;
-                    }
-
-                    return Ok(size)
-                } else {
-                    for contract_definition in source_unit.contract_definitions() {
-                        if contract_definition.enum_definition(id).is_some() {
-                            return Ok(1)
-                        } else if let Some(struct_definition) = contract_definition.struct_definition(id) {
-                            let mut size = 0;
-        
-                            for member in struct_definition.members.iter() {
-                                size += type_name_size(source_units, member.type_name.as_ref().unwrap())?;
-                            }
-        
-                            return Ok(size)
-                        }
-                    }
+            if ast_index.enum_definition(id).is_some() {
+                return Ok(1)
+            } else if let Some(struct_definition) = ast_index.struct_definition(id) {
+                let mut size = 0;
+
+                for member in struct_definition.members.iter() {
+                    size += type_name_size(ast_index, member.type_name.as_ref().unwrap())?;
                 }
+
+                return Ok(size)
             }
 
             return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("User-defined type not found: {}", type_name)))
@@ -105,7 +89,7 @@ fn type_name_size(source_units: &[SourceUnit], type_name: &TypeName) -> std::io:
             } else {
                 value.parse()
             } {
-                return Ok(type_name_size(source_units, base_type.as_ref())? * length as usize)
+                return Ok(type_name_size(ast_index, base_type.as_ref())? * length as usize)
             }
 
             return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("Unhandled array type: {}", type_name)))
@@ -137,7 +121,7 @@ impl AstVisitor for TightVariablePackingVisitor {
 
             let current_slot_size: usize = current_slot.as_ref().unwrap().member_sizes.iter().sum();
 
-            let member_type_name_size = match type_name_size(context.source_units, member.type_name.as_ref().unwrap()) {
+            let member_type_name_size = match type_name_size(context.ast_index, member.type_name.as_ref().unwrap()) {
                 Ok(x) => x,
                 Err(_) => continue,
             };
@@ -198,7 +182,7 @@ impl AstVisitor for TightVariablePackingVisitor {
 
             let current_slot_size: usize = current_slot.as_ref().unwrap().member_sizes.iter().sum();
 
-            let member_type_name_size = match type_name_size(context.source_units, member.type_name.as_ref().unwrap()) {
+            let member_type_name_size = match type_name_size(context.ast_index, member.type_name.as_ref().unwrap()) {
                 Ok(x) => x,
                 Err(_) => continue,
             };
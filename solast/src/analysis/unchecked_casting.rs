@@ -33,9 +33,14 @@ impl AstVisitor for UncheckedCastingVisitor {
         if let Some(argument_expression) = context.function_call.arguments.first() {
             if let Some(argument_type_descriptions) = argument_expression.type_descriptions() {
                 if type_descriptions == argument_type_descriptions {
+                    let location = match context.contract_definition {
+                        Some(contract_definition) => format!("in the `{}` {}", contract_definition.name, contract_definition.kind),
+                        None => "at the file level".to_string(),
+                    };
+
                     match context.definition_node {
-                        ContractDefinitionNode::FunctionDefinition(function_definition) => println!(
-                            "\tL{}: The {} {} in the `{}` {} contains a redundant cast: `{}`",
+                        DefinitionNode::FunctionDefinition(function_definition) => println!(
+                            "\tL{}: The {} {} {} contains a redundant cast: `{}`",
 
                             context.current_source_unit.source_line(context.function_call.src.as_str())?,
 
@@ -47,21 +52,19 @@ impl AstVisitor for UncheckedCastingVisitor {
                                 format!("`{}` {}", function_definition.name, function_definition.kind)
                             },
 
-                            context.contract_definition.name,
-                            context.contract_definition.kind,
+                            location,
 
                             context.function_call
                         ),
 
-                        ContractDefinitionNode::ModifierDefinition(modifier_definition) => println!(
-                            "\tL{}: The `{}` modifier in the `{}` {} contains a redundant cast: `{}`",
+                        DefinitionNode::ModifierDefinition(modifier_definition) => println!(
+                            "\tL{}: The `{}` modifier {} contains a redundant cast: `{}`",
 
                             context.current_source_unit.source_line(context.function_call.src.as_str())?,
 
                             modifier_definition.name,
 
-                            context.contract_definition.name,
-                            context.contract_definition.kind,
+                            location,
 
                             context.function_call
                         ),
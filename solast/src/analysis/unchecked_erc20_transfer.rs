@@ -37,9 +37,14 @@ impl AstVisitor for UncheckedERC20TransferVisitor {
         let function_info = self.function_info.get(&context.function_definition.id).unwrap();
 
         if function_info.occurance_count > 0 {
+            let location = match context.contract_definition {
+                Some(contract_definition) => format!("in the `{}` {}", contract_definition.name, contract_definition.kind),
+                None => "at the file level".to_string(),
+            };
+
             match context.definition_node {
-                ContractDefinitionNode::FunctionDefinition(function_definition) => println!(
-                    "\tL{}: The {} {} in the `{}` {} makes {} without checking the {}, which can revert {} zero",
+                DefinitionNode::FunctionDefinition(function_definition) => println!(
+                    "\tL{}: The {} {} {} makes {} without checking the {}, which can revert {} zero",
 
                     context.current_source_unit.source_line(context.function_definition.src.as_str())?,
 
@@ -51,8 +56,7 @@ impl AstVisitor for UncheckedERC20TransferVisitor {
                         format!("`{}` {}", function_definition.name, function_definition.kind)
                     },
         
-                    context.contract_definition.name,
-                    context.contract_definition.kind,
+                    location,
 
                     if function_info.occurance_count == 1 {
                         "an ERC-20 transfer"
@@ -73,15 +77,14 @@ impl AstVisitor for UncheckedERC20TransferVisitor {
                     },
                 ),
                 
-                ContractDefinitionNode::ModifierDefinition(modifier_definition) => println!(
-                    "\tL{}: The `{}` modifier in the `{}` {} makes {} without checking the {}, which can revert {} zero",
+                DefinitionNode::ModifierDefinition(modifier_definition) => println!(
+                    "\tL{}: The `{}` modifier {} makes {} without checking the {}, which can revert {} zero",
 
                     context.current_source_unit.source_line(context.function_definition.src.as_str())?,
 
                     modifier_definition.name,
         
-                    context.contract_definition.name,
-                    context.contract_definition.kind,
+                    location,
 
                     if function_info.occurance_count == 1 {
                         "an ERC-20 transfer"
@@ -171,50 +174,45 @@ impl AstVisitor for UncheckedERC20TransferVisitor {
 
     fn visit_function_call<'a, 'b>(&mut self, context: &mut FunctionCallContext<'a, 'b>) -> io::Result<()> {
         let definition_id = match context.definition_node {
-            solidity::ast::ContractDefinitionNode::FunctionDefinition(definition) => definition.id,
-            solidity::ast::ContractDefinitionNode::ModifierDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::FunctionDefinition(definition) => definition.id,
+            solidity::ast::DefinitionNode::ModifierDefinition(definition) => definition.id,
             _ => return Ok(())
         };
 
         for referenced_declaration in context.function_call.expression.referenced_declarations() {
-            for source_unit in context.source_units.iter() {
-                if let Some((called_contract_definition, called_function_definition)) =
-                    source_unit.function_and_contract_definition(referenced_declaration)
+            if let Some((called_contract_definition, called_function_definition)) =
+                context.ast_index.function_and_contract_definition(referenced_declaration)
+            {
+                if let Some("erc20" | "ierc20") = called_contract_definition
+                    .map(|contract_definition| contract_definition.name.to_ascii_lowercase())
+                    .as_deref()
                 {
-                    if let "erc20" | "ierc20" = called_contract_definition
-                        .name
-                        .to_ascii_lowercase()
-                        .as_str()
+                    if let "transfer" | "transferFrom" =
+                        called_function_definition.name.as_str()
                     {
-                        if let "transfer" | "transferFrom" =
-                            called_function_definition.name.as_str()
-                        {
-                            for block in context.blocks.iter() {
-                                let block_info = self.block_info.get(&block.id).unwrap();
-
-                                match context.function_call.arguments.last() {
-                                    Some(solidity::ast::Expression::Literal(_)) => break,
-
-                                    Some(expression)
-                                        if !block_info.verified_declarations.contains(
-                                            expression
-                                                .referenced_declarations()
-                                                .last()
-                                                .unwrap_or(&0),
-                                        ) =>
-                                    {
-                                        self.function_info
-                                            .get_mut(&definition_id)
-                                            .unwrap()
-                                            .occurance_count += 1;
-                                    }
-
-                                    _ => {}
+                        for block in context.blocks.iter() {
+                            let block_info = self.block_info.get(&block.id).unwrap();
+
+                            match context.function_call.arguments.last() {
+                                Some(solidity::ast::Expression::Literal(_)) => break,
+
+                                Some(expression)
+                                    if !block_info.verified_declarations.contains(
+                                        expression
+                                            .referenced_declarations()
+                                            .last()
+                                            .unwrap_or(&0),
+                                    ) =>
+                                {
+                                    self.function_info
+                                        .get_mut(&definition_id)
+                                        .unwrap()
+                                        .occurance_count += 1;
                                 }
+
+                                _ => {}
                             }
                         }
-
-                        break;
                     }
                 }
             }
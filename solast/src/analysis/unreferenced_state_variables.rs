@@ -58,7 +58,7 @@ impl AstVisitor for UnusedStateVariablesVisitor {
             None => return Ok(())
         };
 
-        if let ContractDefinitionNode::VariableDeclaration(variable_declaration) = definition_node {
+        if let DefinitionNode::VariableDeclaration(variable_declaration) = definition_node {
             let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
 
             contract_info.variable_info.entry(variable_declaration.id).or_insert_with(|| false);
@@ -69,12 +69,17 @@ impl AstVisitor for UnusedStateVariablesVisitor {
 
     fn visit_identifier<'a, 'b>(&mut self, context: &mut IdentifierContext<'a, 'b>) -> io::Result<()> {
         match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(function_definition) if function_definition.kind != FunctionKind::Constructor => {}
-            ContractDefinitionNode::ModifierDefinition(_) => {}
+            DefinitionNode::FunctionDefinition(function_definition) if function_definition.kind != FunctionKind::Constructor => {}
+            DefinitionNode::ModifierDefinition(_) => {}
             _ => return Ok(())
         }
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
 
         if let Some(variable_info) = contract_info.variable_info.get_mut(&context.identifier.referenced_declaration) {
             *variable_info = true;
@@ -85,12 +90,17 @@ impl AstVisitor for UnusedStateVariablesVisitor {
 
     fn visit_member_access<'a, 'b>(&mut self, context: &mut MemberAccessContext<'a, 'b>) -> io::Result<()> {
         match context.definition_node {
-            ContractDefinitionNode::FunctionDefinition(function_definition) if function_definition.kind != FunctionKind::Constructor => {}
-            ContractDefinitionNode::ModifierDefinition(_) => {}
+            DefinitionNode::FunctionDefinition(function_definition) if function_definition.kind != FunctionKind::Constructor => {}
+            DefinitionNode::ModifierDefinition(_) => {}
             _ => return Ok(())
         }
 
-        let contract_info = self.contract_info.get_mut(&context.contract_definition.id).unwrap();
+        let contract_definition = match context.contract_definition {
+            Some(contract_definition) => contract_definition,
+            None => return Ok(())
+        };
+
+        let contract_info = self.contract_info.get_mut(&contract_definition.id).unwrap();
 
         if let Some(referenced_declaration) = context.member_access.referenced_declaration {
             if let Some(variable_info) = contract_info.variable_info.get_mut(&referenced_declaration) {
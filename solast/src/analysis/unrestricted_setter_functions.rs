@@ -6,13 +6,13 @@ pub struct UnrestrictedSetterFunctionsVisitor;
 impl UnrestrictedSetterFunctionsVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
     ) {
         println!(
             "\t{} is an unprotected setter function",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
         );
     }
 }
@@ -48,6 +48,7 @@ impl AstVisitor for UnrestrictedSetterFunctionsVisitor {
             match statement {
                 Statement::ExpressionStatement(ExpressionStatement {
                     expression: Expression::Assignment(_),
+                    ..
                 }) => continue,
 
                 _ => return Ok(())
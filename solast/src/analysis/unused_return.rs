@@ -5,15 +5,15 @@ pub struct UnusedReturnVisitor;
 impl UnusedReturnVisitor {
     fn print_message(
         &mut self,
-        contract_definition: &ContractDefinition,
-        definition_node: &ContractDefinitionNode,
+        contract_definition: Option<&ContractDefinition>,
+        definition_node: DefinitionNode,
         source_line: usize,
         called_name: &str,
         return_parameter_count: usize,
     ) {
         println!(
             "\t{} makes a call to the {}, ignoring the returned {}",
-            contract_definition.definition_node_location(source_line, definition_node),
+            definition_node_location(source_line, contract_definition, definition_node),
             called_name,
             if return_parameter_count == 1 { "value" } else { "values" },
         );
@@ -29,7 +29,8 @@ impl AstVisitor for UnusedReturnVisitor {
                     expression,
                     src,
                     ..
-                })
+                }),
+                ..
             }) if !arguments.is_empty() => match expression.root_expression() {
                 Some(&Expression::Identifier(Identifier {
                     referenced_declaration,
@@ -47,33 +48,29 @@ impl AstVisitor for UnusedReturnVisitor {
             _ => return Ok(())
         };
 
-        for source_unit in context.source_units.iter() {
-            if let Some((called_contract_definition, called_function_definition)) = source_unit.function_and_contract_definition(referenced_declaration) {
-                if !called_function_definition.return_parameters.parameters.is_empty() {
-                    self.print_message(
-                        context.contract_definition,
-                        context.definition_node,
-                        context.current_source_unit.source_line(src)?,
+        if let Some((called_contract_definition, called_function_definition)) = context.ast_index.function_and_contract_definition(referenced_declaration) {
+            if !called_function_definition.return_parameters.parameters.is_empty() {
+                self.print_message(
+                    context.contract_definition,
+                    context.definition_node,
+                    context.current_source_unit.source_line(src)?,
 
-                        format!(
-                            "{} `{}` {}",
+                    format!(
+                        "{} `{}` {}",
 
-                            format!("{:?}", called_function_definition.visibility).to_lowercase(),
-    
-                            if called_function_definition.name.is_empty() {
-                                called_contract_definition.name.to_string()
-                            } else {
-                                format!("{}.{}", called_contract_definition.name, called_function_definition.name)
-                            },
-    
-                            format!("{:?}", called_function_definition.kind).to_lowercase(),
-                        ).as_str(),
+                        format!("{:?}", called_function_definition.visibility).to_lowercase(),
 
-                        called_function_definition.return_parameters.parameters.len(),
-                    );
-                }
-                
-                break;
+                        match called_contract_definition {
+                            Some(called_contract_definition) if called_function_definition.name.is_empty() => called_contract_definition.name.to_string(),
+                            Some(called_contract_definition) => format!("{}.{}", called_contract_definition.name, called_function_definition.name),
+                            None => called_function_definition.name.to_string(),
+                        },
+
+                        format!("{:?}", called_function_definition.kind).to_lowercase(),
+                    ).as_str(),
+
+                    called_function_definition.return_parameters.parameters.len(),
+                );
             }
         }
 
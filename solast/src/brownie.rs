@@ -1,28 +1,454 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    env,
+    path::{Path, PathBuf},
+};
+
 use serde::{Deserialize, Serialize};
-use solidity::ast::*;
+use solidity::{ast::*, parser::{self, Parser}};
+
+use crate::{
+    abi::AbiEntry,
+    hardhat,
+    natspec::NatSpec,
+    project::{self, Artifact, CompilerMetadata, LoadOptions, Project, ProjectError, ProjectLoader, SkippedSourceUnit},
+};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProjectStructure {
+    pub build: Option<String>,
+    pub contracts: Option<String>,
+    pub interfaces: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Remappings {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SolcConfig {
+    pub remappings: Option<Remappings>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CompilerConfig {
+    pub solc: Option<SolcConfig>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    pub project_structure: Option<ProjectStructure>,
+    pub compiler: Option<CompilerConfig>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> project::Result<Self> {
+        let config: Option<Self> = serde_yaml::from_str(std::fs::read_to_string(path)?.as_str())
+            .map_err(|error| ProjectError::InvalidConfig {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            })?;
+
+        Ok(config.unwrap_or_default())
+    }
+
+    pub fn build_path(&self) -> &str {
+        self.project_structure.as_ref().and_then(|p| p.build.as_deref()).unwrap_or("build")
+    }
+
+    pub fn contracts_path(&self) -> &str {
+        self.project_structure.as_ref().and_then(|p| p.contracts.as_deref()).unwrap_or("contracts")
+    }
+
+    pub fn interfaces_path(&self) -> &str {
+        self.project_structure.as_ref().and_then(|p| p.interfaces.as_deref()).unwrap_or("interfaces")
+    }
+
+    pub fn build_paths(&self, root_path: &Path) -> Vec<PathBuf> {
+        let build_path = root_path.join(self.build_path());
+
+        vec![
+            build_path.join("contracts"),
+            build_path.join("interfaces"),
+        ]
+    }
+
+    pub fn remappings(&self) -> Vec<(String, String)> {
+        let remappings = match self.compiler.as_ref().and_then(|c| c.solc.as_ref()).and_then(|s| s.remappings.as_ref()) {
+            Some(Remappings::Single(remapping)) => vec![remapping.clone()],
+            Some(Remappings::Multiple(remappings)) => remappings.clone(),
+            None => vec![],
+        };
+
+        remappings
+            .iter()
+            .filter_map(|remapping| {
+                let (prefix, target) = remapping.split_once('=')?;
+                Some((prefix.to_string(), target.to_string()))
+            })
+            .collect()
+    }
+
+    pub fn read_source(&self, root_path: &Path, source_path: &str) -> Option<String> {
+        self.find_source(root_path, source_path)
+            .and_then(|source_path| std::fs::read_to_string(source_path).ok())
+    }
+
+    pub fn find_source(&self, root_path: &Path, source_path: &str) -> Option<PathBuf> {
+        let mut candidate_paths = vec![];
+
+        //
+        // Remapped dependencies are installed to the brownie packages folder
+        //
+
+        for (prefix, target) in self.remappings() {
+            if let Some(rest) = source_path.strip_prefix(prefix.as_str()) {
+                let packages_path = env::var("HOME")
+                    .map(|home| PathBuf::from(home).join(".brownie").join("packages"))
+                    .unwrap_or_else(|_| root_path.to_path_buf());
+
+                candidate_paths.push(packages_path.join(target).join(rest.trim_start_matches('/')));
+            }
+        }
+
+        candidate_paths.push(root_path.join(source_path));
+        candidate_paths.push(root_path.join(self.contracts_path()).join(source_path));
+        candidate_paths.push(root_path.join(self.interfaces_path()).join(source_path));
+
+        candidate_paths
+            .into_iter()
+            .find(|candidate_path| candidate_path.is_file())
+    }
+}
+
+pub fn artifact_paths(build_path: &Path) -> project::Result<Vec<PathBuf>> {
+    let mut result = vec![];
+
+    for path in std::fs::read_dir(build_path)? {
+        let path = path?.path();
+
+        if path.is_dir() {
+            result.extend(artifact_paths(path.as_path())?);
+        } else if path.extension().map(|extension| extension == "json").unwrap_or(false) {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Optimizer {
+    pub enabled: Option<bool>,
+    pub runs: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Compiler {
+    pub evm_version: Option<String>,
+    pub optimizer: Option<Optimizer>,
+    pub version: Option<String>,
+}
+
+impl Compiler {
+    pub fn settings(&self) -> serde_json::Value {
+        serde_json::json!({
+            "evmVersion": self.evm_version,
+            "optimizer": self.optimizer,
+        })
+    }
+}
+
+pub type BranchMap = HashMap<String, HashMap<String, (u64, u64, bool)>>;
+pub type StatementMap = HashMap<String, HashMap<String, (u64, u64)>>;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CoverageMap {
+    #[serde(default)]
+    pub branches: HashMap<String, BranchMap>,
+    #[serde(default)]
+    pub statements: HashMap<String, StatementMap>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PcMapEntry {
+    pub op: Option<String>,
+    pub value: Option<String>,
+    pub offset: Option<(u64, u64)>,
+    pub path: Option<String>,
+    #[serde(rename = "fn")]
+    pub function: Option<String>,
+    pub jump: Option<String>,
+    pub dev: Option<String>,
+    pub statement: Option<u64>,
+    pub branch: Option<u64>,
+    pub first_revert: Option<bool>,
+    pub optimizer_revert: Option<bool>,
+}
+
+//
+// The Brownie-specific parts of an artifact, which have no counterpart in the solc contract output
+//
+
+#[derive(Clone, Debug, Default)]
+pub struct BuildData {
+    pub contract_name: Option<String>,
+    pub pc_map: HashMap<String, PcMapEntry>,
+    pub coverage_map: CoverageMap,
+    pub dependencies: Vec<String>,
+    pub all_source_paths: HashMap<String, String>,
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct File {
-    // TODO: abi
-    // TODO: all_source_paths
+    #[serde(default)]
+    pub abi: Vec<AbiEntry>,
+    #[serde(default)]
+    pub all_source_paths: HashMap<String, String>,
     pub ast: Option<SourceUnit>,
     pub bytecode: Option<String>,
-    // TODO: bytecode_sha1
-    // TODO: compiler
+    pub bytecode_sha1: Option<String>,
+    pub compiler: Option<Compiler>,
     pub contract_name: Option<String>,
-    // TODO: coverage_map
-    // TODO: dependencies
+    #[serde(default)]
+    pub coverage_map: CoverageMap,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
     pub deployed_bytecode: Option<String>,
     pub deployed_source_map: Option<String>,
-    // TODO: language
-    // TODO: natspec
-    // TODO: offset
-    // TODO: opcodes
-    // TODO: pc_map
-    // TODO: sha1
+    pub language: Option<String>,
+    pub natspec: Option<NatSpec>,
+    pub offset: Option<(u64, u64)>,
+    pub opcodes: Option<String>,
+    #[serde(default)]
+    pub pc_map: HashMap<String, PcMapEntry>,
+    pub sha1: Option<String>,
     pub source: Option<String>,
     pub source_map: Option<String>,
     pub source_path: Option<String>,
-    // TODO: type
+    #[serde(rename = "type")]
+    pub kind: Option<ContractKind>,
+}
+
+impl File {
+    pub fn contract(&self) -> hardhat::Contract {
+        hardhat::Contract {
+            abi: self.abi.clone(),
+            evm: Some(hardhat::Evm {
+                bytecode: Some(hardhat::Bytecode {
+                    object: self.bytecode.clone(),
+                    source_map: self.source_map.clone(),
+                    ..Default::default()
+                }),
+                deployed_bytecode: Some(hardhat::Bytecode {
+                    object: self.deployed_bytecode.clone(),
+                    opcodes: self.opcodes.clone(),
+                    source_map: self.deployed_source_map.clone(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            userdoc: self.natspec.as_ref().map(NatSpec::userdoc),
+            devdoc: self.natspec.as_ref().map(NatSpec::devdoc),
+            ..Default::default()
+        }
+    }
+
+    pub fn build_data(&self) -> BuildData {
+        BuildData {
+            contract_name: self.contract_name.clone(),
+            pc_map: self.pc_map.clone(),
+            coverage_map: self.coverage_map.clone(),
+            dependencies: self.dependencies.clone(),
+            all_source_paths: self.all_source_paths.clone(),
+        }
+    }
+}
+
+pub struct BrownieLoader;
+
+impl BrownieLoader {
+    fn config_path(root_path: &Path) -> PathBuf {
+        root_path.join("brownie-config.yaml")
+    }
+
+    //
+    // Contracts listed as dependencies may have no artifact of their own in the build folder, so their source units
+    // are parsed from the source files the dependent artifacts were compiled from
+    //
+
+    fn load_missing_dependencies(&self, root_path: &Path, project: &Project, options: &LoadOptions) -> project::Result<Option<Artifact>> {
+        let config = Config::load(Self::config_path(root_path).as_path())?;
+        let source_units = project.source_units();
+
+        let contract_names: HashSet<&str> = source_units.iter()
+            .flat_map(|source_unit| source_unit.contract_definitions())
+            .map(|contract_definition| contract_definition.name.as_str())
+            .collect();
+
+        let mut missing_dependencies = HashSet::new();
+        let mut candidate_paths = BTreeSet::new();
+
+        for build_data in project.artifacts.iter().filter_map(|artifact| artifact.brownie.as_ref()) {
+            let dependencies: Vec<&String> = build_data.dependencies.iter()
+                .filter(|dependency| !contract_names.contains(dependency.as_str()))
+                .collect();
+
+            if dependencies.is_empty() {
+                continue;
+            }
+
+            missing_dependencies.extend(dependencies);
+
+            candidate_paths.extend(
+                build_data.all_source_paths.values()
+                    .filter(|source_path| !source_units.iter().any(|source_unit| source_unit.absolute_path.as_ref() == Some(*source_path)))
+            );
+        }
+
+        if missing_dependencies.is_empty() {
+            return Ok(None);
+        }
+
+        let mut parser = Parser::default();
+        let mut dependency_source_units = vec![];
+        let mut skipped_source_units = vec![];
+
+        for source_path in candidate_paths {
+            let file_path = match config.find_source(root_path, source_path) {
+                Some(file_path) => file_path,
+                None => continue,
+            };
+
+            let source_unit = match parser.parse(std::fs::read_to_string(file_path.as_path())?.as_str(), source_path, dependency_source_units.len()) {
+                Ok(source_unit) => source_unit,
+
+                Err(error) if options.lenient => {
+                    skipped_source_units.push(SkippedSourceUnit {
+                        artifact_path: file_path,
+                        absolute_path: Some(source_path.clone()),
+                        message: error.to_string(),
+                    });
+
+                    continue;
+                }
+
+                Err(error) => return Err(error.into()),
+            };
+
+            if source_unit.contract_definitions().iter().any(|contract_definition| missing_dependencies.contains(&contract_definition.name)) {
+                dependency_source_units.push(source_unit);
+            }
+        }
+
+        parser::resolve(dependency_source_units.as_mut_slice());
+
+        //
+        // The parsed source units have their own NodeIDs, so they are kept out of the compilation of the build artifacts
+        //
+
+        for source_unit in dependency_source_units.iter_mut() {
+            source_unit.compilation_id = Some("dependencies".to_string());
+        }
+
+        Ok(Some(Artifact {
+            path: root_path.join(config.build_path()).join("contracts"),
+            compiler: CompilerMetadata::default(),
+            source_units: dependency_source_units,
+            contracts: HashMap::new(),
+            skipped_source_units,
+            brownie: None,
+        }))
+    }
+}
+
+impl ProjectLoader for BrownieLoader {
+    fn name(&self) -> &'static str {
+        "Brownie"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        Self::config_path(root_path).is_file()
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        let config = Config::load(Self::config_path(root_path).as_path())?;
+        let build_paths = config.build_paths(root_path);
+
+        if !build_paths.iter().any(|build_path| build_path.is_dir()) {
+            return Err(ProjectError::NotCompiled {
+                project_kind: self.name(),
+                build_path: root_path.join(config.build_path()).join("contracts"),
+            });
+        }
+
+        let mut result = vec![];
+
+        for build_path in build_paths {
+            if build_path.is_dir() {
+                result.extend(artifact_paths(build_path.as_path())?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let config = Config::load(Self::config_path(root_path).as_path())?;
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+
+        let build_data = file.build_data();
+        let long_version = file.compiler.as_ref().and_then(|compiler| compiler.version.clone());
+
+        let compiler = CompilerMetadata {
+            version: long_version.as_deref().and_then(|long_version| long_version.split('+').next()).map(str::to_string),
+            long_version,
+            settings: file.compiler.as_ref().map(Compiler::settings),
+        };
+
+        let mut source_units = vec![];
+        let mut contracts = HashMap::new();
+
+        if let (Some(absolute_path), Some(contract_name)) = (file.ast.as_ref().and_then(|ast| ast.absolute_path.clone()), file.contract_name.clone()) {
+            contracts.insert(absolute_path, HashMap::from([(contract_name, file.contract())]));
+        }
+
+        if let Some(mut source_unit) = file.ast {
+            source_unit.set_source(match file.source {
+                Some(source) => Some(source),
+                None => file.source_path.as_deref().and_then(|source_path| config.read_source(root_path, source_path)),
+            });
+
+            source_units.push(source_unit);
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler,
+            source_units,
+            contracts,
+            skipped_source_units,
+            brownie: Some(build_data),
+        })
+    }
+
+    fn load(&self, root_path: &Path, options: &LoadOptions) -> project::Result<Project> {
+        let mut project = Project::default();
+
+        for artifact_path in self.artifact_paths(root_path)? {
+            project.artifacts.push(self.load_artifact(root_path, artifact_path.as_path(), options)?);
+        }
+
+        if let Some(artifact) = self.load_missing_dependencies(root_path, &project, options)? {
+            project.artifacts.push(artifact);
+        }
+
+        Ok(project)
+    }
 }
@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::project::{self, ProjectError};
+
+//
+// Test and mock folders are skipped at any depth, such as contracts/test/ in Hardhat projects. Only the lib/ folder at
+// the project root is skipped, since that is where Foundry and Dapptools install dependencies.
+//
+
+pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] = &[
+    "**/node_modules/**",
+    "@*/**",
+    "hardhat/console.sol",
+    "**/Migrations.sol",
+    "lib/",
+    "**/test/",
+    "**/mocks/",
+];
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+    pub default_excludes: Option<bool>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> project::Result<Self> {
+        toml::from_str(std::fs::read_to_string(path)?.as_str())
+            .map_err(|error| ProjectError::InvalidConfig {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            })
+    }
+
+    pub fn load_or_default(root_path: &Path) -> project::Result<Self> {
+        let config_path = root_path.join("solast.toml");
+
+        if config_path.is_file() {
+            Self::load(config_path.as_path())
+        } else {
+            Ok(Self::default())
+        }
+    }
+}
+
+pub struct PathFilter {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl PathFilter {
+    pub fn new(include_patterns: &[String], exclude_patterns: &[String]) -> project::Result<Self> {
+        Ok(Self {
+            include: Self::build_glob_set(include_patterns)?,
+            exclude: Self::build_glob_set(exclude_patterns)?,
+        })
+    }
+
+    //
+    // Command line patterns extend the patterns in solast.toml. The default and build system exclude patterns are
+    // always applied, unless they were turned off on the command line or in solast.toml.
+    //
+
+    pub fn from_config(
+        config: &Config,
+        include_patterns: &[String],
+        exclude_patterns: &[String],
+        build_system_exclude_patterns: &[String],
+        use_default_excludes: bool,
+    ) -> project::Result<Self> {
+        let include_patterns = [config.include.clone().unwrap_or_default().as_slice(), include_patterns].concat();
+
+        let mut default_exclude_patterns: Vec<String> = vec![];
+
+        if use_default_excludes && config.default_excludes.unwrap_or(true) {
+            default_exclude_patterns.extend(DEFAULT_EXCLUDE_PATTERNS.iter().map(|pattern| pattern.to_string()));
+            default_exclude_patterns.extend(build_system_exclude_patterns.iter().cloned());
+        }
+
+        let exclude_patterns = [
+            default_exclude_patterns.as_slice(),
+            config.exclude.clone().unwrap_or_default().as_slice(),
+            exclude_patterns,
+        ].concat();
+
+        Self::new(include_patterns.as_slice(), exclude_patterns.as_slice())
+    }
+
+    fn build_glob_set(patterns: &[String]) -> project::Result<GlobSet> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            //
+            // Patterns ending with a separator match everything inside of the directory
+            //
+
+            let glob_pattern = match pattern.trim_start_matches("./") {
+                pattern if pattern.ends_with('/') => format!("{}**", pattern),
+                pattern => pattern.to_string(),
+            };
+
+            let glob = GlobBuilder::new(glob_pattern.as_str())
+                .literal_separator(true)
+                .build()
+                .map_err(|error| ProjectError::InvalidPattern {
+                    pattern: pattern.clone(),
+                    message: error.to_string(),
+                })?;
+
+            builder.add(glob);
+        }
+
+        builder.build().map_err(|error| ProjectError::InvalidPattern {
+            pattern: patterns.join(", "),
+            message: error.to_string(),
+        })
+    }
+
+    pub fn is_included(&self, path: &str) -> bool {
+        let path = path.trim_start_matches("./");
+
+        //
+        // When include patterns are supplied only matching paths are analyzed, and excluded paths are always skipped
+        //
+
+        (self.include.is_empty() || self.include.is_match(path)) && !self.exclude.is_match(path)
+    }
+}
@@ -0,0 +1,369 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+
+use crate::{
+    abi::AbiEntry,
+    hardhat,
+    project::{self, Artifact, CompilerMetadata, LoadOptions, Project, ProjectError, ProjectLoader},
+};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Profile {
+    pub src: Option<String>,
+    pub out: Option<String>,
+    pub libs: Option<Vec<String>>,
+    pub test: Option<String>,
+    pub script: Option<String>,
+}
+
+impl Profile {
+    pub fn src(&self) -> &str {
+        self.src.as_deref().unwrap_or("src")
+    }
+
+    pub fn out(&self) -> &str {
+        self.out.as_deref().unwrap_or("out")
+    }
+
+    pub fn libs(&self) -> Vec<&str> {
+        match self.libs.as_ref() {
+            Some(libs) => libs.iter().map(String::as_str).collect(),
+            None => vec!["lib"],
+        }
+    }
+
+    pub fn test(&self) -> &str {
+        self.test.as_deref().unwrap_or("test")
+    }
+
+    pub fn script(&self) -> &str {
+        self.script.as_deref().unwrap_or("script")
+    }
+
+    pub fn exclude_patterns(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.libs()
+            .iter()
+            .map(|lib| format!("{}/", lib.trim_end_matches('/')))
+            .collect();
+
+        result.push(format!("{}/", self.test().trim_end_matches('/')));
+        result.push(format!("{}/", self.script().trim_end_matches('/')));
+
+        result
+    }
+
+    pub fn read_source(&self, root_path: &Path, absolute_path: &str) -> Option<String> {
+        let mut search_paths = vec![
+            root_path.to_path_buf(),
+            root_path.join(self.src()),
+        ];
+
+        for lib in self.libs() {
+            search_paths.push(root_path.join(lib));
+        }
+
+        search_paths
+            .iter()
+            .map(|search_path| search_path.join(absolute_path))
+            .find(|source_path| source_path.is_file())
+            .and_then(|source_path| std::fs::read_to_string(source_path).ok())
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    #[serde(default)]
+    pub profile: HashMap<String, Profile>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> project::Result<Self> {
+        toml::from_str(std::fs::read_to_string(path)?.as_str())
+            .map_err(|error| ProjectError::InvalidConfig {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            })
+    }
+
+    pub fn active_profile(&self) -> Profile {
+        let default_profile = self.profile.get("default").cloned().unwrap_or_default();
+
+        let profile = match env::var("FOUNDRY_PROFILE") {
+            Ok(name) => match self.profile.get(&name) {
+                Some(profile) => profile.clone(),
+                None => return default_profile,
+            },
+
+            Err(_) => return default_profile,
+        };
+
+        Profile {
+            src: profile.src.or(default_profile.src),
+            out: profile.out.or(default_profile.out),
+            libs: profile.libs.or(default_profile.libs),
+            test: profile.test.or(default_profile.test),
+            script: profile.script.or(default_profile.script),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    #[serde(default)]
+    pub abi: Vec<AbiEntry>,
+    pub bytecode: Option<hardhat::Bytecode>,
+    pub deployed_bytecode: Option<hardhat::Bytecode>,
+    #[serde(default)]
+    pub method_identifiers: HashMap<String, String>,
+    pub storage_layout: Option<hardhat::StorageLayout>,
+    pub metadata: Option<serde_json::Value>,
+    pub ast: Option<SourceUnit>,
+    pub id: Option<u64>,
+}
+
+impl File {
+    pub fn contract(&self) -> hardhat::Contract {
+        hardhat::Contract {
+            abi: self.abi.clone(),
+            storage_layout: self.storage_layout.clone(),
+            evm: Some(hardhat::Evm {
+                bytecode: self.bytecode.clone(),
+                deployed_bytecode: self.deployed_bytecode.clone(),
+                method_identifiers: self.method_identifiers.clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    //
+    // Artifacts of files compiled with several compiler versions are written as `Name.<version>.json`, so the name is
+    // taken from the compilation target in the metadata, or from the AST when there is none
+    //
+
+    pub fn contract_name(&self, artifact_path: &Path) -> Option<String> {
+        let metadata = match self.metadata.as_ref() {
+            Some(serde_json::Value::String(metadata)) => serde_json::from_str(metadata).ok(),
+            metadata => metadata.cloned(),
+        };
+
+        let compilation_target = metadata.as_ref()
+            .and_then(|metadata| metadata.pointer("/settings/compilationTarget"))
+            .and_then(serde_json::Value::as_object)
+            .and_then(|compilation_target| compilation_target.values().next())
+            .and_then(serde_json::Value::as_str);
+
+        if let Some(contract_name) = compilation_target {
+            return Some(contract_name.to_string());
+        }
+
+        let file_stem = artifact_path.file_stem()?.to_string_lossy();
+        let file_stem = file_stem.split('.').next().unwrap_or_default();
+
+        self.ast.as_ref()?
+            .contract_definitions()
+            .into_iter()
+            .find(|contract_definition| contract_definition.name == file_stem)
+            .map(|contract_definition| contract_definition.name.clone())
+    }
+
+    pub fn compiler(&self) -> CompilerMetadata {
+        let long_version = self.metadata.as_ref()
+            .and_then(|metadata| metadata.pointer("/compiler/version"))
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string);
+
+        CompilerMetadata {
+            version: long_version.as_ref().map(|long_version| long_version.split('+').next().unwrap_or(long_version).to_string()),
+            long_version,
+            settings: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildInfo {
+    pub id: String,
+    #[serde(rename = "_format")]
+    pub format: Option<String>,
+    pub solc_version: Option<String>,
+    pub solc_long_version: Option<String>,
+    pub input: hardhat::Input,
+    pub output: hardhat::Output,
+}
+
+pub fn artifact_paths(out_path: &Path) -> project::Result<Vec<PathBuf>> {
+    let mut result = vec![];
+
+    for path in std::fs::read_dir(out_path)? {
+        let path = path?.path();
+
+        if path.is_dir() {
+            if !path.file_name().map(|file_name| file_name == "build-info").unwrap_or(false) {
+                result.extend(artifact_paths(path.as_path())?);
+            }
+        } else if path.extension().map(|extension| extension == "json").unwrap_or(false) {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
+
+pub struct FoundryLoader;
+
+impl FoundryLoader {
+    fn active_profile(root_path: &Path) -> project::Result<Profile> {
+        Ok(Config::load(root_path.join("foundry.toml").as_path())?.active_profile())
+    }
+
+    fn profile_artifact_paths(&self, root_path: &Path, profile: &Profile) -> project::Result<Vec<PathBuf>> {
+        let out_path = root_path.join(profile.out());
+
+        if !out_path.is_dir() {
+            return Err(ProjectError::NotCompiled {
+                project_kind: self.name(),
+                build_path: out_path,
+            });
+        }
+
+        //
+        // Prefer build-info files when available, since they contain the source text of each file
+        //
+
+        let mut result = vec![];
+
+        let build_info_path = out_path.join("build-info");
+
+        if build_info_path.is_dir() {
+            result.extend(project::json_file_paths(build_info_path.as_path())?);
+        }
+
+        result.extend(artifact_paths(out_path.as_path())?);
+
+        Ok(result)
+    }
+
+    fn load_profile_artifact(root_path: &Path, profile: &Profile, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let mut source_units = vec![];
+
+        if Self::is_build_info(artifact_path) {
+            let (file, skipped_source_units) = project::read_artifact::<BuildInfo>(artifact_path, options)?;
+
+            for (source_path, source) in file.output.sources {
+                let mut source_unit = source.ast;
+
+                if let Some(source) = file.input.sources.get(&source_path) {
+                    source_unit.set_source(source.content.clone());
+                    source_unit.compilation_id = Some(file.id.clone());
+                    source_units.push(source_unit);
+                }
+            }
+
+            return Ok(Artifact {
+                path: artifact_path.to_path_buf(),
+                compiler: CompilerMetadata {
+                    version: file.solc_version,
+                    long_version: file.solc_long_version,
+                    settings: Some(file.input.settings),
+                },
+                source_units,
+                contracts: file.output.contracts,
+                skipped_source_units,
+                brownie: None,
+            });
+        }
+
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+        let compiler = file.compiler();
+
+        let mut contracts = HashMap::new();
+
+        if let (Some(absolute_path), Some(contract_name)) = (file.ast.as_ref().and_then(|ast| ast.absolute_path.clone()), file.contract_name(artifact_path)) {
+            contracts.insert(absolute_path, HashMap::from([(contract_name, file.contract())]));
+        }
+
+        if let Some(mut source_unit) = file.ast {
+            let absolute_path = source_unit.absolute_path.clone().unwrap_or_default();
+
+            source_unit.set_source(profile.read_source(root_path, absolute_path.as_str()));
+            source_units.push(source_unit);
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler,
+            source_units,
+            contracts,
+            skipped_source_units,
+            brownie: None,
+        })
+    }
+
+    fn is_build_info(artifact_path: &Path) -> bool {
+        artifact_path.parent()
+            .and_then(|parent_path| parent_path.file_name())
+            .map(|file_name| file_name == "build-info")
+            .unwrap_or(false)
+    }
+}
+
+impl ProjectLoader for FoundryLoader {
+    fn name(&self) -> &'static str {
+        "Foundry"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        root_path.join("foundry.toml").is_file()
+    }
+
+    fn exclude_patterns(&self, root_path: &Path) -> project::Result<Vec<String>> {
+        Ok(Self::active_profile(root_path)?.exclude_patterns())
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        self.profile_artifact_paths(root_path, &Self::active_profile(root_path)?)
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        Self::load_profile_artifact(root_path, &Self::active_profile(root_path)?, artifact_path, options)
+    }
+
+    fn load(&self, root_path: &Path, options: &LoadOptions) -> project::Result<Project> {
+        let profile = Self::active_profile(root_path)?;
+
+        let mut project = Project::default();
+        let mut build_info_paths = HashSet::new();
+
+        //
+        // The profile is only read once for the whole project. Build-info files are listed first. Per-contract ASTs of files they already contain are dropped, since they
+        // lack a compilation id and would otherwise end up in a separate compilation next to the build-info copy.
+        //
+
+        for artifact_path in self.profile_artifact_paths(root_path, &profile)? {
+            let mut artifact = Self::load_profile_artifact(root_path, &profile, artifact_path.as_path(), options)?;
+
+            if Self::is_build_info(artifact_path.as_path()) {
+                build_info_paths.extend(artifact.source_units.iter().filter_map(|source_unit| source_unit.absolute_path.clone()));
+            } else {
+                artifact.source_units.retain(|source_unit| {
+                    source_unit.absolute_path.as_ref().map(|absolute_path| !build_info_paths.contains(absolute_path)).unwrap_or(true)
+                });
+            }
+
+            project.artifacts.push(artifact);
+        }
+
+        Ok(project)
+    }
+}
@@ -1,12 +1,23 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
+use eth_lang_utils::ast::NodeID;
 use serde::{Deserialize, Serialize};
 use solidity::ast::*;
 
+use crate::{
+    abi::AbiEntry,
+    natspec::{DevDoc, UserDoc},
+    project::{self, Artifact, CompilerMetadata, LoadOptions, ProjectError, ProjectLoader},
+};
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InputSource {
-    pub content: String,
+    pub content: Option<String>,
+    pub urls: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -14,6 +25,7 @@ pub struct InputSource {
 pub struct Input {
     pub language: String,
     pub sources: HashMap<String, InputSource>,
+    #[serde(default)]
     pub settings: serde_json::Value,
 }
 
@@ -24,10 +36,118 @@ pub struct OutputSource {
     pub id: u64,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BytecodeOffset {
+    pub start: u64,
+    pub length: u64,
+}
+
+pub type LinkReferences = HashMap<String, HashMap<String, Vec<BytecodeOffset>>>;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionDebugData {
+    pub entry_point: Option<u64>,
+    pub id: Option<NodeID>,
+    pub parameter_slots: Option<u64>,
+    pub return_slots: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    pub object: Option<String>,
+    pub opcodes: Option<String>,
+    pub source_map: Option<String>,
+    #[serde(default)]
+    pub link_references: LinkReferences,
+    #[serde(default)]
+    pub immutable_references: HashMap<String, Vec<BytecodeOffset>>,
+    #[serde(default)]
+    pub function_debug_data: HashMap<String, FunctionDebugData>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationGasEstimates {
+    pub code_deposit_cost: Option<String>,
+    pub execution_cost: Option<String>,
+    pub total_cost: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimates {
+    pub creation: Option<CreationGasEstimates>,
+    #[serde(default)]
+    pub external: HashMap<String, String>,
+    #[serde(default)]
+    pub internal: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Evm {
+    pub assembly: Option<String>,
+    pub bytecode: Option<Bytecode>,
+    pub deployed_bytecode: Option<Bytecode>,
+    #[serde(default)]
+    pub method_identifiers: HashMap<String, String>,
+    pub gas_estimates: Option<GasEstimates>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlot {
+    pub ast_id: NodeID,
+    pub contract: String,
+    pub label: String,
+    pub offset: u64,
+    pub slot: String,
+    #[serde(rename = "type")]
+    pub type_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageType {
+    pub encoding: String,
+    pub label: String,
+    pub number_of_bytes: String,
+    pub base: Option<String>,
+    pub key: Option<String>,
+    pub value: Option<String>,
+    pub members: Option<Vec<StorageSlot>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLayout {
+    #[serde(default)]
+    pub storage: Vec<StorageSlot>,
+    pub types: Option<HashMap<String, StorageType>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    #[serde(default)]
+    pub abi: Vec<AbiEntry>,
+    pub metadata: Option<String>,
+    pub userdoc: Option<UserDoc>,
+    pub devdoc: Option<DevDoc>,
+    pub ir: Option<String>,
+    pub storage_layout: Option<StorageLayout>,
+    pub evm: Option<Evm>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Output {
-    pub contracts: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub contracts: HashMap<String, HashMap<String, Contract>>,
+    #[serde(default)]
     pub sources: HashMap<String, OutputSource>,
 }
 
@@ -42,3 +162,57 @@ pub struct File {
     pub input: Input,
     pub output: Output,
 }
+
+pub struct HardhatLoader;
+
+impl ProjectLoader for HardhatLoader {
+    fn name(&self) -> &'static str {
+        "Hardhat"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        root_path.join("hardhat.config.js").is_file() || root_path.join("hardhat.config.ts").is_file()
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        let build_path = root_path.join("artifacts").join("build-info");
+
+        if !build_path.is_dir() {
+            return Err(ProjectError::NotCompiled {
+                project_kind: self.name(),
+                build_path,
+            });
+        }
+
+        project::json_file_paths(build_path.as_path())
+    }
+
+    fn load_artifact(&self, _root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+
+        let mut source_units = vec![];
+
+        for (source_path, source) in file.output.sources {
+            let mut source_unit = source.ast;
+
+            if let Some(source) = file.input.sources.get(&source_path) {
+                source_unit.set_source(source.content.clone());
+                source_unit.compilation_id = Some(file.id.clone());
+                source_units.push(source_unit);
+            }
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler: CompilerMetadata {
+                version: Some(file.solc_version),
+                long_version: Some(file.solc_long_version),
+                settings: Some(file.input.settings),
+            },
+            source_units,
+            contracts: file.output.contracts,
+            skipped_source_units,
+            brownie: None,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+pub mod abi;
+pub mod brownie;
+pub mod filter;
+pub mod foundry;
+pub mod hardhat;
+pub mod natspec;
+pub mod project;
+pub mod solc;
+pub mod sources;
+pub mod truffle;
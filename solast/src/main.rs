@@ -1,11 +1,13 @@
 mod analysis;
-mod brownie;
-mod hardhat;
-mod truffle;
 mod todo_list;
 
+use solast::{
+    filter::{self, PathFilter},
+    project::{self, LoadOptions, ProjectLoader},
+    solc,
+};
 use solidity::ast::*;
-use std::{collections::HashSet, env, fs::File, io, path::PathBuf};
+use std::{cell::Cell, collections::{BTreeMap, HashSet}, env, io, path::PathBuf, rc::Rc};
 
 type VisitorConstructor = fn() -> Box<dyn AstVisitor>;
 type VisitorEntry = (&'static str, VisitorConstructor);
@@ -24,7 +26,7 @@ const VISITOR_TYPES: &[VisitorEntry] = &[
     ("explicit_variable_return", || Box::new(analysis::ExplicitVariableReturnVisitor::default())),
     ("unused_return", || Box::new(analysis::UnusedReturnVisitor)),
     ("storage_array_loop", || Box::new(analysis::StorageArrayLoopVisitor::default())),
-    ("external_calls_in_loop", || Box::new(analysis::ExternalCallsInLoopVisitor::default())),
+    ("external_calls_in_loop", || Box::new(analysis::ExternalCallsInLoopVisitor)),
     ("check_effects_interactions", || Box::new(analysis::CheckEffectsInteractionsVisitor::default())),
     ("secure_ether_transfer", || Box::new(analysis::SecureEtherTransferVisitor)),
     ("safe_erc20_functions", || Box::new(analysis::SafeERC20FunctionsVisitor)),
@@ -54,14 +56,29 @@ const VISITOR_TYPES: &[VisitorEntry] = &[
     ("array_assignment", || Box::new(analysis::ArrayAssignmentVisitor)),
 ];
 
+//
+// Standalone Yul files only contain assembly, so only the analyzers looking at assembly are run on them
+//
+
+const YUL_VISITOR_NAMES: &[&str] = &["inline_assembly"];
+
 fn main() -> io::Result<()> {
     let mut args = env::args();
     args.next().ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))?;
 
     let mut path: Option<PathBuf> = None;
     let mut should_print_todo_list = false;
+    let mut should_analyze_source_variants = false;
+    let mut should_list_unhandled_nodes = false;
+    let mut lenient = false;
+    let mut use_default_excludes = true;
     let mut visitor_names: HashSet<String> = HashSet::new();
     let mut contract_name: Option<String> = None;
+    let mut standard_json_input_path: Option<PathBuf> = None;
+    let mut standard_json_output_path: Option<PathBuf> = None;
+    let mut combined_json_path: Option<PathBuf> = None;
+    let mut include_patterns: Vec<String> = vec![];
+    let mut exclude_patterns: Vec<String> = vec![];
 
     for arg in args {
         match arg {
@@ -70,14 +87,62 @@ fn main() -> io::Result<()> {
                     should_print_todo_list = true;
                 }
 
+                "analyze-source-variants" | "analyze_source_variants" => {
+                    should_analyze_source_variants = true;
+                }
+
+                "unhandled-nodes" | "unhandled_nodes" => {
+                    should_list_unhandled_nodes = true;
+                }
+
+                "lenient" => {
+                    lenient = true;
+                }
+
+                "no-default-excludes" | "no_default_excludes" => {
+                    use_default_excludes = false;
+                }
+
                 s if s.starts_with("contract=") => {
-                    if contract_name.is_some() {
-                        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple contracts specified: {} {}", path.unwrap().to_string_lossy(), arg)));
+                    if let Some(contract_name) = contract_name.as_ref() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple contracts specified: {} {}", contract_name, arg)));
                     }
                     
                     contract_name = Some(s.trim_start_matches("contract=").into());
                 }
 
+                s if s.starts_with("standard-json-input=") => {
+                    if let Some(standard_json_input_path) = standard_json_input_path.as_ref() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple standard JSON inputs specified: {} {}", standard_json_input_path.to_string_lossy(), arg)));
+                    }
+
+                    standard_json_input_path = Some(PathBuf::from(s.trim_start_matches("standard-json-input=")));
+                }
+
+                s if s.starts_with("standard-json-output=") => {
+                    if let Some(standard_json_output_path) = standard_json_output_path.as_ref() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple standard JSON outputs specified: {} {}", standard_json_output_path.to_string_lossy(), arg)));
+                    }
+
+                    standard_json_output_path = Some(PathBuf::from(s.trim_start_matches("standard-json-output=")));
+                }
+
+                s if s.starts_with("combined-json=") => {
+                    if let Some(combined_json_path) = combined_json_path.as_ref() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple combined JSON files specified: {} {}", combined_json_path.to_string_lossy(), arg)));
+                    }
+
+                    combined_json_path = Some(PathBuf::from(s.trim_start_matches("combined-json=")));
+                }
+
+                s if s.starts_with("include=") => {
+                    include_patterns.push(s.trim_start_matches("include=").into());
+                }
+
+                s if s.starts_with("exclude=") => {
+                    exclude_patterns.push(s.trim_start_matches("exclude=").into());
+                }
+
                 s if VISITOR_TYPES.iter().any(|visitor| visitor.0 == s) => {
                     if !visitor_names.contains(s) {
                         visitor_names.insert(s.into());
@@ -90,8 +155,8 @@ fn main() -> io::Result<()> {
             }
 
             _ => {
-                if path.is_some() {
-                    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple paths specified: {} {}", path.unwrap().to_string_lossy(), arg)));
+                if let Some(path) = path.as_ref() {
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Multiple paths specified: {} {}", path.to_string_lossy(), arg)));
                 }
 
                 path = Some(PathBuf::from(arg));
@@ -99,8 +164,17 @@ fn main() -> io::Result<()> {
         }
     }
 
+    if standard_json_input_path.is_some() && standard_json_output_path.is_none() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Standard JSON input supplied without standard JSON output"));
+    }
+
+    //
+    // When analyzing standard or combined JSON output, the path is optional and only used to locate source files
+    //
+
     let path = match path {
         Some(path) => path,
+        None if standard_json_output_path.is_some() || combined_json_path.is_some() => PathBuf::from("."),
         None => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Path not supplied"))
     };
 
@@ -108,194 +182,188 @@ fn main() -> io::Result<()> {
         return Err(io::Error::new(io::ErrorKind::NotFound, path.to_string_lossy()))
     }
     
-    let mut source_units: Vec<SourceUnit> = vec![];
-
-    let brownie_config_path = path.join("brownie-config.yaml");
-    let hardhat_config_js_path = path.join("hardhat.config.js");
-    let hardhat_config_ts_path = path.join("hardhat.config.ts");
-    let truffle_config_path = path.join("truffle-config.js");
+    let loader: Box<dyn ProjectLoader> = if let Some(standard_json_output_path) = standard_json_output_path {
+        Box::new(solc::StandardJsonLoader {
+            input_path: standard_json_input_path,
+            output_path: standard_json_output_path,
+        })
+    } else if let Some(combined_json_path) = combined_json_path {
+        Box::new(solc::CombinedJsonLoader {
+            path: combined_json_path,
+        })
+    } else {
+        project::detect(path.as_path())?
+    };
 
-    if brownie_config_path.is_file() {
-        //
-        // TODO: load the brownie config and get the actual build paths
-        //
+    let path_filter = PathFilter::from_config(
+        &filter::Config::load_or_default(path.as_path())?,
+        include_patterns.as_slice(),
+        exclude_patterns.as_slice(),
+        loader.exclude_patterns(path.as_path())?.as_slice(),
+        use_default_excludes,
+    )?;
+
+    let project = loader.load(path.as_path(), &LoadOptions { lenient })?;
+
+    for skipped_source_unit in project.skipped_source_units() {
+        println!(
+            "WARNING: Skipping malformed source unit {} in artifact file {}: {}",
+            skipped_source_unit.absolute_path.as_deref().unwrap_or("<unknown>"),
+            skipped_source_unit.artifact_path.to_string_lossy(),
+            skipped_source_unit.message
+        );
+    }
 
-        let build_paths = &[
-            path.join("build").join("contracts"),
-            path.join("build").join("interfaces"),
-        ];
+    let mut source_units = project.source_units();
+    let compilations = project.compilations();
 
-        for build_path in build_paths {
-            if !build_path.exists() || !build_path.is_dir() {
-                todo!("brownie project not compiled")
-            }
+    source_units.retain(|source_unit| path_filter.is_included(source_unit.absolute_path.as_deref().unwrap_or("")));
 
-            for path in std::fs::read_dir(build_path)? {
-                let path = path?.path();
+    if let Some(contract_name) = contract_name.as_deref() {
+        source_units.retain(|source_unit| source_unit.contract_definitions().iter().any(|c| c.name == contract_name));
+    }
 
-                if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                    continue;
-                }
+    if should_print_todo_list {
+        todo_list::print(source_units.as_slice());
+    }
 
-                let file: brownie::File = simd_json::from_reader(File::open(path)?)?;
+    //
+    // Report files that were compiled from different contents or with different compiler versions
+    //
 
-                if let Some(mut source_unit) = file.ast {
-                    if let Some(contract_name) = contract_name.as_deref() {
-                        if !source_unit.contract_definitions().iter().any(|c| c.name == contract_name) {
-                            continue;
-                        }
-                    }
+    let mut source_conflicts = project.source_conflicts();
+    source_conflicts.retain(|source_conflict| path_filter.is_included(source_conflict.absolute_path.as_str()));
 
-                    if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                        source_unit.source = file.source.clone();
-                        source_units.push(source_unit);
-                    }
-                }
-            }
-        }
-    } else if hardhat_config_js_path.is_file() || hardhat_config_ts_path.is_file() {
-        let build_path = path.join("artifacts").join("build-info");
+    for source_conflict in source_conflicts.iter() {
+        println!("Conflicting sources found for {}:", source_conflict.absolute_path);
 
-        if !build_path.exists() || !build_path.is_dir() {
-            todo!("hardhat project not compiled")
+        for variant in source_conflict.variants.iter() {
+            println!(
+                "\t{} (solc {})",
+                variant.artifact_path.to_string_lossy(),
+                variant.solc_version.as_deref().unwrap_or("<unknown>")
+            );
         }
 
-        let console_path = PathBuf::new()
-            .join("hardhat")
-            .join("console.sol")
-            .to_string_lossy()
-            .to_string();
-
-        for path in std::fs::read_dir(build_path)? {
-            let path = path?.path();
+        println!();
+    }
 
-            if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
-                continue;
-            }
+    let conflicting_paths: HashSet<String> = source_conflicts.iter()
+        .map(|source_conflict| source_conflict.absolute_path.clone())
+        .collect();
 
-            let file: hardhat::File = simd_json::from_reader(File::open(path)?)?;
+    let mut analyzed_paths = HashSet::new();
+    let mut analyzed_variants: Vec<(String, Option<String>, Option<String>)> = vec![];
+    let mut unhandled_node_types: BTreeMap<String, usize> = BTreeMap::new();
+    let first_file = Rc::new(Cell::new(true));
 
-            for (source_path, source) in file.output.sources {
-                let mut source_unit = source.ast;
+    for compilation in compilations.iter() {
+        //
+        // Each compilation gets its own visitors, since NodeIDs are only unique within a single compilation
+        //
 
-                if source_unit.absolute_path.as_deref().unwrap_or("").ends_with(console_path.as_str()) {
-                    continue;
-                }
-                
-                if let Some(contract_name) = contract_name.as_deref() {
-                    if !source_unit.contract_definitions().iter().any(|c| c.name == contract_name) {
-                        continue;
-                    }
+        let create_visitor_data = |yul: bool| {
+            let mut visitors: Vec<Box<dyn AstVisitor>> = vec![
+                Box::new(analysis::SourceUnitVisitor::new(
+                    first_file.clone(),
+                    conflicting_paths.clone(),
+                    Some(compilation.description()),
+                )),
+            ];
+
+            for &(visitor_name, create_visitor) in VISITOR_TYPES {
+                if (visitor_names.is_empty() || visitor_names.contains(visitor_name)) && (!yul || YUL_VISITOR_NAMES.contains(&visitor_name)) {
+                    visitors.push(create_visitor());
                 }
+            }
 
-                if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                    if let Some(source) = file.input.sources.get(&source_path) {
-                        source_unit.source = Some(source.content.clone());
-                        source_units.push(source_unit);
-                    }
-                }
+            AstVisitorData {
+                analyzed_paths: HashSet::new(),
+                unhandled_node_types: BTreeMap::new(),
+                visitors
             }
-        }
-    } else if truffle_config_path.is_file() {
-        let build_path = path.join("build").join("contracts");
+        };
 
-        if !build_path.exists() || !build_path.is_dir() {
-            todo!("truffle project not compiled")
-        }
+        let ast_index = AstIndex::new(compilation.source_units.as_slice());
 
-        let migrations_path = PathBuf::new()
-            .join("contracts")
-            .join("Migrations.sol")
-            .to_string_lossy()
-            .to_string();
+        let mut solidity_data = create_visitor_data(false);
+        let mut yul_data = create_visitor_data(true);
 
-        for path in std::fs::read_dir(build_path)? {
-            let path = path?.path();
+        for source_unit in compilation.source_units.iter() {
+            //
+            // Skip excluded paths, such as dependencies, tests and mocks
+            //
 
-            if !path.is_file() || !path.extension().map(|extension| extension == "json").unwrap_or(false) {
+            if !path_filter.is_included(source_unit.absolute_path.as_deref().unwrap_or("")) {
                 continue;
             }
 
-            let file: truffle::File = simd_json::from_reader(File::open(path)?)?;
-
-            if let Some(mut source_unit) = file.ast {
-                if source_unit.absolute_path.as_deref().unwrap_or("").ends_with(migrations_path.as_str()) {
+            if let Some(contract_name) = contract_name.as_deref() {
+                if !source_unit.contract_definitions().iter().any(|c| c.name == contract_name) {
                     continue;
                 }
-                
-                if let Some(contract_name) = contract_name.as_deref() {
-                    if !source_unit.contract_definitions().iter().any(|c| c.name == contract_name) {
-                        continue;
-                    }
+            }
+
+            //
+            // Don't analyze the same source unit multiple times, unless analyzing each conflicting variant was requested
+            //
+
+            if let Some(path) = source_unit.absolute_path.as_ref() {
+                let variant = (path.clone(), source_unit.source.clone(), compilation.compiler.version.clone());
+
+                if analyzed_paths.contains(path) && !(should_analyze_source_variants && conflicting_paths.contains(path)) {
+                    continue;
                 }
 
-                if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
-                    source_unit.source = file.source.clone();
-                    source_units.push(source_unit);
+                if analyzed_variants.contains(&variant) {
+                    continue;
                 }
-            }
-        }
-    } else {
-        unimplemented!("no supported project configuration found")
-    }
 
-    source_units.sort_by(|lhs, rhs| {
-        let lhs = lhs.absolute_path.as_deref().unwrap_or("");
-        let rhs = rhs.absolute_path.as_deref().unwrap_or("");
-        lhs.cmp(rhs)
-    });
+                analyzed_paths.insert(path.clone());
+                analyzed_variants.push(variant);
+            }
 
-    if should_print_todo_list {
-        todo_list::print(source_units.as_slice());
-    }
+            let data = if source_unit.is_yul() { &mut yul_data } else { &mut solidity_data };
 
-    let mut visitors: Vec<Box<dyn AstVisitor>> = vec![
-        Box::new(analysis::SourceUnitVisitor::default()),
-    ];
+            if let Some(path) = source_unit.absolute_path.as_ref() {
+                data.analyzed_paths.insert(path.clone());
+            }
 
-    for &(visitor_name, create_visitor) in VISITOR_TYPES {
-        if visitor_names.is_empty() || visitor_names.contains(visitor_name) {
-            visitors.push(create_visitor());
-        }
-    }
+            //
+            // Visit the source unit, resolving references only within its own compilation
+            //
 
-    let mut data = AstVisitorData {
-        analyzed_paths: HashSet::new(),
-        visitors
-    };
+            let mut context = SourceUnitContext {
+                source_units: compilation.source_units.as_slice(),
+                ast_index: &ast_index,
+                current_source_unit: source_unit
+            };
 
-    for source_unit in source_units.iter() {
-        //
-        // Skip node_modules imports
-        //
+            data.visit_source_unit(&mut context)?;
+            data.leave_source_unit(&mut context)?;
+        }
 
-        if source_unit.absolute_path.as_deref().unwrap_or("").starts_with('@') {
-            continue;
+        for (node_type, count) in solidity_data.unhandled_node_types.into_iter().chain(yul_data.unhandled_node_types) {
+            *unhandled_node_types.entry(node_type).or_insert(0) += count;
         }
+    }
 
-        //
-        // Don't analyze the same source unit multiple times
-        //
+    //
+    // List every node type which is not modeled yet, so gaps in AST coverage can be spotted across a whole project
+    //
 
-        if let Some(path) = source_unit.absolute_path.as_ref() {
-            if data.analyzed_paths.contains(path) {
-                continue;
-            }
+    if should_list_unhandled_nodes {
+        if unhandled_node_types.is_empty() {
+            println!("No unhandled node types found");
+        } else {
+            println!("Unhandled node types:");
 
-            data.analyzed_paths.insert(path.clone());
+            for (node_type, count) in unhandled_node_types.iter() {
+                println!("\t{} ({} occurrence{})", node_type, count, if *count == 1 { "" } else { "s" });
+            }
         }
 
-        //
-        // Visit the source unit
-        //
-
-        let mut context = SourceUnitContext {
-            source_units: source_units.as_slice(),
-            current_source_unit: source_unit
-        };
-
-        data.visit_source_unit(&mut context)?;
-        data.leave_source_unit(&mut context)?;
+        println!();
     }
 
     Ok(())
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDocEntry {
+    pub author: Option<String>,
+    pub details: Option<String>,
+    pub params: Option<HashMap<String, String>>,
+    pub returns: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    pub kind: Option<String>,
+    pub version: Option<u64>,
+    pub author: Option<String>,
+    pub details: Option<String>,
+    pub title: Option<String>,
+    pub methods: Option<HashMap<String, DevDocEntry>>,
+    pub events: Option<HashMap<String, DevDocEntry>>,
+    pub errors: Option<HashMap<String, Vec<DevDocEntry>>>,
+    pub state_variables: Option<HashMap<String, DevDocEntry>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum UserDocEntry {
+    Notice(String),
+    Entry { notice: Option<String> },
+}
+
+impl UserDocEntry {
+    pub fn notice(&self) -> Option<&str> {
+        match self {
+            UserDocEntry::Notice(notice) => Some(notice.as_str()),
+            UserDocEntry::Entry { notice } => notice.as_deref(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDoc {
+    pub kind: Option<String>,
+    pub version: Option<u64>,
+    pub notice: Option<String>,
+    pub methods: Option<HashMap<String, UserDocEntry>>,
+    pub events: Option<HashMap<String, UserDocEntry>>,
+    pub errors: Option<HashMap<String, Vec<UserDocEntry>>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NatSpecEntry {
+    pub author: Option<String>,
+    pub details: Option<String>,
+    pub notice: Option<String>,
+    pub params: Option<HashMap<String, String>>,
+    pub returns: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NatSpec {
+    pub author: Option<String>,
+    pub details: Option<String>,
+    pub notice: Option<String>,
+    pub title: Option<String>,
+    pub methods: Option<HashMap<String, NatSpecEntry>>,
+}
+
+//
+// Brownie merges the user and developer documentation of a contract into a single object
+//
+
+impl NatSpec {
+    pub fn userdoc(&self) -> UserDoc {
+        UserDoc {
+            notice: self.notice.clone(),
+            methods: self.methods.as_ref().map(|methods| {
+                methods
+                    .iter()
+                    .filter(|(_, entry)| entry.notice.is_some())
+                    .map(|(signature, entry)| (signature.clone(), UserDocEntry::Entry { notice: entry.notice.clone() }))
+                    .collect()
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub fn devdoc(&self) -> DevDoc {
+        DevDoc {
+            author: self.author.clone(),
+            details: self.details.clone(),
+            title: self.title.clone(),
+            methods: self.methods.as_ref().map(|methods| {
+                methods
+                    .iter()
+                    .map(|(signature, entry)| {
+                        (
+                            signature.clone(),
+                            DevDocEntry {
+                                author: entry.author.clone(),
+                                details: entry.details.clone(),
+                                params: entry.params.clone(),
+                                returns: entry.returns.clone(),
+                            },
+                        )
+                    })
+                    .collect()
+            }),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use solidity::ast::*;
+
+use crate::{brownie, foundry, hardhat, solc, sources, truffle};
+
+#[derive(Debug)]
+pub enum ProjectError {
+    NoConfigFound(PathBuf),
+    NotCompiled {
+        project_kind: &'static str,
+        build_path: PathBuf,
+    },
+    InvalidConfig {
+        path: PathBuf,
+        message: String,
+    },
+    MalformedArtifact {
+        path: PathBuf,
+        message: String,
+    },
+    MalformedAst {
+        path: PathBuf,
+        error: DeserializationError,
+    },
+    InvalidPattern {
+        pattern: String,
+        message: String,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectError::NoConfigFound(path) => write!(f, "No supported project configuration found in {}", path.to_string_lossy()),
+            ProjectError::NotCompiled { project_kind, build_path } => write!(f, "{} project not compiled: {} not found", project_kind, build_path.to_string_lossy()),
+            ProjectError::InvalidConfig { path, message } => write!(f, "Invalid config file {}: {}", path.to_string_lossy(), message),
+            ProjectError::MalformedArtifact { path, message } => write!(f, "Malformed artifact file {}: {}", path.to_string_lossy(), message),
+            ProjectError::MalformedAst { path, error } => write!(f, "Malformed AST in artifact file {}: {}", path.to_string_lossy(), error),
+            ProjectError::InvalidPattern { pattern, message } => write!(f, "Invalid path pattern {}: {}", pattern, message),
+            ProjectError::Io(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ProjectError {}
+
+impl From<io::Error> for ProjectError {
+    fn from(error: io::Error) -> Self {
+        ProjectError::Io(error)
+    }
+}
+
+impl From<ProjectError> for io::Error {
+    fn from(error: ProjectError) -> Self {
+        let kind = match &error {
+            ProjectError::NoConfigFound(_) | ProjectError::NotCompiled { .. } => io::ErrorKind::NotFound,
+            ProjectError::InvalidConfig { .. } | ProjectError::MalformedArtifact { .. } | ProjectError::MalformedAst { .. } => io::ErrorKind::InvalidData,
+            ProjectError::InvalidPattern { .. } => io::ErrorKind::InvalidInput,
+            ProjectError::Io(error) => error.kind(),
+        };
+
+        match error {
+            ProjectError::Io(error) => error,
+            error => io::Error::new(kind, error.to_string()),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ProjectError>;
+
+#[derive(Clone, Debug, Default)]
+pub struct CompilerMetadata {
+    pub version: Option<String>,
+    pub long_version: Option<String>,
+    pub settings: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Artifact {
+    pub path: PathBuf,
+    pub compiler: CompilerMetadata,
+    pub source_units: Vec<SourceUnit>,
+    pub contracts: HashMap<String, HashMap<String, hardhat::Contract>>,
+    pub skipped_source_units: Vec<SkippedSourceUnit>,
+    pub brownie: Option<brownie::BuildData>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SkippedSourceUnit {
+    pub artifact_path: PathBuf,
+    pub absolute_path: Option<String>,
+    pub message: String,
+}
+
+//
+// When lenient, source units which fail to deserialize are skipped instead of failing the whole run
+//
+
+#[derive(Clone, Debug, Default)]
+pub struct LoadOptions {
+    pub lenient: bool,
+}
+
+impl Artifact {
+    pub fn contracts(&self, source_unit: &SourceUnit) -> Option<&HashMap<String, hardhat::Contract>> {
+        self.contracts.get(source_unit.absolute_path.as_deref()?)
+    }
+
+    pub fn contract(&self, source_unit: &SourceUnit, contract_name: &str) -> Option<&hardhat::Contract> {
+        self.contracts(source_unit)?.get(contract_name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Compilation {
+    pub id: Option<String>,
+    pub compiler: CompilerMetadata,
+    pub source_units: Vec<SourceUnit>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Project {
+    pub artifacts: Vec<Artifact>,
+}
+
+impl Compilation {
+    pub fn description(&self) -> String {
+        let mut result = format!("solc {}", self.compiler.version.as_deref().unwrap_or("<unknown>"));
+
+        if let Some(id) = self.id.as_ref() {
+            result.push_str(format!(", compilation {}", id).as_str());
+        }
+
+        result
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SourceVariant {
+    pub artifact_path: PathBuf,
+    pub compilation_id: Option<String>,
+    pub solc_version: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SourceConflict {
+    pub absolute_path: String,
+    pub variants: Vec<SourceVariant>,
+}
+
+//
+// An unknown source text or compiler version is treated as matching any other, since it can't be told apart
+//
+
+fn is_same_or_unknown(lhs: Option<&String>, rhs: Option<&String>) -> bool {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => lhs == rhs,
+        _ => true,
+    }
+}
+
+impl Project {
+    pub fn compilations(&self) -> Vec<Compilation> {
+        let mut compilations: Vec<Compilation> = vec![];
+
+        for artifact in self.artifacts.iter() {
+            'source_units: for source_unit in artifact.source_units.iter() {
+                let mut compilation_index = None;
+
+                for (index, compilation) in compilations.iter().enumerate() {
+                    if compilation.id != source_unit.compilation_id || !is_same_or_unknown(compilation.compiler.version.as_ref(), artifact.compiler.version.as_ref()) {
+                        continue;
+                    }
+
+                    //
+                    // NodeIDs are only unique within a single compilation, so duplicates are only removed within one.
+                    // Conflicting variants of the same file are moved into a separate compilation.
+                    //
+
+                    match compilation.source_units.iter().find(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
+                        Some(existing_source_unit) if is_same_or_unknown(existing_source_unit.source.as_ref(), source_unit.source.as_ref()) => continue 'source_units,
+                        Some(_) => continue,
+
+                        None => {
+                            compilation_index = Some(index);
+                            break;
+                        }
+                    }
+                }
+
+                let compilation = match compilation_index {
+                    Some(index) => &mut compilations[index],
+
+                    None => {
+                        compilations.push(Compilation {
+                            id: source_unit.compilation_id.clone(),
+                            compiler: artifact.compiler.clone(),
+                            source_units: vec![],
+                        });
+
+                        compilations.last_mut().unwrap()
+                    }
+                };
+
+                compilation.source_units.push(source_unit.clone());
+            }
+        }
+
+        for compilation in compilations.iter_mut() {
+            compilation.source_units.sort_by(|lhs, rhs| {
+                let lhs = lhs.absolute_path.as_deref().unwrap_or("");
+                let rhs = rhs.absolute_path.as_deref().unwrap_or("");
+                lhs.cmp(rhs)
+            });
+        }
+
+        compilations
+    }
+
+    pub fn source_conflicts(&self) -> Vec<SourceConflict> {
+        let mut conflicts: Vec<SourceConflict> = vec![];
+
+        for artifact in self.artifacts.iter() {
+            for source_unit in artifact.source_units.iter() {
+                let absolute_path = match source_unit.absolute_path.as_ref() {
+                    Some(absolute_path) => absolute_path,
+                    None => continue,
+                };
+
+                let variant = SourceVariant {
+                    artifact_path: artifact.path.clone(),
+                    compilation_id: source_unit.compilation_id.clone(),
+                    solc_version: artifact.compiler.version.clone(),
+                    source: source_unit.source.clone(),
+                };
+
+                let conflict = match conflicts.iter_mut().position(|conflict| &conflict.absolute_path == absolute_path) {
+                    Some(index) => &mut conflicts[index],
+
+                    None => {
+                        conflicts.push(SourceConflict {
+                            absolute_path: absolute_path.clone(),
+                            variants: vec![],
+                        });
+
+                        conflicts.last_mut().unwrap()
+                    }
+                };
+
+                if !conflict.variants.iter().any(|existing_variant| {
+                    is_same_or_unknown(existing_variant.solc_version.as_ref(), variant.solc_version.as_ref()) &&
+                    is_same_or_unknown(existing_variant.source.as_ref(), variant.source.as_ref())
+                }) {
+                    conflict.variants.push(variant);
+                }
+            }
+        }
+
+        conflicts.retain(|conflict| conflict.variants.len() > 1);
+        conflicts.sort_by(|lhs, rhs| lhs.absolute_path.cmp(&rhs.absolute_path));
+
+        conflicts
+    }
+
+    pub fn skipped_source_units(&self) -> impl Iterator<Item = &SkippedSourceUnit> {
+        self.artifacts.iter().flat_map(|artifact| artifact.skipped_source_units.iter())
+    }
+
+    pub fn source_units(&self) -> Vec<SourceUnit> {
+        let mut source_units: Vec<SourceUnit> = vec![];
+
+        for artifact in self.artifacts.iter() {
+            for source_unit in artifact.source_units.iter() {
+                if !source_units.iter().any(|existing_source_unit| existing_source_unit.absolute_path == source_unit.absolute_path) {
+                    source_units.push(source_unit.clone());
+                }
+            }
+        }
+
+        source_units.sort_by(|lhs, rhs| {
+            let lhs = lhs.absolute_path.as_deref().unwrap_or("");
+            let rhs = rhs.absolute_path.as_deref().unwrap_or("");
+            lhs.cmp(rhs)
+        });
+
+        source_units
+    }
+}
+
+pub trait ProjectLoader {
+    fn name(&self) -> &'static str;
+    fn detect(&self, root_path: &Path) -> bool;
+    fn artifact_paths(&self, root_path: &Path) -> Result<Vec<PathBuf>>;
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> Result<Artifact>;
+
+    fn exclude_patterns(&self, _root_path: &Path) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    fn load(&self, root_path: &Path, options: &LoadOptions) -> Result<Project> {
+        let mut project = Project::default();
+
+        for artifact_path in self.artifact_paths(root_path)? {
+            project.artifacts.push(self.load_artifact(root_path, artifact_path.as_path(), options)?);
+        }
+
+        Ok(project)
+    }
+}
+
+pub fn loaders() -> Vec<Box<dyn ProjectLoader>> {
+    vec![
+        Box::new(brownie::BrownieLoader),
+        Box::new(hardhat::HardhatLoader),
+        Box::new(truffle::TruffleLoader),
+        Box::new(foundry::FoundryLoader),
+        Box::new(solc::DapptoolsLoader),
+        Box::new(sources::SourcesLoader),
+    ]
+}
+
+pub fn detect(root_path: &Path) -> Result<Box<dyn ProjectLoader>> {
+    loaders()
+        .into_iter()
+        .find(|loader| loader.detect(root_path))
+        .ok_or_else(|| ProjectError::NoConfigFound(root_path.to_path_buf()))
+}
+
+//
+// Reads an artifact file, along with the source units that were skipped because they failed to deserialize
+//
+
+pub fn read_artifact<T: DeserializeOwned>(path: &Path, options: &LoadOptions) -> Result<(T, Vec<SkippedSourceUnit>)> {
+    let error = match simd_json::from_reader(File::open(path)?) {
+        Ok(result) => return Ok((result, vec![])),
+        Err(error) => error,
+    };
+
+    //
+    // Artifacts which fail to deserialize are read again as plain JSON to find out which AST node is malformed
+    //
+
+    let malformed_artifact = || ProjectError::MalformedArtifact {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    };
+
+    let mut value: Value = match simd_json::from_reader(File::open(path)?) {
+        Ok(value) => value,
+        Err(_) => return Err(malformed_artifact()),
+    };
+
+    let ast_error = match solidity::ast::locate_deserialization_error(&value) {
+        Some(ast_error) => ast_error,
+        None => return Err(malformed_artifact()),
+    };
+
+    if !options.lenient {
+        return Err(ProjectError::MalformedAst { path: path.to_path_buf(), error: ast_error });
+    }
+
+    let mut pointers = vec![];
+    let mut skipped_source_units = vec![];
+    collect_malformed_source_units(path, &value, &mut String::new(), &mut pointers, &mut skipped_source_units);
+
+    if pointers.is_empty() {
+        return Err(ProjectError::MalformedAst { path: path.to_path_buf(), error: ast_error });
+    }
+
+    //
+    // Optional source units are replaced with null, otherwise the entry containing them is removed
+    //
+
+    for pointer in pointers.iter() {
+        if let Some(source_unit) = value.pointer_mut(pointer.as_str()) {
+            *source_unit = Value::Null;
+        }
+    }
+
+    if let Ok(result) = T::deserialize(&value) {
+        return Ok((result, skipped_source_units));
+    }
+
+    for pointer in pointers.iter() {
+        let entry_pointer = match pointer.rsplit_once('/') {
+            Some((entry_pointer, _)) => entry_pointer,
+            None => continue,
+        };
+
+        let (container_pointer, key) = match entry_pointer.rsplit_once('/') {
+            Some((container_pointer, key)) => (container_pointer, key.replace("~1", "/").replace("~0", "~")),
+            None => continue,
+        };
+
+        if let Some(Value::Object(container)) = value.pointer_mut(container_pointer) {
+            container.remove(key.as_str());
+        }
+    }
+
+    T::deserialize(&value)
+        .map(|result| (result, skipped_source_units))
+        .map_err(|_| ProjectError::MalformedAst { path: path.to_path_buf(), error: ast_error })
+}
+
+fn collect_malformed_source_units(
+    path: &Path,
+    value: &Value,
+    pointer: &mut String,
+    pointers: &mut Vec<String>,
+    skipped_source_units: &mut Vec<SkippedSourceUnit>,
+) {
+    match value {
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let length = pointer.len();
+                pointer.push_str(format!("/{}", index).as_str());
+                collect_malformed_source_units(path, value, pointer, pointers, skipped_source_units);
+                pointer.truncate(length);
+            }
+        }
+
+        Value::Object(fields) if fields.get("nodeType").and_then(Value::as_str) == Some("SourceUnit") => {
+            if let Err(error) = solidity::ast::deserialize_source_unit(value) {
+                skipped_source_units.push(SkippedSourceUnit {
+                    artifact_path: path.to_path_buf(),
+                    absolute_path: fields.get("absolutePath").and_then(Value::as_str).map(str::to_string),
+                    message: error.to_string(),
+                });
+
+                pointers.push(pointer.clone());
+            }
+        }
+
+        Value::Object(fields) => {
+            for (key, value) in fields.iter() {
+                let length = pointer.len();
+                pointer.push('/');
+                pointer.push_str(key.replace('~', "~0").replace('/', "~1").as_str());
+                collect_malformed_source_units(path, value, pointer, pointers, skipped_source_units);
+                pointer.truncate(length);
+            }
+        }
+
+        _ => {}
+    }
+}
+
+pub fn json_file_paths(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut result = vec![];
+
+    for path in std::fs::read_dir(path)? {
+        let path = path?.path();
+
+        if path.is_file() && path.extension().map(|extension| extension == "json").unwrap_or(false) {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
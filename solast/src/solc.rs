@@ -0,0 +1,171 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use solidity::ast::*;
+
+use crate::{
+    hardhat,
+    project::{self, Artifact, CompilerMetadata, LoadOptions, ProjectError, ProjectLoader},
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Source {
+    #[serde(rename = "AST", alias = "ast")]
+    pub ast: SourceUnit,
+    pub id: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    #[serde(default)]
+    pub contracts: HashMap<String, serde_json::Value>,
+    pub source_list: Option<Vec<String>>,
+    #[serde(default)]
+    pub sources: HashMap<String, Source>,
+    pub version: Option<String>,
+}
+
+impl File {
+    pub fn into_artifact(self, root_path: &Path, artifact_path: &Path) -> Artifact {
+        let mut source_units = vec![];
+
+        for (source_path, source) in self.sources {
+            let mut source_unit = source.ast;
+            source_unit.set_source(std::fs::read_to_string(root_path.join(source_path)).ok());
+            source_units.push(source_unit);
+        }
+
+        Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler: CompilerMetadata {
+                version: self.version,
+                ..Default::default()
+            },
+            source_units,
+            contracts: HashMap::new(),
+            skipped_source_units: vec![],
+            brownie: None,
+        }
+    }
+}
+
+pub struct StandardJsonLoader {
+    pub input_path: Option<PathBuf>,
+    pub output_path: PathBuf,
+}
+
+impl ProjectLoader for StandardJsonLoader {
+    fn name(&self) -> &'static str {
+        "Standard JSON"
+    }
+
+    fn detect(&self, _root_path: &Path) -> bool {
+        self.output_path.is_file()
+    }
+
+    fn artifact_paths(&self, _root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        Ok(vec![self.output_path.clone()])
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let (output, skipped_source_units) = project::read_artifact::<hardhat::Output>(artifact_path, options)?;
+
+        let input: Option<hardhat::Input> = match self.input_path.as_ref() {
+            Some(input_path) => Some(project::read_artifact(input_path, options)?.0),
+            None => None,
+        };
+
+        let mut source_units = vec![];
+        let contracts = output.contracts;
+
+        for (source_path, source) in output.sources {
+            let mut source_unit = source.ast;
+
+            source_unit.set_source(
+                input.as_ref()
+                    .and_then(|input| input.sources.get(&source_path))
+                    .and_then(|source| source.content.clone())
+                    .or_else(|| std::fs::read_to_string(root_path.join(&source_path)).ok())
+            );
+
+            source_units.push(source_unit);
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler: CompilerMetadata {
+                settings: input.map(|input| input.settings),
+                ..Default::default()
+            },
+            source_units,
+            contracts,
+            skipped_source_units,
+            brownie: None,
+        })
+    }
+}
+
+pub struct CombinedJsonLoader {
+    pub path: PathBuf,
+}
+
+impl ProjectLoader for CombinedJsonLoader {
+    fn name(&self) -> &'static str {
+        "Combined JSON"
+    }
+
+    fn detect(&self, _root_path: &Path) -> bool {
+        self.path.is_file()
+    }
+
+    fn artifact_paths(&self, _root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        Ok(vec![self.path.clone()])
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+
+        Ok(Artifact {
+            skipped_source_units,
+            ..file.into_artifact(root_path, artifact_path)
+        })
+    }
+}
+
+pub struct DapptoolsLoader;
+
+impl ProjectLoader for DapptoolsLoader {
+    fn name(&self) -> &'static str {
+        "Dapptools"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        root_path.join(".dapprc").is_file() || root_path.join("out").join("dapp.sol.json").is_file()
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        let output_path = root_path.join("out").join("dapp.sol.json");
+
+        if !output_path.is_file() {
+            return Err(ProjectError::NotCompiled {
+                project_kind: self.name(),
+                build_path: output_path,
+            });
+        }
+
+        Ok(vec![output_path])
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+
+        Ok(Artifact {
+            skipped_source_units,
+            ..file.into_artifact(root_path, artifact_path)
+        })
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use solidity::{ast::SourceUnit, parser::{self, Parser}};
+
+use crate::project::{self, Artifact, CompilerMetadata, LoadOptions, Project, ProjectLoader, SkippedSourceUnit};
+
+const IMPORT_SEARCH_PATHS: &[&str] = &["", "node_modules", "lib"];
+
+fn is_yul_file(path: &Path) -> bool {
+    path.extension().map(|extension| extension == "yul").unwrap_or(false)
+}
+
+fn is_source_file(path: &Path) -> bool {
+    path.is_file() && (is_yul_file(path) || path.extension().map(|extension| extension == "sol").unwrap_or(false))
+}
+
+fn is_skipped_directory(path: &Path) -> bool {
+    path.file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .map(|file_name| file_name.starts_with('.') || file_name == "node_modules")
+        .unwrap_or(false)
+}
+
+pub fn source_file_paths(path: &Path) -> project::Result<Vec<PathBuf>> {
+    let mut result = vec![];
+
+    for path in std::fs::read_dir(path)? {
+        let path = path?.path();
+
+        if path.is_dir() {
+            if !is_skipped_directory(path.as_path()) {
+                result.extend(source_file_paths(path.as_path())?);
+            }
+        } else if is_source_file(path.as_path()) {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+
+    Ok(result)
+}
+
+//
+// Stops at the first source file found. Dependency directories are not searched, since sources that only exist
+// there do not make the directory a project of its own
+//
+
+fn contains_source_file(path: &Path) -> bool {
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            let is_dependency_directory = path.file_name().map(|file_name| file_name == "lib").unwrap_or(false);
+
+            if !is_skipped_directory(path.as_path()) && !is_dependency_directory && contains_source_file(path.as_path()) {
+                return true;
+            }
+        } else if is_source_file(path.as_path()) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct SourcesLoader;
+
+impl SourcesLoader {
+    fn base_path(root_path: &Path) -> &Path {
+        if root_path.is_file() {
+            root_path.parent().unwrap_or_else(|| Path::new(""))
+        } else {
+            root_path
+        }
+    }
+
+    fn absolute_path(root_path: &Path, source_path: &Path) -> String {
+        source_path.strip_prefix(Self::base_path(root_path))
+            .unwrap_or(source_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    fn find_import(root_path: &Path, source_path: &Path, file: &str, absolute_path: &str) -> Option<PathBuf> {
+        //
+        // Relative imports are looked up next to the importing file first, since they may point outside of the base path
+        //
+
+        if file.starts_with("./") || file.starts_with("../") {
+            if let Some(import_source_path) = source_path.parent().map(|parent_path| parent_path.join(file)).filter(|path| path.is_file()) {
+                return Some(import_source_path);
+            }
+        }
+
+        IMPORT_SEARCH_PATHS
+            .iter()
+            .map(|search_path| Self::base_path(root_path).join(search_path).join(absolute_path))
+            .find(|source_path| source_path.is_file())
+    }
+}
+
+impl ProjectLoader for SourcesLoader {
+    fn name(&self) -> &'static str {
+        "Solidity and Yul sources"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        if root_path.is_file() {
+            return is_source_file(root_path);
+        }
+
+        contains_source_file(root_path)
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        if root_path.is_file() {
+            return Ok(vec![root_path.to_path_buf()]);
+        }
+
+        source_file_paths(root_path)
+    }
+
+    fn load_artifact(&self, root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let source = std::fs::read_to_string(artifact_path)?;
+        let absolute_path = Self::absolute_path(root_path, artifact_path);
+
+        let result = if is_yul_file(artifact_path) {
+            Parser::default().parse_yul(source.as_str(), absolute_path.as_str(), 0)
+        } else {
+            parser::parse_source_unit(source.as_str(), absolute_path.as_str())
+        };
+
+        let mut source_units = vec![];
+        let mut skipped_source_units = vec![];
+
+        match result {
+            Ok(source_unit) => source_units.push(source_unit),
+
+            Err(error) if options.lenient => skipped_source_units.push(SkippedSourceUnit {
+                artifact_path: artifact_path.to_path_buf(),
+                absolute_path: Some(absolute_path),
+                message: error.to_string(),
+            }),
+
+            Err(error) => return Err(error.into()),
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler: CompilerMetadata::default(),
+            source_units,
+            contracts: HashMap::new(),
+            skipped_source_units,
+            brownie: None,
+        })
+    }
+
+    fn load(&self, root_path: &Path, options: &LoadOptions) -> project::Result<Project> {
+        //
+        // All files are parsed together so NodeIDs are unique and references can be resolved across imports
+        //
+
+        let mut parser = Parser::default();
+        let mut source_units: Vec<SourceUnit> = vec![];
+        let mut skipped_source_units: Vec<SkippedSourceUnit> = vec![];
+
+        let mut pending_paths: Vec<(String, PathBuf)> = self.artifact_paths(root_path)?
+            .into_iter()
+            .map(|source_path| (Self::absolute_path(root_path, source_path.as_path()), source_path))
+            .collect();
+
+        pending_paths.reverse();
+
+        while let Some((absolute_path, source_path)) = pending_paths.pop() {
+            if source_units.iter().any(|source_unit| source_unit.absolute_path.as_deref() == Some(absolute_path.as_str())) ||
+                skipped_source_units.iter().any(|skipped_source_unit| skipped_source_unit.absolute_path.as_deref() == Some(absolute_path.as_str())) {
+                continue;
+            }
+
+            let source = std::fs::read_to_string(source_path.as_path())?;
+
+            let result = if is_yul_file(source_path.as_path()) {
+                parser.parse_yul(source.as_str(), absolute_path.as_str(), source_units.len())
+            } else {
+                parser.parse(source.as_str(), absolute_path.as_str(), source_units.len())
+            };
+
+            let source_unit = match result {
+                Ok(source_unit) => source_unit,
+
+                Err(error) if options.lenient => {
+                    skipped_source_units.push(SkippedSourceUnit {
+                        artifact_path: source_path,
+                        absolute_path: Some(absolute_path),
+                        message: error.to_string(),
+                    });
+
+                    continue;
+                }
+
+                Err(error) => return Err(error.into()),
+            };
+
+            for import_directive in source_unit.import_directives() {
+                let import_path = match import_directive.absolute_path.as_ref() {
+                    Some(import_path) => import_path,
+                    None => continue,
+                };
+
+                if let Some(import_source_path) = Self::find_import(root_path, source_path.as_path(), import_directive.file.as_str(), import_path) {
+                    pending_paths.push((import_path.clone(), import_source_path));
+                }
+            }
+
+            source_units.push(source_unit);
+        }
+
+        parser::resolve(source_units.as_mut_slice());
+
+        Ok(Project {
+            artifacts: vec![Artifact {
+                path: root_path.to_path_buf(),
+                compiler: CompilerMetadata::default(),
+                source_units,
+                contracts: HashMap::new(),
+                skipped_source_units,
+                brownie: None,
+            }],
+        })
+    }
+}
@@ -1,11 +1,42 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
 use serde::{Deserialize, Serialize};
 use solidity::ast::*;
 
+use crate::{
+    abi::AbiEntry,
+    hardhat,
+    natspec::{DevDoc, UserDoc},
+    project::{self, Artifact, CompilerMetadata, LoadOptions, ProjectError, ProjectLoader},
+};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Compiler {
+    pub name: Option<String>,
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Network {
+    pub address: Option<String>,
+    pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub events: HashMap<String, AbiEntry>,
+    #[serde(default)]
+    pub links: HashMap<String, String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct File {
     pub contract_name: Option<String>,
-    // TODO: abi
+    #[serde(default)]
+    pub abi: Vec<AbiEntry>,
     pub metadata: Option<String>,
     pub bytecode: Option<String>,
     pub deployed_bytecode: Option<String>,
@@ -14,10 +45,100 @@ pub struct File {
     pub source: Option<String>,
     pub source_path: Option<String>,
     pub ast: Option<SourceUnit>,
-    // TODO: compiler
-    // TODO: networks
-    // TODO: schemaVersion
-    // TODO: updatedAt
-    // TODO: devdoc
-    // TODO: userdoc
+    pub compiler: Option<Compiler>,
+    #[serde(default)]
+    pub networks: HashMap<String, Network>,
+    pub schema_version: Option<String>,
+    pub updated_at: Option<String>,
+    pub network_type: Option<String>,
+    pub devdoc: Option<DevDoc>,
+    pub userdoc: Option<UserDoc>,
+}
+
+impl File {
+    pub fn contract(&self) -> hardhat::Contract {
+        hardhat::Contract {
+            abi: self.abi.clone(),
+            metadata: self.metadata.clone(),
+            userdoc: self.userdoc.clone(),
+            devdoc: self.devdoc.clone(),
+            evm: Some(hardhat::Evm {
+                bytecode: Some(hardhat::Bytecode {
+                    object: self.bytecode.clone(),
+                    source_map: self.source_map.clone(),
+                    ..Default::default()
+                }),
+                deployed_bytecode: Some(hardhat::Bytecode {
+                    object: self.deployed_bytecode.clone(),
+                    source_map: self.deployed_source_map.clone(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub fn compiler_settings(&self) -> Option<serde_json::Value> {
+        let metadata: serde_json::Value = serde_json::from_str(self.metadata.as_deref()?).ok()?;
+        metadata.get("settings").cloned()
+    }
+}
+
+pub struct TruffleLoader;
+
+impl ProjectLoader for TruffleLoader {
+    fn name(&self) -> &'static str {
+        "Truffle"
+    }
+
+    fn detect(&self, root_path: &Path) -> bool {
+        root_path.join("truffle-config.js").is_file()
+    }
+
+    fn artifact_paths(&self, root_path: &Path) -> project::Result<Vec<PathBuf>> {
+        let build_path = root_path.join("build").join("contracts");
+
+        if !build_path.is_dir() {
+            return Err(ProjectError::NotCompiled {
+                project_kind: self.name(),
+                build_path,
+            });
+        }
+
+        project::json_file_paths(build_path.as_path())
+    }
+
+    fn load_artifact(&self, _root_path: &Path, artifact_path: &Path, options: &LoadOptions) -> project::Result<Artifact> {
+        let (file, skipped_source_units) = project::read_artifact::<File>(artifact_path, options)?;
+
+        let long_version = file.compiler.as_ref().and_then(|compiler| compiler.version.clone());
+
+        let compiler = CompilerMetadata {
+            version: long_version.as_deref().and_then(|long_version| long_version.split('+').next()).map(str::to_string),
+            long_version,
+            settings: file.compiler_settings(),
+        };
+
+        let mut source_units = vec![];
+        let mut contracts = HashMap::new();
+
+        if let (Some(absolute_path), Some(contract_name)) = (file.ast.as_ref().and_then(|ast| ast.absolute_path.clone()), file.contract_name.clone()) {
+            contracts.insert(absolute_path, HashMap::from([(contract_name, file.contract())]));
+        }
+
+        if let Some(mut source_unit) = file.ast {
+            source_unit.set_source(file.source);
+            source_units.push(source_unit);
+        }
+
+        Ok(Artifact {
+            path: artifact_path.to_path_buf(),
+            compiler,
+            source_units,
+            contracts,
+            skipped_source_units,
+            brownie: None,
+        })
+    }
 }
@@ -0,0 +1,51 @@
+use solast::{
+    brownie::BrownieLoader,
+    project::{LoadOptions, ProjectError, ProjectLoader},
+};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+#[test]
+fn brownie_build_data_is_exposed_through_the_artifact() {
+    let project = BrownieLoader.load(fixture_path("brownie").as_path(), &LoadOptions::default()).unwrap();
+    let build_data = project.artifacts[0].brownie.as_ref().unwrap();
+
+    assert_eq!(build_data.contract_name.as_deref(), Some("Token"));
+    assert_eq!(build_data.dependencies, vec!["Ownable".to_string()]);
+    assert_eq!(build_data.pc_map["5"].function.as_deref(), Some("Token.f"));
+    assert_eq!(build_data.pc_map["5"].first_revert, Some(true));
+    assert_eq!(build_data.coverage_map.branches["0"]["Token.f"]["5"], (10, 20, true));
+    assert_eq!(build_data.coverage_map.statements["0"]["Token.f"]["1"], (3, 9));
+}
+
+#[test]
+fn dependencies_missing_from_the_build_folder_are_parsed_from_source() {
+    let project = BrownieLoader.load(fixture_path("brownie").as_path(), &LoadOptions::default()).unwrap();
+
+    let absolute_paths: Vec<Option<String>> = project.source_units()
+        .into_iter()
+        .map(|source_unit| source_unit.absolute_path)
+        .collect();
+
+    assert_eq!(absolute_paths, vec![Some("contracts/Ownable.sol".to_string()), Some("contracts/Token.sol".to_string())]);
+
+    let ownable = project.source_units().remove(0);
+    assert_eq!(ownable.contract_definitions()[0].name, "Ownable");
+    assert!(ownable.source.is_some());
+}
+
+#[test]
+fn brownie_projects_are_detected_by_their_config_file() {
+    assert!(BrownieLoader.detect(fixture_path("brownie").as_path()));
+    assert!(BrownieLoader.detect(fixture_path("brownie_not_compiled").as_path()));
+    assert!(!BrownieLoader.detect(fixture_path("foundry").as_path()));
+}
+
+#[test]
+fn missing_build_folders_are_reported_as_not_compiled() {
+    let error = BrownieLoader.load(fixture_path("brownie_not_compiled").as_path(), &LoadOptions::default()).unwrap_err();
+    assert!(matches!(error, ProjectError::NotCompiled { project_kind: "Brownie", .. }), "{}", error);
+}
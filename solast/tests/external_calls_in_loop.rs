@@ -0,0 +1,124 @@
+use std::{path::PathBuf, process::Command};
+
+const INTERFACE: &str = "
+    interface IToken {
+        function balanceOf(address owner) external view returns (uint256);
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+";
+
+//
+// Runs the external_calls_in_loop analyzer on a single source file and returns the reported lines
+//
+
+fn analyze(name: &str, contract: &str) -> Vec<String> {
+    let path: PathBuf = std::env::temp_dir().join(format!("solast-external-calls-in-loop-{}.sol", name));
+    std::fs::write(path.as_path(), format!("pragma solidity ^0.8.0;\n{}\n{}", INTERFACE, contract)).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solast"))
+        .arg("--external_calls_in_loop")
+        .arg(path.as_path())
+        .output()
+        .unwrap();
+
+    std::fs::remove_file(path.as_path()).unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter(|line| line.contains("makes an external call inside a loop"))
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+#[test]
+fn call_in_loop_body_is_reported() {
+    let lines = analyze("body", "
+        contract C {
+            IToken token;
+
+            function f(address[] calldata recipients) external {
+                for (uint256 i = 0; i < recipients.length; i++) {
+                    token.transfer(recipients[i], 1);
+                }
+            }
+        }
+    ");
+
+    assert_eq!(lines.len(), 1, "{:?}", lines);
+    assert!(lines[0].ends_with("`token.transfer(recipients[i], 1)`"), "{:?}", lines);
+}
+
+#[test]
+fn call_in_loop_condition_is_reported() {
+    let lines = analyze("condition", "
+        contract C {
+            IToken token;
+
+            function f() external view returns (uint256 count) {
+                while (token.balanceOf(address(this)) > count) {
+                    count++;
+                }
+            }
+        }
+    ");
+
+    assert_eq!(lines.len(), 1, "{:?}", lines);
+    assert!(lines[0].ends_with("`token.balanceOf(address(this))`"), "{:?}", lines);
+}
+
+#[test]
+fn nested_calls_in_loop_are_reported_separately() {
+    let lines = analyze("nested", "
+        contract C {
+            IToken token;
+
+            function f(address to) external {
+                for (uint256 i = 0; i < 2; i++) {
+                    do {
+                        token.transfer(to, token.balanceOf(address(this)));
+                    } while (false);
+                }
+            }
+        }
+    ");
+
+    assert_eq!(lines.len(), 2, "{:?}", lines);
+    assert!(lines.iter().any(|line| line.ends_with("`token.transfer(to, token.balanceOf(address(this)))`")), "{:?}", lines);
+    assert!(lines.iter().any(|line| line.ends_with("`token.balanceOf(address(this))`")), "{:?}", lines);
+}
+
+#[test]
+fn call_in_try_statement_inside_loop_is_reported() {
+    let lines = analyze("try", "
+        contract C {
+            IToken token;
+
+            function f(address to) external {
+                for (uint256 i = 0; i < 2; i++) {
+                    try token.transfer(to, 1) returns (bool) {} catch {}
+                }
+            }
+        }
+    ");
+
+    assert_eq!(lines.len(), 1, "{:?}", lines);
+    assert!(lines[0].ends_with("`token.transfer(to, 1)`"), "{:?}", lines);
+}
+
+#[test]
+fn call_outside_of_loop_is_not_reported() {
+    let lines = analyze("outside", "
+        contract C {
+            IToken token;
+
+            function f(address to) external {
+                token.transfer(to, 1);
+                for (uint256 i = 0; i < 2; i++) {}
+            }
+        }
+    ");
+
+    assert!(lines.is_empty(), "{:?}", lines);
+}
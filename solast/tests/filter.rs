@@ -0,0 +1,107 @@
+use solast::filter::{Config, PathFilter};
+
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|pattern| pattern.to_string()).collect()
+}
+
+fn default_filter() -> PathFilter {
+    PathFilter::from_config(&Config::default(), &[], &[], &[], true).unwrap()
+}
+
+#[test]
+fn default_excludes_skip_dependencies_tests_and_mocks() {
+    let filter = default_filter();
+
+    for path in [
+        "node_modules/@openzeppelin/contracts/token/ERC20/ERC20.sol",
+        "@openzeppelin/contracts/access/Ownable.sol",
+        "hardhat/console.sol",
+        "contracts/Migrations.sol",
+        "lib/forge-std/src/Test.sol",
+        "test/Token.t.sol",
+        "contracts/test/TokenTest.sol",
+        "contracts/mocks/MockToken.sol",
+        "mocks/MockToken.sol",
+    ] {
+        assert!(!filter.is_included(path), "{} should be excluded", path);
+    }
+
+    for path in ["contracts/Token.sol", "src/Token.sol", "contracts/lib/Math.sol", "./contracts/Token.sol"] {
+        assert!(filter.is_included(path), "{} should be included", path);
+    }
+}
+
+#[test]
+fn include_patterns_only_analyze_matching_paths() {
+    let filter = PathFilter::from_config(&Config::default(), &patterns(&["contracts/token/"]), &[], &[], true).unwrap();
+
+    assert!(filter.is_included("contracts/token/Token.sol"));
+    assert!(!filter.is_included("contracts/governance/Governor.sol"));
+}
+
+#[test]
+fn exclude_patterns_take_precedence_over_include_patterns() {
+    let filter = PathFilter::from_config(
+        &Config::default(),
+        &patterns(&["contracts/**"]),
+        &patterns(&["contracts/legacy/"]),
+        &[],
+        true,
+    ).unwrap();
+
+    assert!(filter.is_included("contracts/Token.sol"));
+    assert!(!filter.is_included("contracts/legacy/Token.sol"));
+    assert!(!filter.is_included("contracts/mocks/MockToken.sol"));
+}
+
+#[test]
+fn configured_patterns_are_merged_with_command_line_patterns() {
+    let config = Config {
+        include: Some(patterns(&["src/"])),
+        exclude: Some(patterns(&["src/legacy/"])),
+        default_excludes: None,
+    };
+
+    let filter = PathFilter::from_config(&config, &patterns(&["contracts/"]), &patterns(&["contracts/old/"]), &[], true).unwrap();
+
+    assert!(filter.is_included("src/Token.sol"));
+    assert!(filter.is_included("contracts/Token.sol"));
+    assert!(!filter.is_included("src/legacy/Token.sol"));
+    assert!(!filter.is_included("contracts/old/Token.sol"));
+    assert!(!filter.is_included("src/mocks/MockToken.sol"));
+}
+
+#[test]
+fn no_default_excludes_keeps_only_explicit_excludes() {
+    let filter = PathFilter::from_config(&Config::default(), &[], &patterns(&["contracts/old/"]), &patterns(&["script/"]), false).unwrap();
+
+    assert!(filter.is_included("lib/forge-std/src/Test.sol"));
+    assert!(filter.is_included("contracts/mocks/MockToken.sol"));
+    assert!(filter.is_included("script/Deploy.s.sol"));
+    assert!(!filter.is_included("contracts/old/Token.sol"));
+}
+
+#[test]
+fn default_excludes_can_be_turned_off_in_the_config() {
+    let config = Config {
+        default_excludes: Some(false),
+        ..Default::default()
+    };
+
+    let filter = PathFilter::from_config(&config, &[], &[], &patterns(&["script/"]), true).unwrap();
+
+    assert!(filter.is_included("test/Token.t.sol"));
+    assert!(filter.is_included("script/Deploy.s.sol"));
+}
+
+#[test]
+fn build_system_excludes_are_applied_with_the_defaults() {
+    let filter = PathFilter::from_config(&Config::default(), &[], &[], &patterns(&["script/"]), true).unwrap();
+
+    assert!(!filter.is_included("script/Deploy.s.sol"));
+}
+
+#[test]
+fn invalid_patterns_are_rejected() {
+    assert!(PathFilter::new(&patterns(&["contracts/["]), &[]).is_err());
+}
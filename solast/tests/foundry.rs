@@ -0,0 +1,58 @@
+use solast::{
+    foundry::FoundryLoader,
+    project::{LoadOptions, ProjectError, ProjectLoader},
+};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+#[test]
+fn contract_names_do_not_include_the_compiler_version() {
+    let project = FoundryLoader.load(fixture_path("foundry").as_path(), &LoadOptions::default()).unwrap();
+
+    let mut contract_names: Vec<(&str, &str)> = project.artifacts
+        .iter()
+        .flat_map(|artifact| artifact.contracts.iter())
+        .flat_map(|(absolute_path, contracts)| contracts.keys().map(move |contract_name| (absolute_path.as_str(), contract_name.as_str())))
+        .collect();
+
+    contract_names.sort();
+
+    assert_eq!(contract_names, vec![("src/Bar.sol", "Bar"), ("src/Foo.sol", "Foo")]);
+}
+
+#[test]
+fn sources_are_read_from_the_profile_source_folder() {
+    let project = FoundryLoader.load(fixture_path("foundry").as_path(), &LoadOptions::default()).unwrap();
+
+    for source_unit in project.source_units() {
+        assert!(source_unit.source.as_deref().unwrap().contains("contract "), "{:?}", source_unit.absolute_path);
+    }
+}
+
+#[test]
+fn per_contract_artifacts_of_files_in_build_info_are_dropped() {
+    let project = FoundryLoader.load(fixture_path("foundry_build_info").as_path(), &LoadOptions::default()).unwrap();
+
+    let compilations = project.compilations();
+    assert_eq!(compilations.len(), 1);
+    assert_eq!(compilations[0].id.as_deref(), Some("0123abcd"));
+    assert_eq!(compilations[0].compiler.version.as_deref(), Some("0.8.19"));
+    assert_eq!(compilations[0].source_units.len(), 1);
+}
+
+#[test]
+fn missing_output_folders_are_reported_as_not_compiled() {
+    let error = FoundryLoader.load(fixture_path("foundry_not_compiled").as_path(), &LoadOptions::default()).unwrap_err();
+    assert!(matches!(error, ProjectError::NotCompiled { project_kind: "Foundry", .. }), "{}", error);
+}
+
+#[test]
+fn invalid_config_files_are_an_error() {
+    assert!(FoundryLoader.detect(fixture_path("foundry_invalid_config").as_path()));
+
+    let error = FoundryLoader.load(fixture_path("foundry_invalid_config").as_path(), &LoadOptions::default()).unwrap_err();
+    assert!(matches!(error, ProjectError::InvalidConfig { .. }), "{}", error);
+}
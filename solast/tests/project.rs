@@ -0,0 +1,76 @@
+use solast::{
+    project::{self, LoadOptions, Project, ProjectError, ProjectLoader},
+    solc::StandardJsonLoader,
+};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+fn load_standard_json(input: &str) -> Project {
+    let loader = StandardJsonLoader {
+        input_path: Some(fixture_path("standard_json").join(input)),
+        output_path: fixture_path("standard_json").join("output.json"),
+    };
+
+    loader.load(fixture_path("standard_json").as_path(), &LoadOptions::default()).unwrap()
+}
+
+#[test]
+fn project_kinds_are_detected() {
+    for (fixture, name) in [
+        ("brownie", "Brownie"),
+        ("foundry", "Foundry"),
+        ("foundry_invalid_config", "Foundry"),
+        ("dapptools", "Dapptools"),
+        ("sources", "Solidity and Yul sources"),
+    ] {
+        let loader = project::detect(fixture_path(fixture).as_path()).unwrap();
+        assert_eq!(loader.name(), name, "{}", fixture);
+    }
+}
+
+#[test]
+fn folders_without_a_project_are_not_detected() {
+    let error = project::detect(fixture_path("no_project").as_path()).err().unwrap();
+    assert!(matches!(error, ProjectError::NoConfigFound(_)), "{}", error);
+}
+
+#[test]
+fn different_sources_for_the_same_path_are_reported_as_conflicts() {
+    let project = Project {
+        artifacts: vec![
+            load_standard_json("input.json").artifacts.remove(0),
+            load_standard_json("input_modified.json").artifacts.remove(0),
+        ],
+    };
+
+    let conflicts = project.source_conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].absolute_path, "contracts/A.sol");
+    assert_eq!(conflicts[0].variants.len(), 2);
+
+    //
+    // The conflicting variant is moved into a compilation of its own, while the identical file is only kept once
+    //
+
+    let compilations = project.compilations();
+    assert_eq!(compilations.len(), 2);
+    assert_eq!(compilations[0].source_units.len(), 2);
+    assert_eq!(compilations[1].source_units.len(), 1);
+    assert_eq!(compilations[1].source_units[0].absolute_path.as_deref(), Some("contracts/A.sol"));
+}
+
+#[test]
+fn identical_sources_are_not_conflicts() {
+    let project = Project {
+        artifacts: vec![
+            load_standard_json("input.json").artifacts.remove(0),
+            load_standard_json("input.json").artifacts.remove(0),
+        ],
+    };
+
+    assert!(project.source_conflicts().is_empty());
+    assert_eq!(project.compilations().len(), 1);
+}
@@ -0,0 +1,111 @@
+use solast::{
+    project::{LoadOptions, ProjectError, ProjectLoader},
+    solc::{CombinedJsonLoader, DapptoolsLoader, StandardJsonLoader},
+};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+fn standard_json_loader(input: Option<&str>, output: &str) -> StandardJsonLoader {
+    StandardJsonLoader {
+        input_path: input.map(|input| fixture_path("standard_json").join(input)),
+        output_path: fixture_path("standard_json").join(output),
+    }
+}
+
+#[test]
+fn standard_json_is_only_detected_when_the_output_exists() {
+    assert!(standard_json_loader(None, "output.json").detect(fixture_path("standard_json").as_path()));
+    assert!(!standard_json_loader(None, "missing.json").detect(fixture_path("standard_json").as_path()));
+}
+
+#[test]
+fn standard_json_sources_and_settings_are_read_from_the_input() {
+    let loader = standard_json_loader(Some("input_modified.json"), "output.json");
+    let project = loader.load(fixture_path("standard_json").as_path(), &LoadOptions::default()).unwrap();
+
+    let source_units = project.source_units();
+    assert_eq!(source_units.len(), 2);
+    assert!(source_units[0].source.as_deref().unwrap().contains("// modified"));
+
+    let artifact = &project.artifacts[0];
+    assert!(artifact.compiler.settings.is_some());
+    assert!(artifact.contract(&source_units[0], "A").is_some());
+    assert!(artifact.contract(&source_units[1], "B").is_some());
+}
+
+#[test]
+fn standard_json_sources_are_read_from_disk_without_an_input() {
+    let loader = standard_json_loader(None, "output.json");
+    let project = loader.load(fixture_path("standard_json").as_path(), &LoadOptions::default()).unwrap();
+
+    for source_unit in project.source_units() {
+        let source_path = fixture_path("standard_json").join(source_unit.absolute_path.as_deref().unwrap());
+        assert_eq!(source_unit.source, Some(std::fs::read_to_string(source_path).unwrap()));
+    }
+
+    assert!(project.artifacts[0].compiler.settings.is_none());
+}
+
+#[test]
+fn malformed_standard_json_output_is_an_error() {
+    let loader = standard_json_loader(None, "output_malformed.json");
+    let error = loader.load(fixture_path("standard_json").as_path(), &LoadOptions { lenient: true }).unwrap_err();
+
+    assert!(matches!(error, ProjectError::MalformedArtifact { .. }), "{}", error);
+}
+
+#[test]
+fn malformed_asts_are_an_error_unless_lenient() {
+    let loader = standard_json_loader(None, "output_malformed_ast.json");
+
+    let error = loader.load(fixture_path("standard_json").as_path(), &LoadOptions::default()).unwrap_err();
+    assert!(matches!(error, ProjectError::MalformedAst { .. }), "{}", error);
+
+    let project = loader.load(fixture_path("standard_json").as_path(), &LoadOptions { lenient: true }).unwrap();
+
+    let absolute_paths: Vec<Option<String>> = project.source_units()
+        .into_iter()
+        .map(|source_unit| source_unit.absolute_path)
+        .collect();
+
+    assert_eq!(absolute_paths, vec![Some("contracts/A.sol".to_string())]);
+
+    let skipped_paths: Vec<Option<&str>> = project.skipped_source_units()
+        .map(|skipped_source_unit| skipped_source_unit.absolute_path.as_deref())
+        .collect();
+
+    assert_eq!(skipped_paths, vec![Some("contracts/B.sol")]);
+}
+
+#[test]
+fn combined_json_is_loaded_with_sources_from_disk() {
+    let loader = CombinedJsonLoader {
+        path: fixture_path("combined_json").join("combined.json"),
+    };
+
+    assert!(loader.detect(fixture_path("combined_json").as_path()));
+
+    let project = loader.load(fixture_path("combined_json").as_path(), &LoadOptions::default()).unwrap();
+    let source_units = project.source_units();
+
+    assert_eq!(source_units.len(), 1);
+    assert_eq!(source_units[0].contract_definitions()[0].name, "A");
+    assert!(source_units[0].source.is_some());
+    assert!(project.artifacts[0].compiler.version.as_deref().unwrap().starts_with("0.8.19"));
+}
+
+#[test]
+fn dapptools_projects_are_detected_and_loaded() {
+    assert!(DapptoolsLoader.detect(fixture_path("dapptools").as_path()));
+    assert!(!DapptoolsLoader.detect(fixture_path("no_project").as_path()));
+
+    let project = DapptoolsLoader.load(fixture_path("dapptools").as_path(), &LoadOptions::default()).unwrap();
+    let source_units = project.source_units();
+
+    assert_eq!(source_units.len(), 1);
+    assert_eq!(source_units[0].absolute_path.as_deref(), Some("src/A.sol"));
+    assert!(source_units[0].source.is_some());
+}
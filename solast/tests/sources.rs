@@ -0,0 +1,39 @@
+use solast::{
+    project::{LoadOptions, ProjectLoader},
+    sources::SourcesLoader,
+};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
+}
+
+#[test]
+fn unparsable_sources_fail_the_load_unless_lenient() {
+    let error = SourcesLoader.load(fixture_path("sources").as_path(), &LoadOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("Invalid hex number literal"), "{}", error);
+}
+
+#[test]
+fn unparsable_sources_are_skipped_when_lenient() {
+    let project = SourcesLoader.load(fixture_path("sources").as_path(), &LoadOptions { lenient: true }).unwrap();
+
+    let absolute_paths: Vec<Option<String>> = project.source_units()
+        .into_iter()
+        .map(|source_unit| source_unit.absolute_path)
+        .collect();
+
+    assert_eq!(absolute_paths, vec![Some("contracts/Token.sol".to_string())]);
+
+    let skipped_paths: Vec<Option<&str>> = project.skipped_source_units()
+        .map(|skipped_source_unit| skipped_source_unit.absolute_path.as_deref())
+        .collect();
+
+    assert_eq!(skipped_paths, vec![Some("contracts/Broken.sol")]);
+}
+
+#[test]
+fn sources_in_dependency_folders_are_not_detected() {
+    assert!(SourcesLoader.detect(fixture_path("sources").as_path()));
+    assert!(!SourcesLoader.detect(fixture_path("sources_dependencies_only").as_path()));
+}
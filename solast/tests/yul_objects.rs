@@ -0,0 +1,63 @@
+use std::{path::PathBuf, process::Command};
+
+//
+// Runs every analyzer on a single Yul file and returns the reported lines
+//
+
+fn analyze(name: &str, source: &str) -> Vec<String> {
+    let path: PathBuf = std::env::temp_dir().join(format!("solast-yul-objects-{}.yul", name));
+    std::fs::write(path.as_path(), source).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solast"))
+        .arg(path.as_path())
+        .output()
+        .unwrap();
+
+    std::fs::remove_file(path.as_path()).unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter(|line| line.starts_with('\t'))
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+#[test]
+fn findings_are_reported_against_the_object() {
+    let lines = analyze("named", r#"
+object "Token" {
+    code {
+        let p := mload(0x40)
+    }
+
+    object "runtime" {
+        code {
+            calldatacopy(0, 4, sub(calldatasize(), 4))
+        }
+    }
+}
+"#);
+
+    assert_eq!(lines, vec![
+        "L4: The `Token` Yul object contains inline assembly which loads the free memory pointer",
+        "L9: The `runtime` Yul object contains inline assembly which copies arbitrary function arguments",
+    ]);
+}
+
+#[test]
+fn findings_in_a_code_block_are_reported_at_the_file_level() {
+    let lines = analyze("bare", "{\n    let p := mload(0x40)\n}\n");
+
+    assert_eq!(lines, vec![
+        "L2: The Yul code at the file level contains inline assembly which loads the free memory pointer",
+    ]);
+}
+
+#[test]
+fn solidity_analyzers_are_not_run_on_yul() {
+    let lines = analyze("clean", "object \"Token\" {\n    code {\n        sstore(0, 1)\n    }\n}\n");
+
+    assert!(lines.is_empty(), "{:?}", lines);
+}
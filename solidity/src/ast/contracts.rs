@@ -1,6 +1,6 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Display;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -9,16 +9,26 @@ pub enum ContractKind {
     Contract,
     Interface,
     Library,
+
+    //
+    // Never produced by solc. Marks the contracts the native parser wraps standalone Yul objects in.
+    //
+
+    #[serde(rename = "yulObject")]
+    YulObject,
 }
 
 impl Display for ContractKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{}", format!("{:?}", self).to_lowercase()))
+        match self {
+            ContractKind::YulObject => f.write_str("Yul object"),
+            kind => f.write_fmt(format_args!("{}", format!("{:?}", kind).to_lowercase())),
+        }
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ContractDefinitionNode {
     UsingForDirective(UsingForDirective),
     StructDefinition(StructDefinition),
@@ -31,6 +41,39 @@ pub enum ContractDefinitionNode {
     UserDefinedValueTypeDefinition(UserDefinedValueTypeDefinition),
 }
 
+impl<'de> Deserialize<'de> for ContractDefinitionNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("UsingForDirective") => ContractDefinitionNode::UsingForDirective(Deserialize::deserialize(content)?),
+            Some("StructDefinition") => ContractDefinitionNode::StructDefinition(Deserialize::deserialize(content)?),
+            Some("EnumDefinition") => ContractDefinitionNode::EnumDefinition(Deserialize::deserialize(content)?),
+            Some("VariableDeclaration") => ContractDefinitionNode::VariableDeclaration(Deserialize::deserialize(content)?),
+            Some("EventDefinition") => ContractDefinitionNode::EventDefinition(Deserialize::deserialize(content)?),
+            Some("FunctionDefinition") => ContractDefinitionNode::FunctionDefinition(Deserialize::deserialize(content)?),
+            Some("ModifierDefinition") => ContractDefinitionNode::ModifierDefinition(Deserialize::deserialize(content)?),
+            Some("ErrorDefinition") => ContractDefinitionNode::ErrorDefinition(Deserialize::deserialize(content)?),
+            Some("UserDefinedValueTypeDefinition") => ContractDefinitionNode::UserDefinedValueTypeDefinition(Deserialize::deserialize(content)?),
+            node_type => return Err(unexpected_node_type(node_type, "contract definition")),
+        }))
+    }
+}
+
+impl Serialize for ContractDefinitionNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ContractDefinitionNode::UsingForDirective(node) => serialize_tagged(serializer, NodeType::UsingForDirective, node),
+            ContractDefinitionNode::StructDefinition(node) => serialize_tagged(serializer, NodeType::StructDefinition, node),
+            ContractDefinitionNode::EnumDefinition(node) => serialize_tagged(serializer, NodeType::EnumDefinition, node),
+            ContractDefinitionNode::VariableDeclaration(node) => serialize_tagged(serializer, NodeType::VariableDeclaration, node),
+            ContractDefinitionNode::EventDefinition(node) => serialize_tagged(serializer, NodeType::EventDefinition, node),
+            ContractDefinitionNode::FunctionDefinition(node) => serialize_tagged(serializer, NodeType::FunctionDefinition, node),
+            ContractDefinitionNode::ModifierDefinition(node) => serialize_tagged(serializer, NodeType::ModifierDefinition, node),
+            ContractDefinitionNode::ErrorDefinition(node) => serialize_tagged(serializer, NodeType::ErrorDefinition, node),
+            ContractDefinitionNode::UserDefinedValueTypeDefinition(node) => serialize_tagged(serializer, NodeType::UserDefinedValueTypeDefinition, node),
+        }
+    }
+}
+
 impl Display for ContractDefinitionNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -47,6 +90,40 @@ impl Display for ContractDefinitionNode {
     }
 }
 
+//
+// A borrowed definition node, so functions and using-for directives at the file level can be visited with the same
+// contexts as the ones inside of contracts without copying them into a ContractDefinitionNode
+//
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DefinitionNode<'a> {
+    UsingForDirective(&'a UsingForDirective),
+    StructDefinition(&'a StructDefinition),
+    EnumDefinition(&'a EnumDefinition),
+    VariableDeclaration(&'a VariableDeclaration),
+    EventDefinition(&'a EventDefinition),
+    FunctionDefinition(&'a FunctionDefinition),
+    ModifierDefinition(&'a ModifierDefinition),
+    ErrorDefinition(&'a ErrorDefinition),
+    UserDefinedValueTypeDefinition(&'a UserDefinedValueTypeDefinition),
+}
+
+impl<'a> From<&'a ContractDefinitionNode> for DefinitionNode<'a> {
+    fn from(node: &'a ContractDefinitionNode) -> Self {
+        match node {
+            ContractDefinitionNode::UsingForDirective(node) => DefinitionNode::UsingForDirective(node),
+            ContractDefinitionNode::StructDefinition(node) => DefinitionNode::StructDefinition(node),
+            ContractDefinitionNode::EnumDefinition(node) => DefinitionNode::EnumDefinition(node),
+            ContractDefinitionNode::VariableDeclaration(node) => DefinitionNode::VariableDeclaration(node),
+            ContractDefinitionNode::EventDefinition(node) => DefinitionNode::EventDefinition(node),
+            ContractDefinitionNode::FunctionDefinition(node) => DefinitionNode::FunctionDefinition(node),
+            ContractDefinitionNode::ModifierDefinition(node) => DefinitionNode::ModifierDefinition(node),
+            ContractDefinitionNode::ErrorDefinition(node) => DefinitionNode::ErrorDefinition(node),
+            ContractDefinitionNode::UserDefinedValueTypeDefinition(node) => DefinitionNode::UserDefinedValueTypeDefinition(node),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct InheritanceSpecifier {
@@ -291,19 +368,17 @@ impl ContractDefinition {
     pub fn get_assigned_state_variables(
         &self,
         source_units: &[SourceUnit],
-        _definition_node: &ContractDefinitionNode,
+        _definition_node: DefinitionNode,
         expression: &Expression,
     ) -> Vec<NodeID> {
         let mut ids = vec![];
 
         match expression {
-            Expression::Identifier(identifier) => {
-                if self.hierarchy_contains_state_variable(
-                    source_units,
-                    identifier.referenced_declaration,
-                ) {
-                    ids.push(identifier.referenced_declaration);
-                }
+            Expression::Identifier(identifier) if self.hierarchy_contains_state_variable(
+                source_units,
+                identifier.referenced_declaration,
+            ) => {
+                ids.push(identifier.referenced_declaration);
             }
 
             Expression::Assignment(assignment) => {
@@ -353,49 +428,66 @@ impl ContractDefinition {
 
         ids
     }
+}
 
-    pub fn definition_node_location(&self, source_line: usize, definition_node: &ContractDefinitionNode) -> String {
-        format!(
-            "L{}: The {}",
+pub fn definition_node_location(
+    source_line: usize,
+    contract_definition: Option<&ContractDefinition>,
+    definition_node: DefinitionNode,
+) -> String {
+    //
+    // The function wrapping the code of a Yul object does not exist in the source, so the object itself is reported
+    //
+
+    if let Some(contract_definition) = contract_definition.filter(|contract_definition| contract_definition.kind == ContractKind::YulObject) {
+        return match contract_definition.name.as_str() {
+            "" => format!("L{}: The Yul code at the file level", source_line),
+            name => format!("L{}: The `{}` Yul object", source_line, name),
+        };
+    }
 
-            source_line,
+    let location = match contract_definition {
+        Some(contract_definition) => format!("in the `{}` {}", contract_definition.name, contract_definition.kind),
+        None => "at the file level".to_string(),
+    };
 
-            match definition_node {
-                ContractDefinitionNode::FunctionDefinition(function_definition) => format!(
-                    "{} {} in the `{}` {}",
-                    
-                    function_definition.visibility,
+    format!(
+        "L{}: The {}",
 
-                    if let FunctionKind::Constructor = function_definition.kind {
-                        "constructor".to_string()
-                    } else {
-                        format!(
-                            "`{}` {}",
-                            function_definition.name, function_definition.kind
-                        )
-                    },
+        source_line,
 
-                    self.name,
-                    self.kind,
-                ),
-    
-                ContractDefinitionNode::ModifierDefinition(modifier_definition) => format!(
-                    "`{}` modifier in the `{}` {}",
-                    modifier_definition.name,
-                    self.name,
-                    self.kind,
-                ),
-
-                ContractDefinitionNode::UsingForDirective(_) => format!(
-                    "`{}` {}",
-                    self.name,
-                    self.kind,
-                ),
-    
-                x => panic!("Unsupported definition node: {x:?}"),
+        match definition_node {
+            DefinitionNode::FunctionDefinition(function_definition) => format!(
+                "{} {} {}",
+
+                function_definition.visibility,
+
+                if let FunctionKind::Constructor = function_definition.kind {
+                    "constructor".to_string()
+                } else {
+                    format!(
+                        "`{}` {}",
+                        function_definition.name, function_definition.kind
+                    )
+                },
+
+                location,
+            ),
+
+            DefinitionNode::ModifierDefinition(modifier_definition) => format!(
+                "`{}` modifier {}",
+                modifier_definition.name,
+                location,
+            ),
+
+            DefinitionNode::UsingForDirective(_) => match contract_definition {
+                Some(contract_definition) => format!("`{}` {}", contract_definition.name, contract_definition.kind),
+                None => "file-level using-for directive".to_string(),
             },
-        )
-    }
+
+            x => panic!("Unsupported definition node: {x:?}"),
+        },
+    )
 }
 
 impl Display for ContractDefinition {
@@ -446,6 +538,7 @@ impl Display for ContractDefinition {
 
 pub struct ContractDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub contract_definition: &'a ContractDefinition,
 }
@@ -453,13 +546,14 @@ pub struct ContractDefinitionContext<'a> {
 impl<'a> ContractDefinitionContext<'a> {
     pub fn create_using_for_directive_context(
         &self,
-        definition_node: &'a ContractDefinitionNode,
+        definition_node: DefinitionNode<'a>,
         using_for_directive: &'a UsingForDirective
     ) -> UsingForDirectiveContext<'a> {
         UsingForDirectiveContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
-            contract_definition: self.contract_definition,
+            contract_definition: Some(self.contract_definition),
             definition_node,
             using_for_directive
         }
@@ -471,6 +565,7 @@ impl<'a> ContractDefinitionContext<'a> {
     ) -> StructDefinitionContext<'a> {
         StructDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: Some(self.contract_definition),
             struct_definition
@@ -483,6 +578,7 @@ impl<'a> ContractDefinitionContext<'a> {
     ) -> EnumDefinitionContext<'a> {
         EnumDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: Some(self.contract_definition),
             enum_definition
@@ -491,12 +587,13 @@ impl<'a> ContractDefinitionContext<'a> {
     
     pub fn create_variable_declaration_context<'b>(
         &self,
-        definition_node: &'a ContractDefinitionNode,
+        definition_node: DefinitionNode<'a>,
         blocks: &'b mut Vec<&'a Block>,
         variable_declaration: &'a VariableDeclaration
     ) -> VariableDeclarationContext<'a, 'b> {
         VariableDeclarationContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: Some(self.contract_definition),
             definition_node: Some(definition_node),
@@ -511,21 +608,23 @@ impl<'a> ContractDefinitionContext<'a> {
     ) -> EventDefinitionContext<'a> {
         EventDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
-            contract_definition: self.contract_definition,
+            contract_definition: Some(self.contract_definition),
             event_definition
         }
     }
     
     pub fn create_function_definition_context(
         &self,
-        definition_node: &'a ContractDefinitionNode,
+        definition_node: DefinitionNode<'a>,
         function_definition: &'a FunctionDefinition
     ) -> FunctionDefinitionContext<'a> {
         FunctionDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
-            contract_definition: self.contract_definition,
+            contract_definition: Some(self.contract_definition),
             definition_node,
             function_definition
         }
@@ -533,13 +632,14 @@ impl<'a> ContractDefinitionContext<'a> {
     
     pub fn create_modifier_definition_context(
         &self,
-        definition_node: &'a ContractDefinitionNode,
+        definition_node: DefinitionNode<'a>,
         modifier_definition: &'a ModifierDefinition
     ) -> ModifierDefinitionContext<'a> {
         ModifierDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
-            contract_definition: self.contract_definition,
+            contract_definition: Some(self.contract_definition),
             definition_node,
             modifier_definition
         }
@@ -551,6 +651,7 @@ impl<'a> ContractDefinitionContext<'a> {
     ) -> ErrorDefinitionContext<'a> {
         ErrorDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: Some(self.contract_definition),
             error_definition
@@ -563,6 +664,7 @@ impl<'a> ContractDefinitionContext<'a> {
     ) -> UserDefinedValueTypeDefinitionContext<'a> {
         UserDefinedValueTypeDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: Some(self.contract_definition),
             user_defined_value_type_definition,
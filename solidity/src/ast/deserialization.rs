@@ -0,0 +1,264 @@
+use super::*;
+use eth_lang_utils::ast::{take_tagged_node_error, TaggedNodeError};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use std::fmt::{Display, Write};
+use yul::ast::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeserializationError {
+    pub path: String,
+    pub node_type: Option<String>,
+    pub src: Option<String>,
+    pub message: String,
+}
+
+impl Display for DeserializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message.as_str())?;
+
+        if !self.path.is_empty() {
+            f.write_fmt(format_args!(" at `{}`", self.path))?;
+        }
+
+        if let Some(node_type) = self.node_type.as_ref() {
+            f.write_fmt(format_args!(" in {} node", node_type))?;
+        }
+
+        if let Some(src) = self.src.as_ref() {
+            f.write_fmt(format_args!(" (src {})", src))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for DeserializationError {}
+
+pub fn deserialize_source_unit(value: &Value) -> Result<SourceUnit, DeserializationError> {
+    SourceUnit::deserialize(value).map_err(|error| {
+        locate_deserialization_error(value).unwrap_or_else(|| DeserializationError {
+            path: String::new(),
+            node_type: value.get("nodeType").and_then(Value::as_str).map(str::to_string),
+            src: value.get("src").and_then(Value::as_str).map(str::to_string),
+            message: error.to_string(),
+        })
+    })
+}
+
+//
+// Each top-level node is deserialized once. Tagged nodes record the innermost node which failed, which is then
+// found by its node type, id and source location, falling back to the top-level node when nothing was recorded.
+//
+
+pub fn locate_deserialization_error(value: &Value) -> Option<DeserializationError> {
+    locate_node_error(value, &mut String::new())
+}
+
+fn locate_node_error(value: &Value, path: &mut String) -> Option<DeserializationError> {
+    match value {
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let length = path.len();
+                write!(path, "[{}]", index).ok()?;
+
+                if let Some(error) = locate_node_error(value, path) {
+                    return Some(error);
+                }
+
+                path.truncate(length);
+            }
+
+            None
+        }
+
+        Value::Object(fields) => {
+            if let Some(node_type) = fields.get("nodeType").and_then(Value::as_str) {
+                take_tagged_node_error();
+
+                if let Some(result) = check_node(node_type, value) {
+                    let message = match result {
+                        Ok(()) => return None,
+                        Err(error) => error.to_string(),
+                    };
+
+                    if let Some(node_error) = take_tagged_node_error().filter(|node_error| node_error.message == message) {
+                        let mut node_path = path.clone();
+
+                        if find_node(value, &node_error, &mut node_path) {
+                            return Some(DeserializationError {
+                                path: node_path,
+                                node_type: node_error.node_type,
+                                src: node_error.src,
+                                message,
+                            });
+                        }
+                    }
+
+                    return Some(DeserializationError {
+                        path: path.clone(),
+                        node_type: Some(node_type.to_string()),
+                        src: fields.get("src").and_then(Value::as_str).map(str::to_string),
+                        message,
+                    });
+                }
+            }
+
+            for (key, value) in fields.iter() {
+                let length = path.len();
+                push_path_key(path, key);
+
+                if let Some(error) = locate_node_error(value, path) {
+                    return Some(error);
+                }
+
+                path.truncate(length);
+            }
+
+            None
+        }
+
+        _ => None,
+    }
+}
+
+fn find_node(value: &Value, node_error: &TaggedNodeError, path: &mut String) -> bool {
+    match value {
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let length = path.len();
+                write!(path, "[{}]", index).ok();
+
+                if find_node(value, node_error, path) {
+                    return true;
+                }
+
+                path.truncate(length);
+            }
+
+            false
+        }
+
+        Value::Object(fields) => {
+            let is_node = fields.get("nodeType").and_then(Value::as_str) == node_error.node_type.as_deref()
+                && fields.get("id").and_then(Value::as_i64) == node_error.id
+                && fields.get("src").and_then(Value::as_str) == node_error.src.as_deref();
+
+            if is_node {
+                return true;
+            }
+
+            for (key, value) in fields.iter() {
+                let length = path.len();
+                push_path_key(path, key);
+
+                if find_node(value, node_error, path) {
+                    return true;
+                }
+
+                path.truncate(length);
+            }
+
+            false
+        }
+
+        _ => false,
+    }
+}
+
+fn push_path_key(path: &mut String, key: &str) {
+    let is_identifier = key.chars().next().map(|c| c.is_ascii_alphabetic() || c == '_').unwrap_or(false)
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_identifier {
+        path.push_str(format!("[{:?}]", key).as_str());
+        return;
+    }
+
+    if !path.is_empty() {
+        path.push('.');
+    }
+
+    path.push_str(key);
+}
+
+fn check<T: DeserializeOwned>(value: &Value) -> Option<Result<(), serde_json::Error>> {
+    Some(T::deserialize(value).map(|_| ()))
+}
+
+fn check_node(node_type: &str, value: &Value) -> Option<Result<(), serde_json::Error>> {
+    match node_type {
+        "SourceUnit" => check::<SourceUnit>(value),
+        "PragmaDirective" => check::<PragmaDirective>(value),
+        "ImportDirective" => check::<ImportDirective>(value),
+        "UsingForDirective" => check::<UsingForDirective>(value),
+        "ContractDefinition" => check::<ContractDefinition>(value),
+        "InheritanceSpecifier" => check::<InheritanceSpecifier>(value),
+        "OverrideSpecifier" => check::<OverrideSpecifier>(value),
+        "IdentifierPath" => check::<IdentifierPath>(value),
+        "StructuredDocumentation" => check::<StructuredDocumentation>(value),
+        "VariableDeclaration" => check::<VariableDeclaration>(value),
+        "Mapping" => check::<Mapping>(value),
+        "ElementaryTypeName" => check::<ElementaryTypeName>(value),
+        "ElementaryTypeNameExpression" => check::<ElementaryTypeNameExpression>(value),
+        "ArrayTypeName" => check::<ArrayTypeName>(value),
+        "TupleExpression" => check::<TupleExpression>(value),
+        "FunctionDefinition" => check::<FunctionDefinition>(value),
+        "ParameterList" => check::<ParameterList>(value),
+        "Block" | "UncheckedBlock" => check::<Block>(value),
+        "Continue" => check::<Continue>(value),
+        "Break" => check::<Break>(value),
+        "Return" => check::<Return>(value),
+        "Literal" => check::<Literal>(value),
+        "Conditional" => check::<Conditional>(value),
+        "Identifier" => check::<Identifier>(value),
+        "IndexAccess" => check::<IndexAccess>(value),
+        "IndexRangeAccess" => check::<IndexRangeAccess>(value),
+        "MemberAccess" => check::<MemberAccess>(value),
+        "Assignment" => check::<Assignment>(value),
+        "FunctionCall" => check::<FunctionCall>(value),
+        "FunctionCallOptions" => check::<FunctionCallOptions>(value),
+        "FunctionTypeName" => check::<FunctionTypeName>(value),
+        "NewExpression" => check::<NewExpression>(value),
+        "ExpressionStatement" => check::<ExpressionStatement>(value),
+        "VariableDeclarationStatement" => check::<VariableDeclarationStatement>(value),
+        "IfStatement" => check::<IfStatement>(value),
+        "TryCatchClause" => check::<TryCatchClause>(value),
+        "UnaryOperation" => check::<UnaryOperation>(value),
+        "BinaryOperation" => check::<BinaryOperation>(value),
+        "EventDefinition" => check::<EventDefinition>(value),
+        "ErrorDefinition" => check::<ErrorDefinition>(value),
+        "EmitStatement" => check::<EmitStatement>(value),
+        "PlaceholderStatement" => check::<PlaceholderStatement>(value),
+        "TryStatement" => check::<TryStatement>(value),
+        "RevertStatement" => check::<RevertStatement>(value),
+        "ForStatement" => check::<ForStatement>(value),
+        "WhileStatement" => check::<WhileStatement>(value),
+        "DoWhileStatement" => check::<DoWhileStatement>(value),
+        "ModifierDefinition" => check::<ModifierDefinition>(value),
+        "ModifierInvocation" => check::<ModifierInvocation>(value),
+        "EnumDefinition" => check::<EnumDefinition>(value),
+        "EnumValue" => check::<EnumValue>(value),
+        "StructDefinition" => check::<StructDefinition>(value),
+        "UserDefinedTypeName" => check::<UserDefinedTypeName>(value),
+        "UserDefinedValueTypeDefinition" => check::<UserDefinedValueTypeDefinition>(value),
+        "InlineAssembly" => check::<InlineAssembly>(value),
+        "YulLiteral" => check::<YulLiteral>(value),
+        "YulTypedName" => check::<YulTypedName>(value),
+        "YulSwitch" => check::<YulSwitch>(value),
+        "YulCase" => check::<YulCase>(value),
+        "YulFunctionCall" => check::<YulFunctionCall>(value),
+        "YulExpressionStatement" => check::<YulExpressionStatement>(value),
+        "YulAssignment" => check::<YulAssignment>(value),
+        "YulIdentifier" => check::<YulIdentifier>(value),
+        "YulVariableDeclaration" => check::<YulVariableDeclaration>(value),
+        "YulBlock" => check::<YulBlock>(value),
+        "YulIf" => check::<YulIf>(value),
+        "YulForLoop" => check::<YulForLoop>(value),
+        "YulFunctionDefinition" => check::<YulFunctionDefinition>(value),
+        "YulBreak" => check::<YulBreak>(value),
+        "YulContinue" => check::<YulContinue>(value),
+        "YulLeave" => check::<YulLeave>(value),
+        _ => None,
+    }
+}
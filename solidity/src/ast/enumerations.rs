@@ -43,6 +43,7 @@ impl Display for EnumDefinition {
 
 pub struct EnumDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub contract_definition: Option<&'a ContractDefinition>,
     pub enum_definition: &'a EnumDefinition,
@@ -22,6 +22,7 @@ impl Display for ErrorDefinition {
 
 pub struct ErrorDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub contract_definition: Option<&'a ContractDefinition>,
     pub error_definition: &'a ErrorDefinition,
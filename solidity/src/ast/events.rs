@@ -23,7 +23,8 @@ impl Display for EventDefinition {
 
 pub struct EventDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
+    pub contract_definition: Option<&'a ContractDefinition>,
     pub event_definition: &'a EventDefinition,
 }
@@ -1,10 +1,9 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Write};
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Expression {
     Literal(Literal),
     Identifier(Identifier),
@@ -21,12 +20,7 @@ pub enum Expression {
     TupleExpression(TupleExpression),
     NewExpression(NewExpression),
 
-    #[serde(rename_all = "camelCase")]
-    UnhandledExpression {
-        node_type: NodeType,
-        src: Option<String>,
-        id: Option<NodeID>,
-    },
+    UnhandledExpression(UnhandledNode),
 }
 
 impl Expression {
@@ -123,29 +117,68 @@ impl Expression {
             Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression { type_descriptions, .. }) => Some(type_descriptions),
             Expression::TupleExpression(TupleExpression { type_descriptions, .. }) => Some(type_descriptions),
             Expression::NewExpression(NewExpression { type_descriptions, .. }) => Some(type_descriptions),
-            Expression::UnhandledExpression { .. } => None
+            Expression::UnhandledExpression(_) => None
         }
     }
 
+    fn require_src(&self) -> std::io::Result<&str> {
+        self.src().ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+    }
+
     pub fn source_line(&self, source_unit: &SourceUnit) -> std::io::Result<usize> {
-        source_unit.source_line(match self {
-            Expression::Literal(Literal { src, .. }) => src.as_str(),
-            Expression::Identifier(Identifier { src, .. }) => src.as_str(),
-            Expression::UnaryOperation(UnaryOperation { src, .. }) => src.as_str(),
-            Expression::BinaryOperation(BinaryOperation { src, .. }) => src.as_str(),
-            Expression::Conditional(Conditional { src, .. }) => src.as_str(),
-            Expression::Assignment(Assignment { src, .. }) => src.as_str(),
-            Expression::FunctionCall(FunctionCall { src, .. }) => src.as_str(),
-            Expression::FunctionCallOptions(FunctionCallOptions { src, .. }) => src.as_str(),
-            Expression::IndexAccess(IndexAccess { src, .. }) => src.as_str(),
-            Expression::IndexRangeAccess(IndexRangeAccess { src, .. }) => src.as_str(),
-            Expression::MemberAccess(MemberAccess { src, .. }) => src.as_str(),
-            Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression { src, .. }) => src.as_str(),
-            Expression::TupleExpression(TupleExpression { src, .. }) => src.as_str(),
-            Expression::NewExpression(NewExpression { src, .. }) => src.as_str(),
-            Expression::UnhandledExpression { src: Some(src), .. } => src.as_str(),
-            _ => return Err(std::io::Error::from(std::io::ErrorKind::NotFound))
-        })
+        source_unit.source_line(self.require_src()?)
+    }
+
+    pub fn source_range(&self, source_unit: &SourceUnit) -> std::io::Result<SourceRange> {
+        source_unit.source_range(self.require_src()?)
+    }
+
+    pub fn source_snippet<'a>(&self, source_unit: &'a SourceUnit) -> std::io::Result<&'a str> {
+        source_unit.source_snippet(self.require_src()?)
+    }
+}
+
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("Literal") => Expression::Literal(Deserialize::deserialize(content)?),
+            Some("Identifier") => Expression::Identifier(Deserialize::deserialize(content)?),
+            Some("UnaryOperation") => Expression::UnaryOperation(Deserialize::deserialize(content)?),
+            Some("BinaryOperation") => Expression::BinaryOperation(Deserialize::deserialize(content)?),
+            Some("Conditional") => Expression::Conditional(Deserialize::deserialize(content)?),
+            Some("Assignment") => Expression::Assignment(Deserialize::deserialize(content)?),
+            Some("FunctionCall") => Expression::FunctionCall(Deserialize::deserialize(content)?),
+            Some("FunctionCallOptions") => Expression::FunctionCallOptions(Deserialize::deserialize(content)?),
+            Some("IndexAccess") => Expression::IndexAccess(Deserialize::deserialize(content)?),
+            Some("IndexRangeAccess") => Expression::IndexRangeAccess(Deserialize::deserialize(content)?),
+            Some("MemberAccess") => Expression::MemberAccess(Deserialize::deserialize(content)?),
+            Some("ElementaryTypeNameExpression") => Expression::ElementaryTypeNameExpression(Deserialize::deserialize(content)?),
+            Some("TupleExpression") => Expression::TupleExpression(Deserialize::deserialize(content)?),
+            Some("NewExpression") => Expression::NewExpression(Deserialize::deserialize(content)?),
+            _ => Expression::UnhandledExpression(Deserialize::deserialize(content)?),
+        }))
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Expression::Literal(node) => serialize_tagged(serializer, NodeType::Literal, node),
+            Expression::Identifier(node) => serialize_tagged(serializer, NodeType::Identifier, node),
+            Expression::UnaryOperation(node) => serialize_tagged(serializer, NodeType::UnaryOperation, node),
+            Expression::BinaryOperation(node) => serialize_tagged(serializer, NodeType::BinaryOperation, node),
+            Expression::Conditional(node) => serialize_tagged(serializer, NodeType::Conditional, node),
+            Expression::Assignment(node) => serialize_tagged(serializer, NodeType::Assignment, node),
+            Expression::FunctionCall(node) => serialize_tagged(serializer, NodeType::FunctionCall, node),
+            Expression::FunctionCallOptions(node) => serialize_tagged(serializer, NodeType::FunctionCallOptions, node),
+            Expression::IndexAccess(node) => serialize_tagged(serializer, NodeType::IndexAccess, node),
+            Expression::IndexRangeAccess(node) => serialize_tagged(serializer, NodeType::IndexRangeAccess, node),
+            Expression::MemberAccess(node) => serialize_tagged(serializer, NodeType::MemberAccess, node),
+            Expression::ElementaryTypeNameExpression(node) => serialize_tagged(serializer, NodeType::ElementaryTypeNameExpression, node),
+            Expression::TupleExpression(node) => serialize_tagged(serializer, NodeType::TupleExpression, node),
+            Expression::NewExpression(node) => serialize_tagged(serializer, NodeType::NewExpression, node),
+            Expression::UnhandledExpression(node) => node.serialize(serializer),
+        }
     }
 }
 
@@ -175,9 +208,10 @@ impl Display for Expression {
 
 pub struct ExpressionContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub expression: &'a Expression,
@@ -189,6 +223,7 @@ pub struct UnaryOperation {
     pub prefix: bool,
     pub sub_expression: Box<Expression>,
     pub operator: String,
+    pub function: Option<NodeID>,
     pub argument_types: Option<Vec<TypeDescriptions>>,
     pub is_constant: bool,
     pub is_l_value: bool,
@@ -218,9 +253,10 @@ impl Display for UnaryOperation {
 
 pub struct UnaryOperationContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub unary_operation: &'a UnaryOperation,
@@ -233,6 +269,7 @@ pub struct BinaryOperation {
     pub left_expression: Box<Expression>,
     pub right_expression: Box<Expression>,
     pub operator: String,
+    pub function: Option<NodeID>,
     pub argument_types: Option<Vec<TypeDescriptions>>,
     pub is_constant: bool,
     pub is_l_value: bool,
@@ -262,9 +299,10 @@ impl Display for BinaryOperation {
 
 pub struct BinaryOperationContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub binary_operation: &'a BinaryOperation,
@@ -305,9 +343,10 @@ impl Display for Conditional {
 
 pub struct ConditionalContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub conditional: &'a Conditional,
@@ -346,9 +385,10 @@ impl Display for Assignment {
 
 pub struct AssignmentContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub assignment: &'a Assignment,
@@ -411,9 +451,10 @@ impl Display for FunctionCall {
 
 pub struct FunctionCallContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub function_call: &'a FunctionCall,
@@ -449,13 +490,10 @@ impl FunctionCallOptions {
 }
 
 impl Display for FunctionCallOptions {
-    #[allow(clippy::print_in_format_impl)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let option_count = self.options.len();
         
         if self.names.len() != option_count {
-            eprintln!("ERROR: invalid FunctionCallOptions: {:?}, {:?}", self.names, self.options);
-            
             return Err(std::fmt::Error)
         }
 
@@ -493,9 +531,10 @@ impl Display for FunctionCallOptions {
 
 pub struct FunctionCallOptionsContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub function_call_options: &'a FunctionCallOptions,
@@ -530,9 +569,10 @@ impl Display for IndexAccess {
 
 pub struct IndexAccessContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub index_access: &'a IndexAccess,
@@ -580,9 +620,10 @@ impl Display for IndexRangeAccess {
 
 pub struct IndexRangeAccessContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub index_range_access: &'a IndexRangeAccess,
@@ -618,9 +659,10 @@ impl Display for MemberAccess {
 
 pub struct MemberAccessContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub member_access: &'a MemberAccess,
@@ -648,9 +690,10 @@ impl Display for ElementaryTypeNameExpression {
 
 pub struct ElementaryTypeNameExpressionContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub elementary_type_name_expression: &'a ElementaryTypeNameExpression,
@@ -703,9 +746,10 @@ impl Display for TupleExpression {
 
 pub struct TupleExpressionContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub tuple_expression: &'a TupleExpression,
@@ -733,10 +777,22 @@ impl Display for NewExpression {
 
 pub struct NewExpressionContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub new_expression: &'a NewExpression,
 }
+
+pub struct UnhandledExpressionContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: Option<&'a Statement>,
+    pub unhandled_expression: &'a UnhandledNode,
+}
@@ -104,10 +104,8 @@ impl FunctionDefinition {
         let mut ids = vec![];
 
         match expression {
-            Expression::Identifier(identifier) => {
-                if self.return_parameters.parameters.iter().any(|p| p.id == identifier.referenced_declaration) {
-                    ids.push(identifier.referenced_declaration);
-                }
+            Expression::Identifier(identifier) if self.return_parameters.parameters.iter().any(|p| p.id == identifier.referenced_declaration) => {
+                ids.push(identifier.referenced_declaration);
             }
 
             Expression::Assignment(assignment) => {
@@ -178,8 +176,9 @@ impl Display for FunctionDefinition {
 
 pub struct FunctionDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub function_definition: &'a FunctionDefinition,
 }
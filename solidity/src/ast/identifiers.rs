@@ -33,9 +33,10 @@ impl Display for Identifier {
 
 pub struct IdentifierContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub identifier: &'a Identifier,
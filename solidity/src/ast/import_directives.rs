@@ -26,6 +26,7 @@ pub struct ImportDirective {
 
 pub struct ImportDirectiveContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub import_directive: &'a ImportDirective,
 }
@@ -0,0 +1,750 @@
+use super::*;
+use eth_lang_utils::ast::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug)]
+pub enum AstNodeRef<'a> {
+    SourceUnit(&'a SourceUnit),
+    PragmaDirective(&'a PragmaDirective),
+    ImportDirective(&'a ImportDirective),
+    UsingForDirective(&'a UsingForDirective),
+    ContractDefinition(&'a ContractDefinition),
+    InheritanceSpecifier(&'a InheritanceSpecifier),
+    StructDefinition(&'a StructDefinition),
+    EnumDefinition(&'a EnumDefinition),
+    EnumValue(&'a EnumValue),
+    EventDefinition(&'a EventDefinition),
+    ErrorDefinition(&'a ErrorDefinition),
+    FunctionDefinition(&'a FunctionDefinition),
+    ModifierDefinition(&'a ModifierDefinition),
+    ModifierInvocation(&'a ModifierInvocation),
+    VariableDeclaration(&'a VariableDeclaration),
+    UserDefinedValueTypeDefinition(&'a UserDefinedValueTypeDefinition),
+    ParameterList(&'a ParameterList),
+    Block(&'a Block),
+    Statement(&'a Statement),
+    TryCatchClause(&'a TryCatchClause),
+    Expression(&'a Expression),
+    FunctionCall(&'a FunctionCall),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AstIndexEntry<'a> {
+    pub node: AstNodeRef<'a>,
+    pub source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition: Option<AstNodeRef<'a>>,
+    pub parent: Option<NodeID>,
+}
+
+#[derive(Clone, Copy)]
+struct AstIndexScope<'a> {
+    source_unit: &'a SourceUnit,
+    contract_definition: Option<&'a ContractDefinition>,
+    definition: Option<AstNodeRef<'a>>,
+    parent: Option<NodeID>,
+}
+
+impl<'a> AstIndexScope<'a> {
+    fn with_definition(self, definition: AstNodeRef<'a>) -> Self {
+        AstIndexScope {
+            definition: self.definition.or(Some(definition)),
+            ..self
+        }
+    }
+}
+
+//
+// Maps every NodeID of a single compilation to the node it belongs to, along with the source unit, contract
+// and top-level definition enclosing it and the NodeID of its closest parent. Nodes without an id of their own,
+// such as type names, are skipped in the parent chain. NodeIDs are only unique within a compilation,
+// so an index should never be built from the source units of several compilations.
+//
+
+#[derive(Clone, Debug, Default)]
+pub struct AstIndex<'a> {
+    entries: HashMap<NodeID, AstIndexEntry<'a>>,
+}
+
+impl<'a> AstIndex<'a> {
+    pub fn new(source_units: &'a [SourceUnit]) -> Self {
+        let mut index = AstIndex::default();
+
+        for source_unit in source_units.iter() {
+            index.index_source_unit(source_unit);
+        }
+
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&NodeID, &AstIndexEntry<'a>)> {
+        self.entries.iter()
+    }
+
+    pub fn entry(&self, id: NodeID) -> Option<&AstIndexEntry<'a>> {
+        self.entries.get(&id)
+    }
+
+    pub fn node(&self, id: NodeID) -> Option<AstNodeRef<'a>> {
+        self.entries.get(&id).map(|entry| entry.node)
+    }
+
+    pub fn source_unit_of(&self, id: NodeID) -> Option<&'a SourceUnit> {
+        self.entries.get(&id).map(|entry| entry.source_unit)
+    }
+
+    pub fn contract_definition_of(&self, id: NodeID) -> Option<&'a ContractDefinition> {
+        self.entries.get(&id).and_then(|entry| entry.contract_definition)
+    }
+
+    pub fn definition_of(&self, id: NodeID) -> Option<AstNodeRef<'a>> {
+        self.entries.get(&id).and_then(|entry| entry.definition)
+    }
+
+    pub fn source_unit(&self, id: NodeID) -> Option<&'a SourceUnit> {
+        match self.node(id)? {
+            AstNodeRef::SourceUnit(source_unit) => Some(source_unit),
+            _ => None,
+        }
+    }
+
+    pub fn contract_definition(&self, id: NodeID) -> Option<&'a ContractDefinition> {
+        match self.node(id)? {
+            AstNodeRef::ContractDefinition(contract_definition) => Some(contract_definition),
+            _ => None,
+        }
+    }
+
+    pub fn struct_definition(&self, id: NodeID) -> Option<&'a StructDefinition> {
+        match self.node(id)? {
+            AstNodeRef::StructDefinition(struct_definition) => Some(struct_definition),
+            _ => None,
+        }
+    }
+
+    pub fn enum_definition(&self, id: NodeID) -> Option<&'a EnumDefinition> {
+        match self.node(id)? {
+            AstNodeRef::EnumDefinition(enum_definition) => Some(enum_definition),
+            _ => None,
+        }
+    }
+
+    pub fn event_definition(&self, id: NodeID) -> Option<&'a EventDefinition> {
+        match self.node(id)? {
+            AstNodeRef::EventDefinition(event_definition) => Some(event_definition),
+            _ => None,
+        }
+    }
+
+    pub fn error_definition(&self, id: NodeID) -> Option<&'a ErrorDefinition> {
+        match self.node(id)? {
+            AstNodeRef::ErrorDefinition(error_definition) => Some(error_definition),
+            _ => None,
+        }
+    }
+
+    pub fn function_definition(&self, id: NodeID) -> Option<&'a FunctionDefinition> {
+        match self.node(id)? {
+            AstNodeRef::FunctionDefinition(function_definition) => Some(function_definition),
+            _ => None,
+        }
+    }
+
+    pub fn function_and_contract_definition(
+        &self,
+        id: NodeID,
+    ) -> Option<(Option<&'a ContractDefinition>, &'a FunctionDefinition)> {
+        Some((self.contract_definition_of(id), self.function_definition(id)?))
+    }
+
+    pub fn modifier_definition(&self, id: NodeID) -> Option<&'a ModifierDefinition> {
+        match self.node(id)? {
+            AstNodeRef::ModifierDefinition(modifier_definition) => Some(modifier_definition),
+            _ => None,
+        }
+    }
+
+    pub fn variable_declaration(&self, id: NodeID) -> Option<&'a VariableDeclaration> {
+        match self.node(id)? {
+            AstNodeRef::VariableDeclaration(variable_declaration) => Some(variable_declaration),
+            _ => None,
+        }
+    }
+
+    pub fn state_variable_declaration(&self, id: NodeID) -> Option<(&'a ContractDefinition, &'a VariableDeclaration)> {
+        match self.parent_node(id)? {
+            AstNodeRef::ContractDefinition(contract_definition) => Some((contract_definition, self.variable_declaration(id)?)),
+            _ => None,
+        }
+    }
+
+    pub fn hierarchy_contains_state_variable(&self, contract_definition: &ContractDefinition, state_variable_id: NodeID) -> bool {
+        let owner_id = match self.state_variable_declaration(state_variable_id) {
+            Some((owner, _)) => owner.id,
+            None => return false,
+        };
+
+        match contract_definition.linearized_base_contracts.as_ref() {
+            Some(contract_ids) => contract_ids.contains(&owner_id),
+            None => owner_id == contract_definition.id,
+        }
+    }
+
+    pub fn user_defined_value_type_definition(&self, id: NodeID) -> Option<&'a UserDefinedValueTypeDefinition> {
+        match self.node(id)? {
+            AstNodeRef::UserDefinedValueTypeDefinition(user_defined_value_type_definition) => Some(user_defined_value_type_definition),
+            _ => None,
+        }
+    }
+
+    pub fn block(&self, id: NodeID) -> Option<&'a Block> {
+        match self.node(id)? {
+            AstNodeRef::Block(block) => Some(block),
+            AstNodeRef::Statement(Statement::Block(block) | Statement::UncheckedBlock(block)) => Some(block),
+            _ => None,
+        }
+    }
+
+    pub fn statement(&self, id: NodeID) -> Option<&'a Statement> {
+        match self.node(id)? {
+            AstNodeRef::Statement(statement) => Some(statement),
+            _ => None,
+        }
+    }
+
+    pub fn expression(&self, id: NodeID) -> Option<&'a Expression> {
+        match self.node(id)? {
+            AstNodeRef::Expression(expression) => Some(expression),
+            _ => None,
+        }
+    }
+
+    pub fn find_contract_definition_node(
+        &self,
+        id: NodeID,
+    ) -> Option<(&'a ContractDefinition, &'a ContractDefinitionNode)> {
+        let entry = self.entries.get(&id)?;
+        let contract_definition = entry.contract_definition?;
+
+        let definition_node = contract_definition.nodes.iter().find(|node| match (node, entry.node) {
+            (ContractDefinitionNode::UsingForDirective(node), AstNodeRef::UsingForDirective(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::StructDefinition(node), AstNodeRef::StructDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::EnumDefinition(node), AstNodeRef::EnumDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::VariableDeclaration(node), AstNodeRef::VariableDeclaration(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::EventDefinition(node), AstNodeRef::EventDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::FunctionDefinition(node), AstNodeRef::FunctionDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::ModifierDefinition(node), AstNodeRef::ModifierDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::ErrorDefinition(node), AstNodeRef::ErrorDefinition(other)) => std::ptr::eq(node, other),
+            (ContractDefinitionNode::UserDefinedValueTypeDefinition(node), AstNodeRef::UserDefinedValueTypeDefinition(other)) => std::ptr::eq(node, other),
+            _ => false,
+        })?;
+
+        Some((contract_definition, definition_node))
+    }
+
+    pub fn parent(&self, id: NodeID) -> Option<NodeID> {
+        self.entries.get(&id).and_then(|entry| entry.parent)
+    }
+
+    pub fn parent_node(&self, id: NodeID) -> Option<AstNodeRef<'a>> {
+        self.node(self.parent(id)?)
+    }
+
+    pub fn ancestors(&self, id: NodeID) -> impl Iterator<Item = (NodeID, AstNodeRef<'a>)> + '_ {
+        std::iter::successors(self.parent(id), move |&id| self.parent(id))
+            .filter_map(move |id| Some((id, self.node(id)?)))
+    }
+
+    pub fn find_ancestor<T>(&self, id: NodeID, predicate: impl FnMut(AstNodeRef<'a>) -> Option<T>) -> Option<T> {
+        self.ancestors(id).map(|(_, node)| node).find_map(predicate)
+    }
+
+    pub fn is_ancestor(&self, ancestor_id: NodeID, id: NodeID) -> bool {
+        self.ancestors(id).any(|(id, _)| id == ancestor_id)
+    }
+
+    pub fn enclosing_statement(&self, id: NodeID) -> Option<&'a Statement> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Statement(statement) => Some(statement),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_block(&self, id: NodeID) -> Option<&'a Block> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Block(block) => Some(block),
+            AstNodeRef::Statement(Statement::Block(block) | Statement::UncheckedBlock(block)) => Some(block),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_loop(&self, id: NodeID) -> Option<&'a Statement> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Statement(
+                statement @ (Statement::ForStatement(_) | Statement::WhileStatement(_) | Statement::DoWhileStatement(_))
+            ) => Some(statement),
+            _ => None,
+        })
+    }
+
+    pub fn is_inside_loop(&self, id: NodeID) -> bool {
+        self.enclosing_loop(id).is_some()
+    }
+
+    pub fn enclosing_if_statement(&self, id: NodeID) -> Option<&'a IfStatement> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Statement(Statement::IfStatement(if_statement)) => Some(if_statement),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_unchecked_block(&self, id: NodeID) -> Option<&'a Block> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Statement(Statement::UncheckedBlock(block)) => Some(block),
+            _ => None,
+        })
+    }
+
+    pub fn is_inside_unchecked(&self, id: NodeID) -> bool {
+        self.enclosing_unchecked_block(id).is_some()
+    }
+
+    pub fn enclosing_try_statement(&self, id: NodeID) -> Option<&'a TryStatement> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Statement(Statement::TryStatement(try_statement)) => Some(try_statement),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_try_catch_clause(&self, id: NodeID) -> Option<&'a TryCatchClause> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::TryCatchClause(try_catch_clause) => Some(try_catch_clause),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_function_call(&self, id: NodeID) -> Option<&'a FunctionCall> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::Expression(Expression::FunctionCall(function_call)) => Some(function_call),
+            AstNodeRef::FunctionCall(function_call) => Some(function_call),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_function(&self, id: NodeID) -> Option<&'a FunctionDefinition> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::FunctionDefinition(function_definition) => Some(function_definition),
+            _ => None,
+        })
+    }
+
+    pub fn enclosing_modifier(&self, id: NodeID) -> Option<&'a ModifierDefinition> {
+        self.find_ancestor(id, |node| match node {
+            AstNodeRef::ModifierDefinition(modifier_definition) => Some(modifier_definition),
+            _ => None,
+        })
+    }
+
+    fn insert(&mut self, id: NodeID, node: AstNodeRef<'a>, scope: AstIndexScope<'a>) -> AstIndexScope<'a> {
+        self.entries.entry(id).or_insert(AstIndexEntry {
+            node,
+            source_unit: scope.source_unit,
+            contract_definition: scope.contract_definition,
+            definition: scope.definition,
+            parent: scope.parent,
+        });
+
+        AstIndexScope {
+            parent: Some(id),
+            ..scope
+        }
+    }
+
+    fn index_source_unit(&mut self, source_unit: &'a SourceUnit) {
+        let scope = AstIndexScope {
+            source_unit,
+            contract_definition: None,
+            definition: None,
+            parent: None,
+        };
+
+        let scope = self.insert(source_unit.id, AstNodeRef::SourceUnit(source_unit), scope);
+
+        for node in source_unit.nodes.iter() {
+            match node {
+                SourceUnitNode::PragmaDirective(pragma_directive) => {
+                    self.insert(pragma_directive.id, AstNodeRef::PragmaDirective(pragma_directive), scope);
+                }
+
+                SourceUnitNode::ImportDirective(import_directive) => {
+                    self.insert(import_directive.id, AstNodeRef::ImportDirective(import_directive), scope);
+                }
+
+                SourceUnitNode::UsingForDirective(using_for_directive) => self.index_using_for_directive(using_for_directive, scope),
+                SourceUnitNode::ContractDefinition(contract_definition) => self.index_contract_definition(contract_definition, scope),
+                SourceUnitNode::StructDefinition(struct_definition) => self.index_struct_definition(struct_definition, scope),
+                SourceUnitNode::EnumDefinition(enum_definition) => self.index_enum_definition(enum_definition, scope),
+                SourceUnitNode::EventDefinition(event_definition) => self.index_event_definition(event_definition, scope),
+                SourceUnitNode::ErrorDefinition(error_definition) => self.index_error_definition(error_definition, scope),
+                SourceUnitNode::FunctionDefinition(function_definition) => self.index_function_definition(function_definition, scope),
+                SourceUnitNode::VariableDeclaration(variable_declaration) => self.index_variable_declaration(variable_declaration, scope),
+                SourceUnitNode::UserDefinedValueTypeDefinition(user_defined_value_type_definition) => {
+                    self.index_user_defined_value_type_definition(user_defined_value_type_definition, scope)
+                }
+            }
+        }
+    }
+
+    fn index_contract_definition(&mut self, contract_definition: &'a ContractDefinition, scope: AstIndexScope<'a>) {
+        let scope = self.insert(contract_definition.id, AstNodeRef::ContractDefinition(contract_definition), scope);
+
+        let scope = AstIndexScope {
+            contract_definition: Some(contract_definition),
+            ..scope
+        };
+
+        for base_contract in contract_definition.base_contracts.iter() {
+            let scope = self.insert(base_contract.id, AstNodeRef::InheritanceSpecifier(base_contract), scope);
+
+            for argument in base_contract.arguments.iter().flatten() {
+                self.index_expression(argument, scope);
+            }
+        }
+
+        for node in contract_definition.nodes.iter() {
+            match node {
+                ContractDefinitionNode::UsingForDirective(using_for_directive) => self.index_using_for_directive(using_for_directive, scope),
+                ContractDefinitionNode::StructDefinition(struct_definition) => self.index_struct_definition(struct_definition, scope),
+                ContractDefinitionNode::EnumDefinition(enum_definition) => self.index_enum_definition(enum_definition, scope),
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => self.index_variable_declaration(variable_declaration, scope),
+                ContractDefinitionNode::EventDefinition(event_definition) => self.index_event_definition(event_definition, scope),
+                ContractDefinitionNode::FunctionDefinition(function_definition) => self.index_function_definition(function_definition, scope),
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => self.index_modifier_definition(modifier_definition, scope),
+                ContractDefinitionNode::ErrorDefinition(error_definition) => self.index_error_definition(error_definition, scope),
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(user_defined_value_type_definition) => {
+                    self.index_user_defined_value_type_definition(user_defined_value_type_definition, scope)
+                }
+            }
+        }
+    }
+
+    fn index_using_for_directive(&mut self, using_for_directive: &'a UsingForDirective, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::UsingForDirective(using_for_directive);
+        let scope = self.insert(using_for_directive.id, node, scope.with_definition(node));
+
+        if let Some(type_name) = using_for_directive.type_name.as_ref() {
+            self.index_type_name(type_name, scope);
+        }
+    }
+
+    fn index_struct_definition(&mut self, struct_definition: &'a StructDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::StructDefinition(struct_definition);
+        let scope = self.insert(struct_definition.id, node, scope.with_definition(node));
+
+        for member in struct_definition.members.iter() {
+            self.index_variable_declaration(member, scope);
+        }
+    }
+
+    fn index_enum_definition(&mut self, enum_definition: &'a EnumDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::EnumDefinition(enum_definition);
+        let scope = self.insert(enum_definition.id, node, scope.with_definition(node));
+
+        for member in enum_definition.members.iter() {
+            self.insert(member.id, AstNodeRef::EnumValue(member), scope);
+        }
+    }
+
+    fn index_event_definition(&mut self, event_definition: &'a EventDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::EventDefinition(event_definition);
+        let scope = self.insert(event_definition.id, node, scope.with_definition(node));
+        self.index_parameter_list(&event_definition.parameters, scope);
+    }
+
+    fn index_error_definition(&mut self, error_definition: &'a ErrorDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::ErrorDefinition(error_definition);
+        let scope = self.insert(error_definition.id, node, scope.with_definition(node));
+        self.index_parameter_list(&error_definition.parameters, scope);
+    }
+
+    fn index_user_defined_value_type_definition(
+        &mut self,
+        user_defined_value_type_definition: &'a UserDefinedValueTypeDefinition,
+        scope: AstIndexScope<'a>,
+    ) {
+        let node = AstNodeRef::UserDefinedValueTypeDefinition(user_defined_value_type_definition);
+        let scope = self.insert(user_defined_value_type_definition.id, node, scope.with_definition(node));
+        self.index_type_name(&user_defined_value_type_definition.underlying_type, scope);
+    }
+
+    fn index_function_definition(&mut self, function_definition: &'a FunctionDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::FunctionDefinition(function_definition);
+        let scope = self.insert(function_definition.id, node, scope.with_definition(node));
+        self.index_parameter_list(&function_definition.parameters, scope);
+        self.index_parameter_list(&function_definition.return_parameters, scope);
+
+        for modifier_invocation in function_definition.modifiers.iter() {
+            let scope = self.insert(modifier_invocation.id, AstNodeRef::ModifierInvocation(modifier_invocation), scope);
+
+            for argument in modifier_invocation.arguments.iter().flatten() {
+                self.index_expression(argument, scope);
+            }
+        }
+
+        if let Some(body) = function_definition.body.as_ref() {
+            self.index_block(body, scope);
+        }
+    }
+
+    fn index_modifier_definition(&mut self, modifier_definition: &'a ModifierDefinition, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::ModifierDefinition(modifier_definition);
+        let scope = self.insert(modifier_definition.id, node, scope.with_definition(node));
+        self.index_parameter_list(&modifier_definition.parameters, scope);
+        self.index_block(&modifier_definition.body, scope);
+    }
+
+    fn index_variable_declaration(&mut self, variable_declaration: &'a VariableDeclaration, scope: AstIndexScope<'a>) {
+        let node = AstNodeRef::VariableDeclaration(variable_declaration);
+        let scope = self.insert(variable_declaration.id, node, scope.with_definition(node));
+
+        if let Some(type_name) = variable_declaration.type_name.as_ref() {
+            self.index_type_name(type_name, scope);
+        }
+
+        if let Some(value) = variable_declaration.value.as_ref() {
+            self.index_expression(value, scope);
+        }
+    }
+
+    fn index_parameter_list(&mut self, parameter_list: &'a ParameterList, scope: AstIndexScope<'a>) {
+        let scope = self.insert(parameter_list.id, AstNodeRef::ParameterList(parameter_list), scope);
+
+        for parameter in parameter_list.parameters.iter() {
+            self.index_variable_declaration(parameter, scope);
+        }
+    }
+
+    fn index_type_name(&mut self, type_name: &'a TypeName, scope: AstIndexScope<'a>) {
+        match type_name {
+            TypeName::FunctionTypeName(function_type_name) => {
+                self.index_parameter_list(&function_type_name.parameter_types, scope);
+                self.index_parameter_list(&function_type_name.return_parameter_types, scope);
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => self.index_type_name(&array_type_name.base_type, scope),
+
+            TypeName::Mapping(mapping) => {
+                self.index_type_name(&mapping.key_type, scope);
+                self.index_type_name(&mapping.value_type, scope);
+            }
+
+            TypeName::UserDefinedTypeName(_) | TypeName::ElementaryTypeName(_) | TypeName::String(_) => {}
+        }
+    }
+
+    fn index_block(&mut self, block: &'a Block, scope: AstIndexScope<'a>) {
+        let scope = self.insert(block.id, AstNodeRef::Block(block), scope);
+
+        for statement in block.statements.iter() {
+            self.index_statement(statement, scope);
+        }
+    }
+
+    fn index_block_or_statement(&mut self, block_or_statement: &'a BlockOrStatement, scope: AstIndexScope<'a>) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.index_block(block, scope),
+            BlockOrStatement::Statement(statement) => self.index_statement(statement, scope),
+        }
+    }
+
+    fn index_function_call(&mut self, function_call: &'a FunctionCall, scope: AstIndexScope<'a>) {
+        let scope = self.insert(function_call.id, AstNodeRef::FunctionCall(function_call), scope);
+        self.index_expression(&function_call.expression, scope);
+
+        for argument in function_call.arguments.iter() {
+            self.index_expression(argument, scope);
+        }
+    }
+
+    fn index_statement(&mut self, statement: &'a Statement, scope: AstIndexScope<'a>) {
+        let scope = match statement.id() {
+            Some(id) => self.insert(id, AstNodeRef::Statement(statement), scope),
+            None => scope,
+        };
+
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                for declaration in variable_declaration_statement.declarations.iter().flatten() {
+                    self.index_variable_declaration(declaration, scope);
+                }
+
+                if let Some(initial_value) = variable_declaration_statement.initial_value.as_ref() {
+                    self.index_expression(initial_value, scope);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.index_expression(&if_statement.condition, scope);
+                self.index_block_or_statement(&if_statement.true_body, scope);
+
+                if let Some(false_body) = if_statement.false_body.as_ref() {
+                    self.index_block_or_statement(false_body, scope);
+                }
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.index_block_or_statement(&do_while_statement.body, scope);
+                self.index_expression(&do_while_statement.condition, scope);
+            }
+
+            Statement::ForStatement(for_statement) => {
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_ref() {
+                    self.index_statement(initialization_expression, scope);
+                }
+
+                if let Some(condition) = for_statement.condition.as_ref() {
+                    self.index_expression(condition, scope);
+                }
+
+                if let Some(loop_expression) = for_statement.loop_expression.as_ref() {
+                    self.index_statement(loop_expression, scope);
+                }
+
+                self.index_block_or_statement(&for_statement.body, scope);
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.index_expression(&while_statement.condition, scope);
+                self.index_block_or_statement(&while_statement.body, scope);
+            }
+
+            Statement::EmitStatement(emit_statement) => self.index_expression(&emit_statement.event_call, scope),
+
+            Statement::TryStatement(try_statement) => {
+                self.index_function_call(&try_statement.external_call, scope);
+
+                for clause in try_statement.clauses.iter() {
+                    let scope = self.insert(clause.id, AstNodeRef::TryCatchClause(clause), scope);
+
+                    if let Some(parameters) = clause.parameters.as_ref() {
+                        self.index_parameter_list(parameters, scope);
+                    }
+
+                    self.index_block(&clause.block, scope);
+                }
+            }
+
+            //
+            // The statement itself was indexed under the block's id, so only its statements are indexed here
+            //
+
+            Statement::Block(block) | Statement::UncheckedBlock(block) => {
+                for statement in block.statements.iter() {
+                    self.index_statement(statement, scope);
+                }
+            }
+
+            Statement::Return(return_statement) => {
+                if let Some(expression) = return_statement.expression.as_ref() {
+                    self.index_expression(expression, scope);
+                }
+            }
+
+            Statement::RevertStatement(revert_statement) => self.index_function_call(&revert_statement.error_call, scope),
+            Statement::ExpressionStatement(expression_statement) => self.index_expression(&expression_statement.expression, scope),
+
+            Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::PlaceholderStatement(_)
+            | Statement::InlineAssembly(_)
+            | Statement::UnhandledStatement(_) => {}
+        }
+    }
+
+    fn index_expression(&mut self, expression: &'a Expression, scope: AstIndexScope<'a>) {
+        let scope = match expression.id() {
+            Some(id) => self.insert(id, AstNodeRef::Expression(expression), scope),
+            None => scope,
+        };
+
+        match expression {
+            Expression::UnaryOperation(unary_operation) => self.index_expression(&unary_operation.sub_expression, scope),
+
+            Expression::BinaryOperation(binary_operation) => {
+                self.index_expression(&binary_operation.left_expression, scope);
+                self.index_expression(&binary_operation.right_expression, scope);
+            }
+
+            Expression::Conditional(conditional) => {
+                self.index_expression(&conditional.condition, scope);
+                self.index_expression(&conditional.true_expression, scope);
+                self.index_expression(&conditional.false_expression, scope);
+            }
+
+            Expression::Assignment(assignment) => {
+                self.index_expression(&assignment.left_hand_side, scope);
+                self.index_expression(&assignment.right_hand_side, scope);
+            }
+
+            Expression::FunctionCall(function_call) => {
+                self.index_expression(&function_call.expression, scope);
+
+                for argument in function_call.arguments.iter() {
+                    self.index_expression(argument, scope);
+                }
+            }
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                self.index_expression(&function_call_options.expression, scope);
+
+                for option in function_call_options.options.iter() {
+                    self.index_expression(option, scope);
+                }
+
+                for argument in function_call_options.arguments.iter().flatten() {
+                    self.index_expression(argument, scope);
+                }
+            }
+
+            Expression::IndexAccess(index_access) => {
+                self.index_expression(&index_access.base_expression, scope);
+                self.index_expression(&index_access.index_expression, scope);
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                self.index_expression(&index_range_access.base_expression, scope);
+
+                if let Some(start_expression) = index_range_access.start_expression.as_ref() {
+                    self.index_expression(start_expression, scope);
+                }
+
+                if let Some(end_expression) = index_range_access.end_expression.as_ref() {
+                    self.index_expression(end_expression, scope);
+                }
+            }
+
+            Expression::MemberAccess(member_access) => self.index_expression(&member_access.expression, scope),
+
+            Expression::TupleExpression(tuple_expression) => {
+                for component in tuple_expression.components.iter().flatten() {
+                    self.index_expression(component, scope);
+                }
+            }
+
+            Expression::NewExpression(new_expression) => self.index_type_name(&new_expression.type_name, scope),
+
+            Expression::Literal(_)
+            | Expression::Identifier(_)
+            | Expression::ElementaryTypeNameExpression(_)
+            | Expression::UnhandledExpression(_) => {}
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod blocks;
 mod contracts;
+mod deserialization;
 mod documentation;
 mod enumerations;
 mod errors;
@@ -8,8 +9,10 @@ mod expressions;
 mod functions;
 mod identifiers;
 mod import_directives;
+mod index;
 mod literals;
 mod modifiers;
+mod nodes;
 mod pragma_directives;
 mod source_units;
 mod statements;
@@ -21,8 +24,8 @@ mod variables;
 mod visitor;
 
 pub use self::{
-    blocks::*, contracts::*, documentation::*, enumerations::*, errors::*, events::*,
-    expressions::*, functions::*, identifiers::*, import_directives::*, literals::*, modifiers::*,
+    blocks::*, contracts::*, deserialization::*, documentation::*, enumerations::*, errors::*, events::*,
+    expressions::*, functions::*, identifiers::*, import_directives::*, index::*, literals::*, modifiers::*,
     pragma_directives::*, source_units::*, statements::*, structures::*, types::*,
     user_defined_value_types::*, using_for_directives::*, variables::*, visitor::*,
 };
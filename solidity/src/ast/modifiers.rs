@@ -46,9 +46,10 @@ impl Display for ModifierDefinition {
 
 pub struct ModifierDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub modifier_definition: &'a ModifierDefinition,
 }
 
@@ -56,6 +57,7 @@ impl<'a> ModifierDefinitionContext<'a> {
     pub fn create_block_context<'b>(&self, block: &'a Block, blocks: &'b mut Vec<&'a Block>) -> BlockContext::<'a, 'b> {
         BlockContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: self.contract_definition,
             definition_node: self.definition_node,
@@ -106,8 +108,9 @@ impl Display for ModifierInvocation {
 
 pub struct ModifierInvocationContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub modifier_invocation: &'a ModifierInvocation,
 }
@@ -0,0 +1,1329 @@
+use super::*;
+use eth_lang_utils::ast::*;
+
+//
+// AstNode implementations for the Solidity AST. Type names carry no id or source location of their own,
+// so they report None for both. AstIndex keeps its own typed traversal to track enclosing definitions; the
+// resolver tests check that both traversals reach the same nodes.
+//
+
+fn push_documentation<'a>(children: &mut Vec<&'a dyn AstNode>, documentation: Option<&'a Documentation>) {
+    if let Some(Documentation::Structured(Some(documentation))) = documentation {
+        children.push(documentation);
+    }
+}
+
+impl SourceUnitNode {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            SourceUnitNode::PragmaDirective(node) => node,
+            SourceUnitNode::ImportDirective(node) => node,
+            SourceUnitNode::UsingForDirective(node) => node,
+            SourceUnitNode::ContractDefinition(node) => node,
+            SourceUnitNode::StructDefinition(node) => node,
+            SourceUnitNode::EnumDefinition(node) => node,
+            SourceUnitNode::EventDefinition(node) => node,
+            SourceUnitNode::ErrorDefinition(node) => node,
+            SourceUnitNode::FunctionDefinition(node) => node,
+            SourceUnitNode::VariableDeclaration(node) => node,
+            SourceUnitNode::UserDefinedValueTypeDefinition(node) => node,
+        }
+    }
+}
+
+impl AstNode for SourceUnitNode {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl ContractDefinitionNode {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            ContractDefinitionNode::UsingForDirective(node) => node,
+            ContractDefinitionNode::StructDefinition(node) => node,
+            ContractDefinitionNode::EnumDefinition(node) => node,
+            ContractDefinitionNode::VariableDeclaration(node) => node,
+            ContractDefinitionNode::EventDefinition(node) => node,
+            ContractDefinitionNode::FunctionDefinition(node) => node,
+            ContractDefinitionNode::ModifierDefinition(node) => node,
+            ContractDefinitionNode::ErrorDefinition(node) => node,
+            ContractDefinitionNode::UserDefinedValueTypeDefinition(node) => node,
+        }
+    }
+}
+
+impl AstNode for ContractDefinitionNode {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl Statement {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            Statement::VariableDeclarationStatement(node) => node,
+            Statement::IfStatement(node) => node,
+            Statement::DoWhileStatement(node) => node,
+            Statement::ForStatement(node) => node,
+            Statement::WhileStatement(node) => node,
+            Statement::EmitStatement(node) => node,
+            Statement::TryStatement(node) => node,
+            Statement::Block(node) => node,
+            Statement::UncheckedBlock(node) => node,
+            Statement::Break(node) => node,
+            Statement::Continue(node) => node,
+            Statement::PlaceholderStatement(node) => node,
+            Statement::Return(node) => node,
+            Statement::RevertStatement(node) => node,
+            Statement::ExpressionStatement(node) => node,
+            Statement::InlineAssembly(node) => node,
+            Statement::UnhandledStatement(node) => node,
+        }
+    }
+}
+
+impl AstNode for Statement {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        match self {
+            Statement::UncheckedBlock(_) => "UncheckedBlock",
+            _ => self.as_ast_node().node_type(),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl BlockOrStatement {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            BlockOrStatement::Block(node) => node.as_ref(),
+            BlockOrStatement::Statement(node) => node.as_ref(),
+        }
+    }
+}
+
+impl AstNode for BlockOrStatement {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl Expression {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            Expression::Literal(node) => node,
+            Expression::Identifier(node) => node,
+            Expression::UnaryOperation(node) => node,
+            Expression::BinaryOperation(node) => node,
+            Expression::Conditional(node) => node,
+            Expression::Assignment(node) => node,
+            Expression::FunctionCall(node) => node,
+            Expression::FunctionCallOptions(node) => node,
+            Expression::IndexAccess(node) => node,
+            Expression::IndexRangeAccess(node) => node,
+            Expression::MemberAccess(node) => node,
+            Expression::ElementaryTypeNameExpression(node) => node,
+            Expression::TupleExpression(node) => node,
+            Expression::NewExpression(node) => node,
+            Expression::UnhandledExpression(node) => node,
+        }
+    }
+}
+
+impl AstNode for Expression {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl TypeName {
+    fn as_ast_node(&self) -> Option<&dyn AstNode> {
+        match self {
+            TypeName::FunctionTypeName(node) => Some(node),
+            TypeName::ArrayTypeName(node) => Some(node),
+            TypeName::Mapping(node) => Some(node),
+            TypeName::UserDefinedTypeName(node) => Some(node),
+            TypeName::ElementaryTypeName(node) => Some(node),
+            TypeName::String(_) => None,
+        }
+    }
+}
+
+impl AstNode for TypeName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().map(|node| node.node_type()).unwrap_or("ElementaryTypeName")
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().map(|node| node.children()).unwrap_or_default()
+    }
+}
+
+impl AstNode for SourceUnit {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "SourceUnit"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.nodes.iter().map(|node| node as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for PragmaDirective {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "PragmaDirective"
+    }
+}
+
+impl AstNode for ImportDirective {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ImportDirective"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.symbol_aliases.iter().map(|symbol_alias| &symbol_alias.foreign as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for UsingForDirective {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "UsingForDirective"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(library_name) = self.library_name.as_ref() {
+            children.push(library_name);
+        }
+
+        children.extend(self.function_list.iter().map(|function| function.identifier_path() as &dyn AstNode));
+
+        if let Some(type_name) = self.type_name.as_ref() {
+            children.push(type_name);
+        }
+
+        children
+    }
+}
+
+impl AstNode for ContractDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ContractDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+        children.extend(self.base_contracts.iter().map(|base_contract| base_contract as &dyn AstNode));
+        children.extend(self.nodes.iter().map(|node| node as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for InheritanceSpecifier {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "InheritanceSpecifier"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.base_name];
+        children.extend(self.arguments.iter().flatten().map(|argument| argument as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for StructuredDocumentation {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "StructuredDocumentation"
+    }
+}
+
+impl AstNode for EnumDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "EnumDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.members.iter().map(|member| member as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for EnumValue {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "EnumValue"
+    }
+}
+
+impl AstNode for ErrorDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ErrorDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+        children.push(&self.parameters);
+
+        children
+    }
+}
+
+impl AstNode for EventDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "EventDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+        children.push(&self.parameters);
+
+        children
+    }
+}
+
+impl AstNode for UnaryOperation {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "UnaryOperation"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.sub_expression.as_ref()]
+    }
+}
+
+impl AstNode for BinaryOperation {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "BinaryOperation"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.left_expression.as_ref(), self.right_expression.as_ref()]
+    }
+}
+
+impl AstNode for Conditional {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Conditional"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.condition.as_ref(), self.true_expression.as_ref(), self.false_expression.as_ref()]
+    }
+}
+
+impl AstNode for Assignment {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Assignment"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.left_hand_side.as_ref(), self.right_hand_side.as_ref()]
+    }
+}
+
+impl AstNode for FunctionCall {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "FunctionCall"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![self.expression.as_ref()];
+        children.extend(self.arguments.iter().map(|argument| argument as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for FunctionCallOptions {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "FunctionCallOptions"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![self.expression.as_ref()];
+        children.extend(self.options.iter().map(|option| option as &dyn AstNode));
+        children.extend(self.arguments.iter().flatten().map(|argument| argument as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for IndexAccess {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "IndexAccess"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.base_expression.as_ref(), self.index_expression.as_ref()]
+    }
+}
+
+impl AstNode for IndexRangeAccess {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "IndexRangeAccess"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![self.base_expression.as_ref()];
+
+        if let Some(start_expression) = self.start_expression.as_deref() {
+            children.push(start_expression);
+        }
+
+        if let Some(end_expression) = self.end_expression.as_deref() {
+            children.push(end_expression);
+        }
+
+        children
+    }
+}
+
+impl AstNode for MemberAccess {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "MemberAccess"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.expression.as_ref()]
+    }
+}
+
+impl AstNode for ElementaryTypeNameExpression {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ElementaryTypeNameExpression"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.type_name]
+    }
+}
+
+impl AstNode for TupleExpression {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "TupleExpression"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.components.iter().flatten().map(|component| component as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for NewExpression {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "NewExpression"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.type_name]
+    }
+}
+
+impl AstNode for ParameterList {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ParameterList"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.parameters.iter().map(|parameter| parameter as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for OverrideSpecifier {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "OverrideSpecifier"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.overrides.iter().map(|identifier_path| identifier_path as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for FunctionDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "FunctionDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+
+        if let Some(overrides) = self.overrides.as_ref() {
+            children.push(overrides);
+        }
+
+        children.push(&self.parameters);
+        children.push(&self.return_parameters);
+        children.extend(self.modifiers.iter().map(|modifier| modifier as &dyn AstNode));
+
+        if let Some(body) = self.body.as_ref() {
+            children.push(body);
+        }
+
+        children
+    }
+}
+
+impl AstNode for Identifier {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Identifier"
+    }
+}
+
+impl AstNode for IdentifierPath {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "IdentifierPath"
+    }
+}
+
+impl AstNode for Literal {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Literal"
+    }
+}
+
+impl AstNode for ModifierDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ModifierDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+        children.push(&self.parameters);
+
+        if let Some(overrides) = self.overrides.as_ref() {
+            children.push(overrides);
+        }
+
+        children.push(&self.body);
+
+        children
+    }
+}
+
+impl AstNode for ModifierInvocation {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ModifierInvocation"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.modifier_name];
+        children.extend(self.arguments.iter().flatten().map(|argument| argument as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for Block {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Block"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.statements.iter().map(|statement| statement as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for ExpressionStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ExpressionStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.expression]
+    }
+}
+
+impl AstNode for VariableDeclarationStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "VariableDeclarationStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        children.extend(self.declarations.iter().flatten().map(|declaration| declaration as &dyn AstNode));
+
+        if let Some(initial_value) = self.initial_value.as_ref() {
+            children.push(initial_value);
+        }
+
+        children
+    }
+}
+
+impl AstNode for IfStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "IfStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.condition, &self.true_body];
+
+        if let Some(false_body) = self.false_body.as_ref() {
+            children.push(false_body);
+        }
+
+        children
+    }
+}
+
+impl AstNode for ForStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "ForStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(initialization_expression) = self.initialization_expression.as_deref() {
+            children.push(initialization_expression);
+        }
+
+        if let Some(condition) = self.condition.as_ref() {
+            children.push(condition);
+        }
+
+        if let Some(loop_expression) = self.loop_expression.as_deref() {
+            children.push(loop_expression);
+        }
+
+        children.push(&self.body);
+
+        children
+    }
+}
+
+impl AstNode for WhileStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "WhileStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.condition, &self.body]
+    }
+}
+
+impl AstNode for DoWhileStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "DoWhileStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.body, &self.condition]
+    }
+}
+
+impl AstNode for EmitStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "EmitStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.event_call]
+    }
+}
+
+impl AstNode for TryStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "TryStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.external_call];
+        children.extend(self.clauses.iter().map(|clause| clause as &dyn AstNode));
+
+        children
+    }
+}
+
+impl AstNode for RevertStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "RevertStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.error_call]
+    }
+}
+
+impl AstNode for TryCatchClause {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "TryCatchClause"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(parameters) = self.parameters.as_ref() {
+            children.push(parameters);
+        }
+
+        children.push(&self.block);
+
+        children
+    }
+}
+
+impl AstNode for Return {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Return"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(expression) = self.expression.as_ref() {
+            children.push(expression);
+        }
+
+        children
+    }
+}
+
+impl AstNode for Break {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Break"
+    }
+}
+
+impl AstNode for Continue {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "Continue"
+    }
+}
+
+impl AstNode for PlaceholderStatement {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "PlaceholderStatement"
+    }
+}
+
+impl AstNode for InlineAssembly {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "InlineAssembly"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(ast) = self.ast.as_ref() {
+            children.push(ast);
+        }
+
+        children
+    }
+}
+
+impl AstNode for StructDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "StructDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.members.iter().map(|member| member as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for ElementaryTypeName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "ElementaryTypeName"
+    }
+}
+
+impl AstNode for UserDefinedTypeName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "UserDefinedTypeName"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(path_node) = self.path_node.as_ref() {
+            children.push(path_node);
+        }
+
+        children
+    }
+}
+
+impl AstNode for FunctionTypeName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "FunctionTypeName"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.parameter_types, &self.return_parameter_types]
+    }
+}
+
+impl AstNode for ArrayTypeName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "ArrayTypeName"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![self.base_type.as_ref()];
+
+        if let Some(length) = self.length.as_ref() {
+            children.push(length);
+        }
+
+        children
+    }
+}
+
+impl AstNode for Mapping {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        None
+    }
+
+    fn node_type(&self) -> &str {
+        "Mapping"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![self.key_type.as_ref(), self.value_type.as_ref()]
+    }
+}
+
+impl AstNode for UserDefinedValueTypeDefinition {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "UserDefinedValueTypeDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.underlying_type]
+    }
+}
+
+impl AstNode for VariableDeclaration {
+    fn id(&self) -> Option<NodeID> {
+        Some(self.id)
+    }
+
+    fn src(&self) -> Option<&str> {
+        Some(self.src.as_str())
+    }
+
+    fn node_type(&self) -> &str {
+        "VariableDeclaration"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        push_documentation(&mut children, self.documentation.as_ref());
+
+        if let Some(type_name) = self.type_name.as_ref() {
+            children.push(type_name);
+        }
+
+        if let Some(overrides) = self.overrides.as_ref() {
+            children.push(overrides);
+        }
+
+        if let Some(value) = self.value.as_ref() {
+            children.push(value);
+        }
+
+        children
+    }
+}
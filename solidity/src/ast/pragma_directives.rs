@@ -12,6 +12,7 @@ pub struct PragmaDirective {
 
 pub struct PragmaDirectiveContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub pragma_directive: &'a PragmaDirective,
 }
@@ -1,21 +1,61 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, io};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::HashMap, io, sync::OnceLock};
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceUnitNode {
     PragmaDirective(PragmaDirective),
     ImportDirective(ImportDirective),
+    UsingForDirective(UsingForDirective),
     ContractDefinition(ContractDefinition),
     StructDefinition(StructDefinition),
     EnumDefinition(EnumDefinition),
+    EventDefinition(EventDefinition),
     ErrorDefinition(ErrorDefinition),
+    FunctionDefinition(FunctionDefinition),
     VariableDeclaration(VariableDeclaration),
     UserDefinedValueTypeDefinition(UserDefinedValueTypeDefinition),
 }
 
+impl<'de> Deserialize<'de> for SourceUnitNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("PragmaDirective") => SourceUnitNode::PragmaDirective(Deserialize::deserialize(content)?),
+            Some("ImportDirective") => SourceUnitNode::ImportDirective(Deserialize::deserialize(content)?),
+            Some("UsingForDirective") => SourceUnitNode::UsingForDirective(Deserialize::deserialize(content)?),
+            Some("ContractDefinition") => SourceUnitNode::ContractDefinition(Deserialize::deserialize(content)?),
+            Some("StructDefinition") => SourceUnitNode::StructDefinition(Deserialize::deserialize(content)?),
+            Some("EnumDefinition") => SourceUnitNode::EnumDefinition(Deserialize::deserialize(content)?),
+            Some("EventDefinition") => SourceUnitNode::EventDefinition(Deserialize::deserialize(content)?),
+            Some("ErrorDefinition") => SourceUnitNode::ErrorDefinition(Deserialize::deserialize(content)?),
+            Some("FunctionDefinition") => SourceUnitNode::FunctionDefinition(Deserialize::deserialize(content)?),
+            Some("VariableDeclaration") => SourceUnitNode::VariableDeclaration(Deserialize::deserialize(content)?),
+            Some("UserDefinedValueTypeDefinition") => SourceUnitNode::UserDefinedValueTypeDefinition(Deserialize::deserialize(content)?),
+            node_type => return Err(unexpected_node_type(node_type, "source unit")),
+        }))
+    }
+}
+
+impl Serialize for SourceUnitNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SourceUnitNode::PragmaDirective(node) => serialize_tagged(serializer, NodeType::PragmaDirective, node),
+            SourceUnitNode::ImportDirective(node) => serialize_tagged(serializer, NodeType::ImportDirective, node),
+            SourceUnitNode::UsingForDirective(node) => serialize_tagged(serializer, NodeType::UsingForDirective, node),
+            SourceUnitNode::ContractDefinition(node) => serialize_tagged(serializer, NodeType::ContractDefinition, node),
+            SourceUnitNode::StructDefinition(node) => serialize_tagged(serializer, NodeType::StructDefinition, node),
+            SourceUnitNode::EnumDefinition(node) => serialize_tagged(serializer, NodeType::EnumDefinition, node),
+            SourceUnitNode::EventDefinition(node) => serialize_tagged(serializer, NodeType::EventDefinition, node),
+            SourceUnitNode::ErrorDefinition(node) => serialize_tagged(serializer, NodeType::ErrorDefinition, node),
+            SourceUnitNode::FunctionDefinition(node) => serialize_tagged(serializer, NodeType::FunctionDefinition, node),
+            SourceUnitNode::VariableDeclaration(node) => serialize_tagged(serializer, NodeType::VariableDeclaration, node),
+            SourceUnitNode::UserDefinedValueTypeDefinition(node) => serialize_tagged(serializer, NodeType::UserDefinedValueTypeDefinition, node),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceUnit {
@@ -27,36 +67,64 @@ pub struct SourceUnit {
 
     #[serde(skip_serializing)]
     pub source: Option<String>,
+
+    #[serde(skip)]
+    pub compilation_id: Option<String>,
+
+    #[serde(skip)]
+    pub line_table: OnceLock<LineTable>,
 }
 
 impl SourceUnit {
-    pub fn source_line(&self, src: &str) -> io::Result<usize> {
-        let source = match self.source.as_ref() {
-            Some(source) => source.as_str(),
-            _ => return Err(io::Error::from(io::ErrorKind::NotFound))
-        };
-
-        let mut values: Vec<Option<usize>> = vec![];
-
-        for token in src.split(':') {
-            values.push(if token.is_empty() {
-                None
-            } else {
-                Some(token.parse().map_err(|error| {
-                    io::Error::new(io::ErrorKind::InvalidData, error)
-                })?)
-            });
+    pub fn is_yul(&self) -> bool {
+        let contract_definitions = self.contract_definitions();
+        !contract_definitions.is_empty() && contract_definitions.iter().all(|contract_definition| contract_definition.kind == ContractKind::YulObject)
+    }
+
+    pub fn set_source(&mut self, source: Option<String>) {
+        self.source = source;
+        self.line_table = OnceLock::new();
+    }
+
+    fn source_text(&self) -> io::Result<&str> {
+        match self.source.as_ref() {
+            Some(source) => Ok(source.as_str()),
+            _ => Err(io::Error::from(io::ErrorKind::NotFound))
         }
+    }
+
+    pub fn line_table(&self) -> io::Result<&LineTable> {
+        let source = self.source_text()?;
+
+        Ok(self.line_table.get_or_init(|| LineTable::new(source)))
+    }
+
+    pub fn source_location(&self, src: &str) -> io::Result<SourceLocation> {
+        src.parse()
+    }
+
+    pub fn source_line(&self, src: &str) -> io::Result<usize> {
+        let location = self.source_location(src)?;
+
+        self.line_table()?.line(location.start)
+    }
+
+    pub fn source_range(&self, src: &str) -> io::Result<SourceRange> {
+        self.source_range_of(&self.source_location(src)?)
+    }
+
+    pub fn source_range_of(&self, location: &SourceLocation) -> io::Result<SourceRange> {
+        self.line_table()?.range(self.source_text()?, location)
+    }
 
-        Ok(
-            source[..match values.first() {
-                Some(&Some(value)) => value,
-                _ => return Err(io::Error::from(io::ErrorKind::NotFound))
-            }]
-            .chars()
-            .filter(|&c| c == '\n')
-            .count() + 1
-        )
+    pub fn source_snippet(&self, src: &str) -> io::Result<&str> {
+        self.source_snippet_of(&self.source_location(src)?)
+    }
+
+    pub fn source_snippet_of(&self, location: &SourceLocation) -> io::Result<&str> {
+        self.source_text()?.get(location.start..location.end()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Source location {} is out of bounds", location))
+        })
     }
 
     pub fn pragma_directives(&self) -> Vec<&PragmaDirective> {
@@ -95,6 +163,11 @@ impl SourceUnit {
         result
     }
 
+    //
+    // Lookups by NodeID only scan the top-level and contract-level nodes of this source unit. Analyzers resolve
+    // references through the AstIndex of their compilation instead, which covers every source unit and nested node.
+    //
+
     pub fn contract_definition(&self, id: NodeID) -> Option<&ContractDefinition> {
         for node in self.nodes.iter() {
             if let SourceUnitNode::ContractDefinition(contract_definition) = node {
@@ -167,35 +240,43 @@ impl SourceUnit {
         None
     }
 
-    pub fn function_definition(&self, id: NodeID) -> Option<&FunctionDefinition> {
+    pub fn function_definitions(&self) -> Vec<&FunctionDefinition> {
+        let mut result = vec![];
+
         for node in self.nodes.iter() {
-            if let SourceUnitNode::ContractDefinition(contract_definition) = node {
-                for node in contract_definition.nodes.iter() {
-                    if let ContractDefinitionNode::FunctionDefinition(function_definition) = node {
-                        if function_definition.id == id {
-                            return Some(function_definition);
-                        }
-                    }
-                }
+            if let SourceUnitNode::FunctionDefinition(function_definition) = node {
+                result.push(function_definition);
             }
         }
 
-        None
+        result
+    }
+
+    pub fn function_definition(&self, id: NodeID) -> Option<&FunctionDefinition> {
+        self.function_and_contract_definition(id).map(|(_, function_definition)| function_definition)
     }
 
     pub fn function_and_contract_definition(
         &self,
         id: NodeID,
-    ) -> Option<(&ContractDefinition, &FunctionDefinition)> {
+    ) -> Option<(Option<&ContractDefinition>, &FunctionDefinition)> {
         for node in self.nodes.iter() {
-            if let SourceUnitNode::ContractDefinition(contract_definition) = node {
-                for node in contract_definition.nodes.iter() {
-                    if let ContractDefinitionNode::FunctionDefinition(function_definition) = node {
-                        if function_definition.id == id {
-                            return Some((contract_definition, function_definition));
+            match node {
+                SourceUnitNode::FunctionDefinition(function_definition) if function_definition.id == id => {
+                    return Some((None, function_definition));
+                }
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    for node in contract_definition.nodes.iter() {
+                        if let ContractDefinitionNode::FunctionDefinition(function_definition) = node {
+                            if function_definition.id == id {
+                                return Some((Some(contract_definition), function_definition));
+                            }
                         }
                     }
                 }
+
+                _ => {}
             }
         }
 
@@ -209,17 +290,7 @@ impl SourceUnit {
         for node in self.nodes.iter() {
             if let SourceUnitNode::ContractDefinition(contract_definition) = node {
                 for node in contract_definition.nodes.iter() {
-                    if id == match node {
-                        ContractDefinitionNode::UsingForDirective(node) => node.id,
-                        ContractDefinitionNode::StructDefinition(node) => node.id,
-                        ContractDefinitionNode::EnumDefinition(node) => node.id,
-                        ContractDefinitionNode::VariableDeclaration(node) => node.id,
-                        ContractDefinitionNode::EventDefinition(node) => node.id,
-                        ContractDefinitionNode::FunctionDefinition(node) => node.id,
-                        ContractDefinitionNode::ModifierDefinition(node) => node.id,
-                        ContractDefinitionNode::ErrorDefinition(node) => node.id,
-                        ContractDefinitionNode::UserDefinedValueTypeDefinition(node) => node.id,
-                    } {
+                    if node.id() == Some(id) {
                         return Some((contract_definition, node));
                     }
                 }
@@ -232,6 +303,7 @@ impl SourceUnit {
 
 pub struct SourceUnitContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
 }
 
@@ -242,6 +314,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> PragmaDirectiveContext<'a> {
         PragmaDirectiveContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             pragma_directive,
         }
@@ -253,6 +326,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> ImportDirectiveContext<'a> {
         ImportDirectiveContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             import_directive,
         }
@@ -264,6 +338,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> ContractDefinitionContext<'a> {
         ContractDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition,
         }
@@ -275,6 +350,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> StructDefinitionContext<'a> {
         StructDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: None,
             struct_definition,
@@ -287,6 +363,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> EnumDefinitionContext<'a> {
         EnumDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: None,
             enum_definition,
@@ -299,6 +376,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> ErrorDefinitionContext<'a> {
         ErrorDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: None,
             error_definition,
@@ -311,6 +389,7 @@ impl<'a> SourceUnitContext<'a> {
     ) -> VariableDeclarationContext<'a, 'b> {
         VariableDeclarationContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: None,
             definition_node: None,
@@ -325,9 +404,23 @@ impl<'a> SourceUnitContext<'a> {
     ) -> UserDefinedValueTypeDefinitionContext<'a> {
         UserDefinedValueTypeDefinitionContext {
             source_units: self.source_units,
+            ast_index: self.ast_index,
             current_source_unit: self.current_source_unit,
             contract_definition: None,
             user_defined_value_type_definition,
         }
     }
+
+    pub fn create_event_definition_context(
+        &self,
+        event_definition: &'a EventDefinition,
+    ) -> EventDefinitionContext<'a> {
+        EventDefinitionContext {
+            source_units: self.source_units,
+            ast_index: self.ast_index,
+            current_source_unit: self.current_source_unit,
+            contract_definition: None,
+            event_definition,
+        }
+    }
 }
@@ -1,30 +1,29 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Display;
 use yul::ast::*;
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Statement {
     VariableDeclarationStatement(VariableDeclarationStatement),
     IfStatement(IfStatement),
+    DoWhileStatement(DoWhileStatement),
     ForStatement(ForStatement),
     WhileStatement(WhileStatement),
     EmitStatement(EmitStatement),
     TryStatement(TryStatement),
+    Block(Block),
     UncheckedBlock(Block),
+    Break(Break),
+    Continue(Continue),
+    PlaceholderStatement(PlaceholderStatement),
     Return(Return),
     RevertStatement(RevertStatement),
     ExpressionStatement(ExpressionStatement),
     InlineAssembly(InlineAssembly),
 
-    #[serde(rename_all = "camelCase")]
-    UnhandledStatement {
-        node_type: NodeType,
-        src: Option<String>,
-        id: Option<NodeID>,
-    },
+    UnhandledStatement(UnhandledNode),
 }
 
 impl Statement {
@@ -33,27 +32,78 @@ impl Statement {
     }
 }
 
+impl<'de> Deserialize<'de> for Statement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("VariableDeclarationStatement") => Statement::VariableDeclarationStatement(Deserialize::deserialize(content)?),
+            Some("IfStatement") => Statement::IfStatement(Deserialize::deserialize(content)?),
+            Some("DoWhileStatement") => Statement::DoWhileStatement(Deserialize::deserialize(content)?),
+            Some("ForStatement") => Statement::ForStatement(Deserialize::deserialize(content)?),
+            Some("WhileStatement") => Statement::WhileStatement(Deserialize::deserialize(content)?),
+            Some("EmitStatement") => Statement::EmitStatement(Deserialize::deserialize(content)?),
+            Some("TryStatement") => Statement::TryStatement(Deserialize::deserialize(content)?),
+            Some("Block") => Statement::Block(Deserialize::deserialize(content)?),
+            Some("UncheckedBlock") => Statement::UncheckedBlock(Deserialize::deserialize(content)?),
+            Some("Break") => Statement::Break(Deserialize::deserialize(content)?),
+            Some("Continue") => Statement::Continue(Deserialize::deserialize(content)?),
+            Some("PlaceholderStatement") => Statement::PlaceholderStatement(Deserialize::deserialize(content)?),
+            Some("Return") => Statement::Return(Deserialize::deserialize(content)?),
+            Some("RevertStatement") => Statement::RevertStatement(Deserialize::deserialize(content)?),
+            Some("ExpressionStatement") => Statement::ExpressionStatement(Deserialize::deserialize(content)?),
+            Some("InlineAssembly") => Statement::InlineAssembly(Deserialize::deserialize(content)?),
+            _ => Statement::UnhandledStatement(Deserialize::deserialize(content)?),
+        }))
+    }
+}
+
+impl Serialize for Statement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Statement::VariableDeclarationStatement(node) => serialize_tagged(serializer, NodeType::VariableDeclarationStatement, node),
+            Statement::IfStatement(node) => serialize_tagged(serializer, NodeType::IfStatement, node),
+            Statement::DoWhileStatement(node) => serialize_tagged(serializer, NodeType::DoWhileStatement, node),
+            Statement::ForStatement(node) => serialize_tagged(serializer, NodeType::ForStatement, node),
+            Statement::WhileStatement(node) => serialize_tagged(serializer, NodeType::WhileStatement, node),
+            Statement::EmitStatement(node) => serialize_tagged(serializer, NodeType::EmitStatement, node),
+            Statement::TryStatement(node) => serialize_tagged(serializer, NodeType::TryStatement, node),
+            Statement::Block(node) => serialize_tagged(serializer, NodeType::Block, node),
+            Statement::UncheckedBlock(node) => serialize_tagged(serializer, NodeType::UncheckedBlock, node),
+            Statement::Break(node) => serialize_tagged(serializer, NodeType::Break, node),
+            Statement::Continue(node) => serialize_tagged(serializer, NodeType::Continue, node),
+            Statement::PlaceholderStatement(node) => serialize_tagged(serializer, NodeType::PlaceholderStatement, node),
+            Statement::Return(node) => serialize_tagged(serializer, NodeType::Return, node),
+            Statement::RevertStatement(node) => serialize_tagged(serializer, NodeType::RevertStatement, node),
+            Statement::ExpressionStatement(node) => serialize_tagged(serializer, NodeType::ExpressionStatement, node),
+            Statement::InlineAssembly(node) => serialize_tagged(serializer, NodeType::InlineAssembly, node),
+            Statement::UnhandledStatement(node) => node.serialize(serializer),
+        }
+    }
+}
+
 impl Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Statement::VariableDeclarationStatement(stmt) => stmt.fmt(f),
             Statement::IfStatement(stmt) => stmt.fmt(f),
             Statement::ForStatement(stmt) => stmt.fmt(f),
+            Statement::DoWhileStatement(stmt) => stmt.fmt(f),
             Statement::WhileStatement(stmt) => stmt.fmt(f),
             Statement::EmitStatement(stmt) => stmt.fmt(f),
             Statement::TryStatement(stmt) => stmt.fmt(f),
             Statement::RevertStatement(stmt) => stmt.fmt(f),
-            Statement::UncheckedBlock(stmt) => stmt.fmt(f),
+            Statement::Block(stmt) => stmt.fmt(f),
+            Statement::UncheckedBlock(stmt) => f.write_fmt(format_args!("unchecked {}", stmt)),
+            Statement::Break(stmt) => stmt.fmt(f),
+            Statement::Continue(stmt) => stmt.fmt(f),
+            Statement::PlaceholderStatement(stmt) => stmt.fmt(f),
             Statement::Return(stmt) => stmt.fmt(f),
             Statement::ExpressionStatement(stmt) => stmt.fmt(f),
             Statement::InlineAssembly(_) => {
                 f.write_str("assembly { /* WARNING: not implemented */ }")
             }
-            Statement::UnhandledStatement { node_type, .. } => match node_type {
-                NodeType::PlaceholderStatement => f.write_str("_"),
-                NodeType::Break => f.write_str("break"),
-                NodeType::Continue => f.write_str("continue"),
-                _ => unimplemented!("{:?}", node_type),
+            Statement::UnhandledStatement(node) => match node.node_type.as_str() {
+                "Throw" => f.write_str("throw"),
+                node_type => f.write_fmt(format_args!("/* WARNING: unhandled {} */", node_type)),
             },
         }
     }
@@ -61,9 +111,10 @@ impl Display for Statement {
 
 pub struct StatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
 }
@@ -72,6 +123,8 @@ pub struct StatementContext<'a, 'b> {
 #[serde(rename_all = "camelCase")]
 pub struct ExpressionStatement {
     pub expression: Expression,
+    pub src: String,
+    pub id: NodeID,
 }
 
 impl Display for ExpressionStatement {
@@ -124,20 +177,38 @@ impl Display for VariableDeclarationStatement {
 
 pub struct VariableDeclarationStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub variable_declaration_statement: &'a VariableDeclarationStatement,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BlockOrStatement {
     Block(Box<Block>),
     Statement(Box<Statement>),
 }
 
+impl<'de> Deserialize<'de> for BlockOrStatement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("Block") => BlockOrStatement::Block(Deserialize::deserialize(content)?),
+            _ => BlockOrStatement::Statement(Deserialize::deserialize(content)?),
+        }))
+    }
+}
+
+impl Serialize for BlockOrStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            BlockOrStatement::Block(node) => serialize_tagged(serializer, NodeType::Block, node),
+            BlockOrStatement::Statement(node) => node.serialize(serializer),
+        }
+    }
+}
+
 impl BlockOrStatement {
     pub fn contains_returns(&self) -> bool {
         match self {
@@ -186,9 +257,10 @@ impl Display for BlockOrStatement {
 
 pub struct BlockOrStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub block_or_statement: &'a BlockOrStatement,
 }
@@ -217,9 +289,10 @@ impl Display for IfStatement {
 
 pub struct IfStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub if_statement: &'a IfStatement,
 }
@@ -261,9 +334,10 @@ impl Display for ForStatement {
 
 pub struct ForStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub for_statement: &'a ForStatement,
 }
@@ -285,17 +359,45 @@ impl Display for WhileStatement {
 
 pub struct WhileStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub while_statement: &'a WhileStatement,
 }
 
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DoWhileStatement {
+    pub condition: Expression,
+    pub body: BlockOrStatement,
+    pub src: String,
+    pub id: NodeID,
+}
+
+impl Display for DoWhileStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("do {} while ({})", self.body, self.condition))
+    }
+}
+
+pub struct DoWhileStatementContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub do_while_statement: &'a DoWhileStatement,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct EmitStatement {
     pub event_call: Expression,
+    pub src: String,
+    pub id: NodeID,
 }
 
 impl Display for EmitStatement {
@@ -306,9 +408,10 @@ impl Display for EmitStatement {
 
 pub struct EmitStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub emit_statement: &'a EmitStatement,
 }
@@ -318,19 +421,41 @@ pub struct EmitStatementContext<'a, 'b> {
 pub struct TryStatement {
     pub clauses: Vec<TryCatchClause>,
     pub external_call: FunctionCall,
+    pub src: String,
+    pub id: NodeID,
 }
 
 impl Display for TryStatement {
-    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("try {}", self.external_call))?;
+
+        for (i, clause) in self.clauses.iter().enumerate() {
+            //
+            // The first clause is the success clause, which is written without `catch` and with its parameters
+            // introduced by `returns`
+            //
+
+            if i == 0 {
+                if let Some(parameters) = clause.parameters.as_ref() {
+                    f.write_fmt(format_args!(" returns {}", parameters))?;
+                }
+
+                f.write_fmt(format_args!(" {}", clause.block))?;
+            } else {
+                f.write_fmt(format_args!(" {}", clause))?;
+            }
+        }
+
+        Ok(())
     }
 }
 
 pub struct TryStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub try_statement: &'a TryStatement,
 }
@@ -339,6 +464,8 @@ pub struct TryStatementContext<'a, 'b> {
 #[serde(rename_all = "camelCase")]
 pub struct RevertStatement {
     pub error_call: FunctionCall,
+    pub src: String,
+    pub id: NodeID,
 }
 
 impl Display for RevertStatement {
@@ -349,9 +476,10 @@ impl Display for RevertStatement {
 
 pub struct RevertStatementContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub revert_statement: &'a RevertStatement,
 }
@@ -362,11 +490,23 @@ pub struct TryCatchClause {
     pub block: Block,
     pub error_name: Option<String>,
     pub parameters: Option<ParameterList>,
+    pub src: String,
+    pub id: NodeID,
 }
 
 impl Display for TryCatchClause {
-    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("catch ")?;
+
+        if let Some(error_name) = self.error_name.as_ref() {
+            f.write_str(error_name)?;
+        }
+
+        if let Some(parameters) = self.parameters.as_ref() {
+            f.write_fmt(format_args!("{} ", parameters))?;
+        }
+
+        f.write_fmt(format_args!("{}", self.block))
     }
 }
 
@@ -393,9 +533,10 @@ impl Display for Return {
 
 pub struct ReturnContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: Option<&'a Statement>,
     pub return_statement: &'a Return,
@@ -403,6 +544,75 @@ pub struct ReturnContext<'a, 'b> {
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
+pub struct Break {
+    pub src: String,
+    pub id: NodeID,
+}
+
+impl Display for Break {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("break")
+    }
+}
+
+pub struct BreakContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub break_statement: &'a Break,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Continue {
+    pub src: String,
+    pub id: NodeID,
+}
+
+impl Display for Continue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("continue")
+    }
+}
+
+pub struct ContinueContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub continue_statement: &'a Continue,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceholderStatement {
+    pub src: String,
+    pub id: NodeID,
+}
+
+impl Display for PlaceholderStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("_")
+    }
+}
+
+pub struct PlaceholderStatementContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub placeholder_statement: &'a PlaceholderStatement,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase", from = "InlineAssemblyData")]
 pub struct InlineAssembly {
     #[serde(rename = "AST")]
     pub ast: Option<YulBlock>,
@@ -413,12 +623,55 @@ pub struct InlineAssembly {
     pub id: NodeID,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InlineAssemblyData {
+    #[serde(rename = "AST")]
+    ast: Option<YulBlock>,
+    evm_version: Option<String>,
+    external_references: Vec<ExternalReference>,
+    operations: Option<String>,
+    src: String,
+    id: NodeID,
+}
+
+impl From<InlineAssemblyData> for InlineAssembly {
+    fn from(data: InlineAssemblyData) -> Self {
+        //
+        // Legacy ASTs only supply the assembly source in `operations`, so the yul AST is parsed from it when missing.
+        // The compiler reformats that source, so the parsed nodes are left without locations.
+        //
+
+        let ast = data.ast.or_else(|| yul::parser::parse_detached_block(data.operations.as_ref()?.as_str()).ok());
+
+        Self {
+            ast,
+            evm_version: data.evm_version,
+            external_references: data.external_references,
+            operations: data.operations,
+            src: data.src,
+            id: data.id,
+        }
+    }
+}
+
 pub struct InlineAssemblyContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
 }
+
+pub struct UnhandledStatementContext<'a, 'b> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub unhandled_statement: &'a UnhandledNode,
+}
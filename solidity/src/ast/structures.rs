@@ -30,6 +30,7 @@ impl Display for StructDefinition {
 
 pub struct StructDefinitionContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub contract_definition: Option<&'a ContractDefinition>,
     pub struct_definition: &'a StructDefinition,
@@ -1,6 +1,6 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Display;
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -10,8 +10,7 @@ pub struct TypeDescriptions {
     pub type_string: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TypeName {
     FunctionTypeName(FunctionTypeName),
     ArrayTypeName(ArrayTypeName),
@@ -21,6 +20,33 @@ pub enum TypeName {
     String(String),
 }
 
+impl<'de> Deserialize<'de> for TypeName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match (node_type, content) {
+            (Some("FunctionTypeName"), content) => TypeName::FunctionTypeName(Deserialize::deserialize(content)?),
+            (Some("ArrayTypeName"), content) => TypeName::ArrayTypeName(Deserialize::deserialize(content)?),
+            (Some("Mapping"), content) => TypeName::Mapping(Deserialize::deserialize(content)?),
+            (Some("UserDefinedTypeName"), content) => TypeName::UserDefinedTypeName(Deserialize::deserialize(content)?),
+            (Some("ElementaryTypeName"), content) => TypeName::ElementaryTypeName(Deserialize::deserialize(content)?),
+            (_, NodeContent::String(string)) => TypeName::String(string),
+            (node_type, _) => return Err(unexpected_node_type(node_type, "type name")),
+        }))
+    }
+}
+
+impl Serialize for TypeName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TypeName::FunctionTypeName(node) => serialize_tagged(serializer, NodeType::FunctionTypeName, node),
+            TypeName::ArrayTypeName(node) => serialize_tagged(serializer, NodeType::ArrayTypeName, node),
+            TypeName::Mapping(node) => serialize_tagged(serializer, NodeType::Mapping, node),
+            TypeName::UserDefinedTypeName(node) => serialize_tagged(serializer, NodeType::UserDefinedTypeName, node),
+            TypeName::ElementaryTypeName(node) => serialize_tagged(serializer, NodeType::ElementaryTypeName, node),
+            TypeName::String(string) => string.serialize(serializer),
+        }
+    }
+}
+
 impl Display for TypeName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -28,8 +54,8 @@ impl Display for TypeName {
             TypeName::UserDefinedTypeName(user_defined_type_name) => user_defined_type_name.fmt(f),
             TypeName::ArrayTypeName(array_type_name) => array_type_name.fmt(f),
             TypeName::Mapping(mapping) => mapping.fmt(f),
+            TypeName::FunctionTypeName(function_type_name) => function_type_name.fmt(f),
             TypeName::String(string) => string.fmt(f),
-            _ => unimplemented!(),
         }
     }
 }
@@ -98,6 +124,22 @@ pub struct FunctionTypeName {
     pub type_descriptions: TypeDescriptions,
 }
 
+impl Display for FunctionTypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("function {} {}", self.parameter_types, self.visibility))?;
+
+        if self.state_mutability != StateMutability::NonPayable {
+            f.write_fmt(format_args!(" {}", self.state_mutability))?;
+        }
+
+        if !self.return_parameter_types.parameters.is_empty() {
+            f.write_fmt(format_args!(" returns {}", self.return_parameter_types))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ArrayTypeName {
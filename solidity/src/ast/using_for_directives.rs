@@ -3,32 +3,90 @@ use eth_lang_utils::ast::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum UsingForFunction {
+    Operator {
+        definition: IdentifierPath,
+        operator: String,
+    },
+
+    Function {
+        function: IdentifierPath,
+    },
+}
+
+impl UsingForFunction {
+    pub fn identifier_path(&self) -> &IdentifierPath {
+        match self {
+            UsingForFunction::Operator { definition, .. } => definition,
+            UsingForFunction::Function { function } => function,
+        }
+    }
+}
+
+impl Display for UsingForFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsingForFunction::Operator { definition, operator } => f.write_fmt(format_args!("{} as {}", definition, operator)),
+            UsingForFunction::Function { function } => function.fmt(f),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct UsingForDirective {
-    pub library_name: IdentifierPath,
+    pub library_name: Option<IdentifierPath>,
+    #[serde(default)]
+    pub function_list: Vec<UsingForFunction>,
     pub type_name: Option<TypeName>,
+    #[serde(default)]
+    pub global: bool,
     pub src: String,
     pub id: NodeID,
 }
 
 impl Display for UsingForDirective {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!(
-            "using {} for {}",
-            self.library_name,
-            match self.type_name.as_ref() {
-                Some(type_name) => format!("{}", type_name),
-                None => "_".to_string(),
+        f.write_str("using ")?;
+
+        match self.library_name.as_ref() {
+            Some(library_name) => library_name.fmt(f)?,
+
+            None => {
+                f.write_str("{")?;
+
+                for (i, function) in self.function_list.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    function.fmt(f)?;
+                }
+
+                f.write_str("}")?;
             }
-        ))
+        }
+
+        match self.type_name.as_ref() {
+            Some(type_name) => f.write_fmt(format_args!(" for {}", type_name))?,
+            None => f.write_str(" for *")?,
+        }
+
+        if self.global {
+            f.write_str(" global")?;
+        }
+
+        Ok(())
     }
 }
 
 pub struct UsingForDirectiveContext<'a> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub using_for_directive: &'a UsingForDirective,
 }
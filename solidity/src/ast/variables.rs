@@ -121,9 +121,10 @@ impl Display for VariableDeclaration {
 
 pub struct VariableDeclarationContext<'a, 'b> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
     pub contract_definition: Option<&'a ContractDefinition>,
-    pub definition_node: Option<&'a ContractDefinitionNode>,
+    pub definition_node: Option<DefinitionNode<'a>>,
     pub blocks: Option<&'b mut Vec<&'a Block>>,
     pub variable_declaration: &'a VariableDeclaration,
 }
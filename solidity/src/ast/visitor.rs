@@ -1,13 +1,14 @@
 use super::*;
 use eth_lang_utils::ast::*;
-use std::{collections::HashSet, io};
+use std::{collections::{BTreeMap, HashSet}, io};
 use yul::ast::*;
 
 pub struct YulBlockContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -17,9 +18,10 @@ pub struct YulBlockContext<'a, 'b, 'c> {
 
 pub struct YulStatementContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -29,9 +31,10 @@ pub struct YulStatementContext<'a, 'b, 'c> {
 
 pub struct YulIfContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -42,9 +45,10 @@ pub struct YulIfContext<'a, 'b, 'c> {
 
 pub struct YulSwitchContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -55,9 +59,10 @@ pub struct YulSwitchContext<'a, 'b, 'c> {
 
 pub struct YulCaseContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -69,9 +74,10 @@ pub struct YulCaseContext<'a, 'b, 'c> {
 
 pub struct YulAssignmentContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -82,9 +88,10 @@ pub struct YulAssignmentContext<'a, 'b, 'c> {
 
 pub struct YulVariableDeclarationContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -95,9 +102,10 @@ pub struct YulVariableDeclarationContext<'a, 'b, 'c> {
 
 pub struct YulExpressionStatementContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -106,11 +114,82 @@ pub struct YulExpressionStatementContext<'a, 'b, 'c> {
     pub yul_expression_statement: &'a YulExpressionStatement,
 }
 
+pub struct YulForLoopContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: &'a YulStatement,
+    pub yul_for_loop: &'a YulForLoop,
+}
+
+pub struct YulFunctionDefinitionContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: &'a YulStatement,
+    pub yul_function_definition: &'a YulFunctionDefinition,
+}
+
+pub struct YulBreakContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: &'a YulStatement,
+    pub yul_break: &'a YulBreak,
+}
+
+pub struct YulContinueContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: &'a YulStatement,
+    pub yul_continue: &'a YulContinue,
+}
+
+pub struct YulLeaveContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: &'a YulStatement,
+    pub yul_leave: &'a YulLeave,
+}
+
 pub struct YulExpressionContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -121,9 +200,10 @@ pub struct YulExpressionContext<'a, 'b, 'c> {
 
 pub struct YulLiteralContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -135,9 +215,10 @@ pub struct YulLiteralContext<'a, 'b, 'c> {
 
 pub struct YulIdentifierContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -149,9 +230,10 @@ pub struct YulIdentifierContext<'a, 'b, 'c> {
 
 pub struct YulFunctionCallContext<'a, 'b, 'c> {
     pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
     pub current_source_unit: &'a SourceUnit,
-    pub contract_definition: &'a ContractDefinition,
-    pub definition_node: &'a ContractDefinitionNode,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
     pub blocks: &'b mut Vec<&'a Block>,
     pub statement: &'a Statement,
     pub inline_assembly: &'a InlineAssembly,
@@ -161,6 +243,33 @@ pub struct YulFunctionCallContext<'a, 'b, 'c> {
     pub yul_function_call: &'a YulFunctionCall,
 }
 
+pub struct UnhandledYulStatementContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub unhandled_yul_statement: &'a UnhandledNode,
+}
+
+pub struct UnhandledYulExpressionContext<'a, 'b, 'c> {
+    pub source_units: &'a [SourceUnit],
+    pub ast_index: &'a AstIndex<'a>,
+    pub current_source_unit: &'a SourceUnit,
+    pub contract_definition: Option<&'a ContractDefinition>,
+    pub definition_node: DefinitionNode<'a>,
+    pub blocks: &'b mut Vec<&'a Block>,
+    pub statement: &'a Statement,
+    pub inline_assembly: &'a InlineAssembly,
+    pub yul_blocks: &'c mut Vec<&'a YulBlock>,
+    pub yul_statement: Option<&'a YulStatement>,
+    pub unhandled_yul_expression: &'a UnhandledNode,
+}
+
 #[allow(unused_variables)]
 pub trait AstVisitor {
     fn visit_source_unit<'a>(&mut self, context: &mut SourceUnitContext<'a>) -> io::Result<()> { Ok(()) }
@@ -223,6 +332,9 @@ pub trait AstVisitor {
     fn visit_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
     fn leave_while_statement<'a, 'b>(&mut self, context: &mut WhileStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
 
+    fn visit_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn leave_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+
     fn visit_emit_statement<'a, 'b>(&mut self, context: &mut EmitStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
     fn leave_emit_statement<'a, 'b>(&mut self, context: &mut EmitStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
 
@@ -235,6 +347,15 @@ pub trait AstVisitor {
     fn visit_block_or_statement<'a, 'b>(&mut self, context: &mut BlockOrStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
     fn leave_block_or_statement<'a, 'b>(&mut self, context: &mut BlockOrStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
 
+    fn visit_break<'a, 'b>(&mut self, context: &mut BreakContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn leave_break<'a, 'b>(&mut self, context: &mut BreakContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+
+    fn visit_continue<'a, 'b>(&mut self, context: &mut ContinueContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn leave_continue<'a, 'b>(&mut self, context: &mut ContinueContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+
+    fn visit_placeholder_statement<'a, 'b>(&mut self, context: &mut PlaceholderStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn leave_placeholder_statement<'a, 'b>(&mut self, context: &mut PlaceholderStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+
     fn visit_return<'a, 'b>(&mut self, context: &mut ReturnContext<'a, 'b>) -> io::Result<()> { Ok(()) }
     fn leave_return<'a, 'b>(&mut self, context: &mut ReturnContext<'a, 'b>) -> io::Result<()> { Ok(()) }
 
@@ -310,6 +431,21 @@ pub trait AstVisitor {
     fn visit_yul_expression_statement<'a, 'b, 'c>(&mut self, context: &mut YulExpressionStatementContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
     fn leave_yul_expression_statement<'a, 'b, 'c>(&mut self, context: &mut YulExpressionStatementContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
 
+    fn visit_yul_for_loop<'a, 'b, 'c>(&mut self, context: &mut YulForLoopContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn leave_yul_for_loop<'a, 'b, 'c>(&mut self, context: &mut YulForLoopContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+
+    fn visit_yul_function_definition<'a, 'b, 'c>(&mut self, context: &mut YulFunctionDefinitionContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn leave_yul_function_definition<'a, 'b, 'c>(&mut self, context: &mut YulFunctionDefinitionContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+
+    fn visit_yul_break<'a, 'b, 'c>(&mut self, context: &mut YulBreakContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn leave_yul_break<'a, 'b, 'c>(&mut self, context: &mut YulBreakContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+
+    fn visit_yul_continue<'a, 'b, 'c>(&mut self, context: &mut YulContinueContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn leave_yul_continue<'a, 'b, 'c>(&mut self, context: &mut YulContinueContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+
+    fn visit_yul_leave<'a, 'b, 'c>(&mut self, context: &mut YulLeaveContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn leave_yul_leave<'a, 'b, 'c>(&mut self, context: &mut YulLeaveContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+
     fn visit_yul_expression<'a, 'b, 'c>(&mut self, context: &mut YulExpressionContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
     fn leave_yul_expression<'a, 'b, 'c>(&mut self, context: &mut YulExpressionContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
 
@@ -322,32 +458,24 @@ pub trait AstVisitor {
     fn visit_yul_function_call<'a, 'b, 'c>(&mut self, context: &mut YulFunctionCallContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
     fn leave_yul_function_call<'a, 'b, 'c>(&mut self, context: &mut YulFunctionCallContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
 
-    fn visit_unhandled_statement(
-        &mut self,
-        source_unit: &SourceUnit,
-        node_type: &NodeType,
-        src: &Option<String>,
-        id: &Option<NodeID>,
-    ) -> io::Result<()> {
-        Ok(())
-    }
-
-    fn visit_unhandled_expression(
-        &mut self,
-        source_unit: &SourceUnit,
-        node_type: &NodeType,
-        src: &Option<String>,
-        id: &Option<NodeID>,
-    ) -> io::Result<()> {
-        Ok(())
-    }
+    fn visit_unhandled_statement<'a, 'b>(&mut self, context: &mut UnhandledStatementContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn visit_unhandled_expression<'a, 'b>(&mut self, context: &mut UnhandledExpressionContext<'a, 'b>) -> io::Result<()> { Ok(()) }
+    fn visit_unhandled_yul_statement<'a, 'b, 'c>(&mut self, context: &mut UnhandledYulStatementContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
+    fn visit_unhandled_yul_expression<'a, 'b, 'c>(&mut self, context: &mut UnhandledYulExpressionContext<'a, 'b, 'c>) -> io::Result<()> { Ok(()) }
 }
 
 pub struct AstVisitorData<'a> {
     pub analyzed_paths: HashSet<String>,
+    pub unhandled_node_types: BTreeMap<String, usize>,
     pub visitors: Vec<Box<dyn AstVisitor + 'a>>,
 }
 
+impl AstVisitorData<'_> {
+    fn record_unhandled_node(&mut self, node: &UnhandledNode) {
+        *self.unhandled_node_types.entry(node.node_type.clone()).or_insert(0) += 1;
+    }
+}
+
 impl AstVisitor for AstVisitorData<'_> {
     fn visit_source_unit<'a>(&mut self, context: &mut SourceUnitContext<'a>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
@@ -403,6 +531,40 @@ impl AstVisitor for AstVisitorData<'_> {
                     self.visit_user_defined_value_type_definition(&mut context)?;
                     self.leave_user_defined_value_type_definition(&mut context)?;
                 }
+
+                SourceUnitNode::EventDefinition(event_definition) => {
+                    let mut context = context.create_event_definition_context(event_definition);
+                    self.visit_event_definition(&mut context)?;
+                    self.leave_event_definition(&mut context)?;
+                }
+
+                SourceUnitNode::FunctionDefinition(function_definition) => {
+                    let mut context = FunctionDefinitionContext {
+                        source_units: context.source_units,
+                        ast_index: context.ast_index,
+                        current_source_unit: context.current_source_unit,
+                        contract_definition: None,
+                        definition_node: DefinitionNode::FunctionDefinition(function_definition),
+                        function_definition,
+                    };
+
+                    self.visit_function_definition(&mut context)?;
+                    self.leave_function_definition(&mut context)?;
+                }
+
+                SourceUnitNode::UsingForDirective(using_for_directive) => {
+                    let mut context = UsingForDirectiveContext {
+                        source_units: context.source_units,
+                        ast_index: context.ast_index,
+                        current_source_unit: context.current_source_unit,
+                        contract_definition: None,
+                        definition_node: DefinitionNode::UsingForDirective(using_for_directive),
+                        using_for_directive,
+                    };
+
+                    self.visit_using_for_directive(&mut context)?;
+                    self.leave_using_for_directive(&mut context)?;
+                }
             }
         }
 
@@ -489,7 +651,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for definition_node in context.contract_definition.nodes.iter() {
             match definition_node {
                 ContractDefinitionNode::UsingForDirective(using_for_directive) => {
-                    let mut context = context.create_using_for_directive_context(definition_node, using_for_directive);
+                    let mut context = context.create_using_for_directive_context(definition_node.into(), using_for_directive);
                     self.visit_using_for_directive(&mut context)?;
                     self.leave_using_for_directive(&mut context)?;
                 }
@@ -510,7 +672,7 @@ impl AstVisitor for AstVisitorData<'_> {
                     let mut blocks = vec![];
 
                     let mut context = context.create_variable_declaration_context(
-                        definition_node,
+                        definition_node.into(),
                         &mut blocks,
                         variable_declaration
                     );
@@ -532,13 +694,13 @@ impl AstVisitor for AstVisitorData<'_> {
                 }
 
                 ContractDefinitionNode::FunctionDefinition(function_definition) => {
-                    let mut context = context.create_function_definition_context(definition_node, function_definition);
+                    let mut context = context.create_function_definition_context(definition_node.into(), function_definition);
                     self.visit_function_definition(&mut context)?;
                     self.leave_function_definition(&mut context)?;
                 }
 
                 ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
-                    let mut context = context.create_modifier_definition_context(definition_node, modifier_definition);
+                    let mut context = context.create_modifier_definition_context(definition_node.into(), modifier_definition);
                     self.visit_modifier_definition(&mut context)?;
                     self.leave_modifier_definition(&mut context)?;
                 }
@@ -656,8 +818,9 @@ impl AstVisitor for AstVisitorData<'_> {
         for variable_declaration in context.function_definition.parameters.parameters.iter() {
             let mut context = VariableDeclarationContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
-                contract_definition: Some(context.contract_definition),
+                contract_definition: context.contract_definition,
                 definition_node: Some(context.definition_node),
                 blocks: None,
                 variable_declaration
@@ -670,6 +833,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for modifier_invocation in context.function_definition.modifiers.iter() {
             let mut modifier_context = ModifierInvocationContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -685,6 +849,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
             let mut context = BlockContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -732,6 +897,7 @@ impl AstVisitor for AstVisitorData<'_> {
             for expression in arguments.iter() {
                 let mut context = ExpressionContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -766,6 +932,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for statement in context.block.statements.iter() {
             let mut context = StatementContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -799,6 +966,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::VariableDeclarationStatement(variable_declaration_statement) => {
                 let mut context = VariableDeclarationStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -813,6 +981,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::IfStatement(if_statement) => {
                 let mut context = IfStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -827,6 +996,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::ForStatement(for_statement) => {
                 let mut context = ForStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -838,9 +1008,25 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_for_statement(&mut context)?;
             }
 
+            Statement::DoWhileStatement(do_while_statement) => {
+                let mut context = DoWhileStatementContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    do_while_statement
+                };
+
+                self.visit_do_while_statement(&mut context)?;
+                self.leave_do_while_statement(&mut context)?;
+            }
+
             Statement::WhileStatement(while_statement) => {
                 let mut context = WhileStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -855,6 +1041,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::EmitStatement(emit_statement) => {
                 let mut context = EmitStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -869,6 +1056,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::TryStatement(try_statement) => {
                 let mut context = TryStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -883,6 +1071,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::RevertStatement(revert_statement) => {
                 let mut context = RevertStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -894,9 +1083,10 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_revert_statement(&mut context)?;
             }
 
-            Statement::UncheckedBlock(block) => {
+            Statement::Block(block) | Statement::UncheckedBlock(block) => {
                 let mut context = BlockContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -908,9 +1098,55 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_block(&mut context)?;
             }
 
+            Statement::Break(break_statement) => {
+                let mut context = BreakContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    break_statement
+                };
+
+                self.visit_break(&mut context)?;
+                self.leave_break(&mut context)?;
+            }
+
+            Statement::Continue(continue_statement) => {
+                let mut context = ContinueContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    continue_statement
+                };
+
+                self.visit_continue(&mut context)?;
+                self.leave_continue(&mut context)?;
+            }
+
+            Statement::PlaceholderStatement(placeholder_statement) => {
+                let mut context = PlaceholderStatementContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    placeholder_statement
+                };
+
+                self.visit_placeholder_statement(&mut context)?;
+                self.leave_placeholder_statement(&mut context)?;
+            }
+
             Statement::Return(return_statement) => {
                 let mut context = ReturnContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -926,6 +1162,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::ExpressionStatement(expression_statement) => {
                 let mut context = ExpressionContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -941,6 +1178,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Statement::InlineAssembly(inline_assembly) => {
                 let mut context = InlineAssemblyContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -953,8 +1191,18 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_inline_assembly(&mut context)?;
             }
 
-            Statement::UnhandledStatement { node_type, src, id } => {
-                self.visit_unhandled_statement(context.current_source_unit, node_type, src, id)?;
+            Statement::UnhandledStatement(unhandled_statement) => {
+                let mut context = UnhandledStatementContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    unhandled_statement,
+                };
+
+                self.visit_unhandled_statement(&mut context)?;
             }
         }
 
@@ -977,8 +1225,9 @@ impl AstVisitor for AstVisitorData<'_> {
         for variable_declaration in context.variable_declaration_statement.declarations.iter().flatten() {
             let mut context = VariableDeclarationContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
-                contract_definition: Some(context.contract_definition),
+                contract_definition: context.contract_definition,
                 definition_node: Some(context.definition_node),
                 blocks: Some(context.blocks),
                 variable_declaration
@@ -991,6 +1240,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(initial_value) = context.variable_declaration_statement.initial_value.as_ref() {
             let mut context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1021,6 +1271,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut true_body_context = BlockOrStatementContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1034,6 +1285,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(false_body) = context.if_statement.false_body.as_ref() {
             let mut false_body_context = BlockOrStatementContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1064,6 +1316,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(statement) = context.for_statement.initialization_expression.as_ref() {
             let mut context = StatementContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1078,6 +1331,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(expression) = context.for_statement.condition.as_ref() {
             let mut context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1093,6 +1347,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(statement) = context.for_statement.loop_expression.as_ref() {
             let mut context = StatementContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1106,6 +1361,7 @@ impl AstVisitor for AstVisitorData<'_> {
         
         let mut context = BlockOrStatementContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1134,6 +1390,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut condition_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1147,6 +1404,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut context = BlockOrStatementContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1168,6 +1426,49 @@ impl AstVisitor for AstVisitorData<'_> {
         Ok(())
     }
 
+    fn visit_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_do_while_statement(context)?;
+        }
+
+        let mut body_context = BlockOrStatementContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            block_or_statement: &context.do_while_statement.body,
+        };
+
+        self.visit_block_or_statement(&mut body_context)?;
+        self.leave_block_or_statement(&mut body_context)?;
+
+        let mut context = ExpressionContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: None,
+            expression: &context.do_while_statement.condition,
+        };
+
+        self.visit_expression(&mut context)?;
+        self.leave_expression(&mut context)?;
+
+        Ok(())
+    }
+
+    fn leave_do_while_statement<'a, 'b>(&mut self, context: &mut DoWhileStatementContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_do_while_statement(context)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_emit_statement<'a, 'b>(&mut self, context: &mut EmitStatementContext<'a, 'b>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
             visitor.visit_emit_statement(context)?;
@@ -1189,9 +1490,24 @@ impl AstVisitor for AstVisitorData<'_> {
             visitor.visit_try_statement(context)?;
         }
 
+        let mut external_call_context = FunctionCallContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: None,
+            function_call: &context.try_statement.external_call,
+        };
+
+        self.visit_function_call(&mut external_call_context)?;
+        self.leave_function_call(&mut external_call_context)?;
+
         for clause in context.try_statement.clauses.iter() {
             let mut context = BlockContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1208,7 +1524,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
     fn leave_try_statement<'a, 'b>(&mut self, context: &mut TryStatementContext<'a, 'b>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
-            visitor.visit_try_statement(context)?;
+            visitor.leave_try_statement(context)?;
         }
 
         Ok(())
@@ -1239,6 +1555,7 @@ impl AstVisitor for AstVisitorData<'_> {
             BlockOrStatement::Block(block) => {
                 let mut context = BlockContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1253,6 +1570,7 @@ impl AstVisitor for AstVisitorData<'_> {
             BlockOrStatement::Statement(statement) => {
                 let mut context = StatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1276,6 +1594,54 @@ impl AstVisitor for AstVisitorData<'_> {
         Ok(())
     }
 
+    fn visit_break<'a, 'b>(&mut self, context: &mut BreakContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_break(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_break<'a, 'b>(&mut self, context: &mut BreakContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_break(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_continue<'a, 'b>(&mut self, context: &mut ContinueContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_continue(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_continue<'a, 'b>(&mut self, context: &mut ContinueContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_continue(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_placeholder_statement<'a, 'b>(&mut self, context: &mut PlaceholderStatementContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_placeholder_statement(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_placeholder_statement<'a, 'b>(&mut self, context: &mut PlaceholderStatementContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_placeholder_statement(context)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_return<'a, 'b>(&mut self, context: &mut ReturnContext<'a, 'b>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
             visitor.visit_return(context)?;
@@ -1284,6 +1650,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(expression) = context.return_statement.expression.as_ref() {
             let mut condition_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1308,6 +1675,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::Literal(literal) => {
                 let mut context = LiteralContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1323,6 +1691,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::Identifier(identifier) => {
                 let mut context = IdentifierContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1338,6 +1707,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::UnaryOperation(unary_operation) => {
                 let mut context = UnaryOperationContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1353,6 +1723,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::BinaryOperation(binary_operation) => {
                 let mut context = BinaryOperationContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1368,6 +1739,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::Conditional(conditional) => {
                 let mut context = ConditionalContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1383,6 +1755,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::Assignment(assignment) => {
                 let mut context = AssignmentContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1398,6 +1771,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::FunctionCall(function_call) => {
                 let mut context = FunctionCallContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1413,6 +1787,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::FunctionCallOptions(function_call_options) => {
                 let mut context = FunctionCallOptionsContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1428,6 +1803,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::IndexAccess(index_access) => {
                 let mut context = IndexAccessContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1443,6 +1819,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::IndexRangeAccess(index_range_access) => {
                 let mut context = IndexRangeAccessContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1458,6 +1835,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::MemberAccess(member_access) => {
                 let mut context = MemberAccessContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1473,6 +1851,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::ElementaryTypeNameExpression(elementary_type_name_expression) => {
                 let mut context = ElementaryTypeNameExpressionContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1488,6 +1867,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::TupleExpression(tuple_expression) => {
                 let mut context = TupleExpressionContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1503,6 +1883,7 @@ impl AstVisitor for AstVisitorData<'_> {
             Expression::NewExpression(new_expression) => {
                 let mut context = NewExpressionContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -1515,8 +1896,19 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_new_expression(&mut context)?;
             }
 
-            Expression::UnhandledExpression { node_type, src, id } => {
-                self.visit_unhandled_expression(context.current_source_unit, node_type, src, id)?;
+            Expression::UnhandledExpression(unhandled_expression) => {
+                let mut context = UnhandledExpressionContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    unhandled_expression,
+                };
+
+                self.visit_unhandled_expression(&mut context)?;
             }
         }
 
@@ -1562,6 +1954,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut sub_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1591,6 +1984,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut left_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1604,6 +1998,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut right_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1633,6 +2028,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut condition_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1646,6 +2042,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut true_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1659,6 +2056,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut false_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1688,6 +2086,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut left_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1701,6 +2100,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut right_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1730,6 +2130,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut expression_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1744,6 +2145,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for argument in context.function_call.arguments.iter() {
             let mut argument_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1774,6 +2176,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut expression_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1788,6 +2191,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for option in context.function_call_options.options.iter() {
             let mut option_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1818,6 +2222,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut base_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1831,6 +2236,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut index_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1860,6 +2266,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut base_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1874,6 +2281,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(start_expression) = context.index_range_access.start_expression.as_ref() {
             let mut start_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1889,6 +2297,7 @@ impl AstVisitor for AstVisitorData<'_> {
         if let Some(end_expression) = context.index_range_access.end_expression.as_ref() {
             let mut end_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -1919,6 +2328,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut expression_context = ExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -1965,6 +2375,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for component in context.tuple_expression.components.iter().flatten() {
             let mut component_context = ExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -2004,52 +2415,44 @@ impl AstVisitor for AstVisitorData<'_> {
         Ok(())
     }
 
-    fn visit_unhandled_statement(
-        &mut self,
-        source_unit: &SourceUnit,
-        node_type: &NodeType,
-        src: &Option<String>,
-        id: &Option<NodeID>,
-    ) -> io::Result<()> {
+    fn visit_unhandled_statement<'a, 'b>(&mut self, context: &mut UnhandledStatementContext<'a, 'b>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
-            visitor.visit_unhandled_statement(source_unit, node_type, src, id)?;
+            visitor.visit_unhandled_statement(context)?;
         }
 
-        match node_type {
-            NodeType::Break | NodeType::Continue | NodeType::PlaceholderStatement => Ok(()),
+        self.record_unhandled_node(context.unhandled_statement);
 
-            _ => {
-                println!(
-                    "WARNING: Unhandled statement: {:?} {:?} {:?}",
-                    node_type, src, id
-                );
-                Ok(())
-            }
+        Ok(())
+    }
+
+    fn visit_unhandled_expression<'a, 'b>(&mut self, context: &mut UnhandledExpressionContext<'a, 'b>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_unhandled_expression(context)?;
         }
+
+        self.record_unhandled_node(context.unhandled_expression);
+
+        Ok(())
     }
 
-    fn visit_unhandled_expression(
-        &mut self,
-        source_unit: &SourceUnit,
-        node_type: &NodeType,
-        src: &Option<String>,
-        id: &Option<NodeID>,
-    ) -> io::Result<()> {
+    fn visit_unhandled_yul_statement<'a, 'b, 'c>(&mut self, context: &mut UnhandledYulStatementContext<'a, 'b, 'c>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
-            visitor.visit_unhandled_expression(source_unit, node_type, src, id)?;
+            visitor.visit_unhandled_yul_statement(context)?;
         }
 
-        match node_type {
-            NodeType::PlaceholderStatement => Ok(()),
+        self.record_unhandled_node(context.unhandled_yul_statement);
 
-            _ => {
-                println!(
-                    "WARNING: Unhandled expression: {:?} {:?} {:?}",
-                    node_type, src, id
-                );
-                Ok(())
-            }
+        Ok(())
+    }
+
+    fn visit_unhandled_yul_expression<'a, 'b, 'c>(&mut self, context: &mut UnhandledYulExpressionContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_unhandled_yul_expression(context)?;
         }
+
+        self.record_unhandled_node(context.unhandled_yul_expression);
+
+        Ok(())
     }
 
     fn visit_inline_assembly<'a, 'b>(&mut self, context: &mut InlineAssemblyContext<'a, 'b>) -> io::Result<()> {
@@ -2062,6 +2465,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
             let mut context = YulBlockContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -2097,6 +2501,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for yul_statement in context.yul_block.statements.iter() {
             let mut context = YulStatementContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -2130,9 +2535,29 @@ impl AstVisitor for AstVisitorData<'_> {
         }
 
         match context.yul_statement {
+            YulStatement::YulForLoop(yul_for_loop) => {
+                let mut context = YulForLoopContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    yul_for_loop,
+                };
+
+                self.visit_yul_for_loop(&mut context)?;
+                self.leave_yul_for_loop(&mut context)?;
+            }
+
             YulStatement::YulIf(yul_if) => {
                 let mut context = YulIfContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2151,6 +2576,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulStatement::YulSwitch(yul_switch) => {
                 let mut context = YulSwitchContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2169,6 +2595,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulStatement::YulAssignment(yul_assignment) => {
                 let mut context = YulAssignmentContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2187,6 +2614,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulStatement::YulVariableDeclaration(yul_variable_declaration) => {
                 let mut context = YulVariableDeclarationContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2205,6 +2633,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulStatement::YulExpressionStatement(yul_expression_statement) => {
                 let mut context = YulExpressionStatementContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2220,11 +2649,115 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_yul_expression_statement(&mut context)?;
             }
 
-            YulStatement::UnhandledYulStatement { node_type, src, id } => {
-                println!(
-                    "WARNING: Unhandled yul statement: {:?} {:?} {:?}",
-                    node_type, src, id
-                );
+            YulStatement::YulFunctionDefinition(yul_function_definition) => {
+                let mut context = YulFunctionDefinitionContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    yul_function_definition,
+                };
+
+                self.visit_yul_function_definition(&mut context)?;
+                self.leave_yul_function_definition(&mut context)?;
+            }
+
+            YulStatement::YulBlock(yul_block) => {
+                let mut context = YulBlockContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_block,
+                };
+
+                self.visit_yul_block(&mut context)?;
+                self.leave_yul_block(&mut context)?;
+            }
+
+            YulStatement::YulBreak(yul_break) => {
+                let mut context = YulBreakContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    yul_break,
+                };
+
+                self.visit_yul_break(&mut context)?;
+                self.leave_yul_break(&mut context)?;
+            }
+
+            YulStatement::YulContinue(yul_continue) => {
+                let mut context = YulContinueContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    yul_continue,
+                };
+
+                self.visit_yul_continue(&mut context)?;
+                self.leave_yul_continue(&mut context)?;
+            }
+
+            YulStatement::YulLeave(yul_leave) => {
+                let mut context = YulLeaveContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    yul_leave,
+                };
+
+                self.visit_yul_leave(&mut context)?;
+                self.leave_yul_leave(&mut context)?;
+            }
+
+            YulStatement::UnhandledYulStatement(unhandled_yul_statement) => {
+                let mut context = UnhandledYulStatementContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    unhandled_yul_statement,
+                };
+
+                self.visit_unhandled_yul_statement(&mut context)?;
             }
         }
 
@@ -2246,6 +2779,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut condition_context = YulExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2262,6 +2796,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut body_context = YulBlockContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2293,6 +2828,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut expression_context = YulExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2310,6 +2846,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for yul_case in context.yul_switch.cases.iter() {
             let mut case_context = YulCaseContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -2342,24 +2879,28 @@ impl AstVisitor for AstVisitorData<'_> {
             visitor.visit_yul_case(context)?;
         }
 
-        let mut value_context = YulExpressionContext {
-            source_units: context.source_units,
-            current_source_unit: context.current_source_unit,
-            contract_definition: context.contract_definition,
-            definition_node: context.definition_node,
-            blocks: context.blocks,
-            statement: context.statement,
-            inline_assembly: context.inline_assembly,
-            yul_blocks: context.yul_blocks,
-            yul_statement: Some(context.yul_statement),
-            yul_expression: &context.yul_case.value,
-        };
+        if let Some(value) = context.yul_case.value.as_ref() {
+            let mut value_context = YulExpressionContext {
+                source_units: context.source_units,
+                ast_index: context.ast_index,
+                current_source_unit: context.current_source_unit,
+                contract_definition: context.contract_definition,
+                definition_node: context.definition_node,
+                blocks: context.blocks,
+                statement: context.statement,
+                inline_assembly: context.inline_assembly,
+                yul_blocks: context.yul_blocks,
+                yul_statement: Some(context.yul_statement),
+                yul_expression: value,
+            };
 
-        self.visit_yul_expression(&mut value_context)?;
-        self.leave_yul_expression(&mut value_context)?;
+            self.visit_yul_expression(&mut value_context)?;
+            self.leave_yul_expression(&mut value_context)?;
+        }
 
         let mut body_context = YulBlockContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2392,6 +2933,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for yul_identifier in context.yul_assignment.variable_names.iter() {
             let mut context = YulIdentifierContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -2410,6 +2952,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut value_context = YulExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2440,21 +2983,24 @@ impl AstVisitor for AstVisitorData<'_> {
             visitor.visit_yul_variable_declaration(context)?;
         }
 
-        let mut value_context = YulExpressionContext {
-            source_units: context.source_units,
-            current_source_unit: context.current_source_unit,
-            contract_definition: context.contract_definition,
-            definition_node: context.definition_node,
-            blocks: context.blocks,
-            statement: context.statement,
-            inline_assembly: context.inline_assembly,
-            yul_blocks: context.yul_blocks,
-            yul_statement: Some(context.yul_statement),
-            yul_expression: &context.yul_variable_declaration.value,
-        };
+        if let Some(value) = context.yul_variable_declaration.value.as_ref() {
+            let mut value_context = YulExpressionContext {
+                source_units: context.source_units,
+                ast_index: context.ast_index,
+                current_source_unit: context.current_source_unit,
+                contract_definition: context.contract_definition,
+                definition_node: context.definition_node,
+                blocks: context.blocks,
+                statement: context.statement,
+                inline_assembly: context.inline_assembly,
+                yul_blocks: context.yul_blocks,
+                yul_statement: Some(context.yul_statement),
+                yul_expression: value,
+            };
 
-        self.visit_yul_expression(&mut value_context)?;
-        self.leave_yul_expression(&mut value_context)?;
+            self.visit_yul_expression(&mut value_context)?;
+            self.leave_yul_expression(&mut value_context)?;
+        }
 
         Ok(())
     }
@@ -2474,6 +3020,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut expression_context = YulExpressionContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2499,6 +3046,167 @@ impl AstVisitor for AstVisitorData<'_> {
         Ok(())
     }
 
+    fn visit_yul_for_loop<'a, 'b, 'c>(&mut self, context: &mut YulForLoopContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_yul_for_loop(context)?;
+        }
+
+        let mut pre_context = YulBlockContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: context.statement,
+            inline_assembly: context.inline_assembly,
+            yul_blocks: context.yul_blocks,
+            yul_block: &context.yul_for_loop.pre,
+        };
+
+        self.visit_yul_block(&mut pre_context)?;
+        self.leave_yul_block(&mut pre_context)?;
+
+        let mut condition_context = YulExpressionContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: context.statement,
+            inline_assembly: context.inline_assembly,
+            yul_blocks: context.yul_blocks,
+            yul_statement: Some(context.yul_statement),
+            yul_expression: &context.yul_for_loop.condition,
+        };
+
+        self.visit_yul_expression(&mut condition_context)?;
+        self.leave_yul_expression(&mut condition_context)?;
+
+        let mut body_context = YulBlockContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: context.statement,
+            inline_assembly: context.inline_assembly,
+            yul_blocks: context.yul_blocks,
+            yul_block: &context.yul_for_loop.body,
+        };
+
+        self.visit_yul_block(&mut body_context)?;
+        self.leave_yul_block(&mut body_context)?;
+
+        let mut post_context = YulBlockContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: context.statement,
+            inline_assembly: context.inline_assembly,
+            yul_blocks: context.yul_blocks,
+            yul_block: &context.yul_for_loop.post,
+        };
+
+        self.visit_yul_block(&mut post_context)?;
+        self.leave_yul_block(&mut post_context)?;
+
+        Ok(())
+    }
+
+    fn leave_yul_for_loop<'a, 'b, 'c>(&mut self, context: &mut YulForLoopContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_yul_for_loop(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_yul_function_definition<'a, 'b, 'c>(&mut self, context: &mut YulFunctionDefinitionContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_yul_function_definition(context)?;
+        }
+
+        let mut body_context = YulBlockContext {
+            source_units: context.source_units,
+            ast_index: context.ast_index,
+            current_source_unit: context.current_source_unit,
+            contract_definition: context.contract_definition,
+            definition_node: context.definition_node,
+            blocks: context.blocks,
+            statement: context.statement,
+            inline_assembly: context.inline_assembly,
+            yul_blocks: context.yul_blocks,
+            yul_block: &context.yul_function_definition.body,
+        };
+
+        self.visit_yul_block(&mut body_context)?;
+        self.leave_yul_block(&mut body_context)?;
+
+        Ok(())
+    }
+
+    fn leave_yul_function_definition<'a, 'b, 'c>(&mut self, context: &mut YulFunctionDefinitionContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_yul_function_definition(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_yul_break<'a, 'b, 'c>(&mut self, context: &mut YulBreakContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_yul_break(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_yul_break<'a, 'b, 'c>(&mut self, context: &mut YulBreakContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_yul_break(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_yul_continue<'a, 'b, 'c>(&mut self, context: &mut YulContinueContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_yul_continue(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_yul_continue<'a, 'b, 'c>(&mut self, context: &mut YulContinueContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_yul_continue(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_yul_leave<'a, 'b, 'c>(&mut self, context: &mut YulLeaveContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.visit_yul_leave(context)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave_yul_leave<'a, 'b, 'c>(&mut self, context: &mut YulLeaveContext<'a, 'b, 'c>) -> io::Result<()> {
+        for visitor in self.visitors.iter_mut() {
+            visitor.leave_yul_leave(context)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_yul_expression<'a, 'b, 'c>(&mut self, context: &mut YulExpressionContext<'a, 'b, 'c>) -> io::Result<()> {
         for visitor in self.visitors.iter_mut() {
             visitor.visit_yul_expression(context)?;
@@ -2508,6 +3216,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulExpression::YulLiteral(yul_literal) => {
                 let mut literal_context = YulLiteralContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2527,6 +3236,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulExpression::YulIdentifier(yul_identifier) => {
                 let mut identifier_context = YulIdentifierContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2546,6 +3256,7 @@ impl AstVisitor for AstVisitorData<'_> {
             YulExpression::YulFunctionCall(yul_function_call) => {
                 let mut function_call_context = YulFunctionCallContext {
                     source_units: context.source_units,
+                    ast_index: context.ast_index,
                     current_source_unit: context.current_source_unit,
                     contract_definition: context.contract_definition,
                     definition_node: context.definition_node,
@@ -2562,11 +3273,22 @@ impl AstVisitor for AstVisitorData<'_> {
                 self.leave_yul_function_call(&mut function_call_context)?;
             }
 
-            YulExpression::UnhandledYulExpression { node_type, src, id } => {
-                println!(
-                    "WARNING: Unhandled yul expression: {:?} {:?} {:?}",
-                    node_type, src, id
-                );
+            YulExpression::UnhandledYulExpression(unhandled_yul_expression) => {
+                let mut context = UnhandledYulExpressionContext {
+                    source_units: context.source_units,
+                    ast_index: context.ast_index,
+                    current_source_unit: context.current_source_unit,
+                    contract_definition: context.contract_definition,
+                    definition_node: context.definition_node,
+                    blocks: context.blocks,
+                    statement: context.statement,
+                    inline_assembly: context.inline_assembly,
+                    yul_blocks: context.yul_blocks,
+                    yul_statement: context.yul_statement,
+                    unhandled_yul_expression,
+                };
+
+                self.visit_unhandled_yul_expression(&mut context)?;
             }
         }
 
@@ -2620,6 +3342,7 @@ impl AstVisitor for AstVisitorData<'_> {
 
         let mut identifier_context = YulIdentifierContext {
             source_units: context.source_units,
+            ast_index: context.ast_index,
             current_source_unit: context.current_source_unit,
             contract_definition: context.contract_definition,
             definition_node: context.definition_node,
@@ -2638,6 +3361,7 @@ impl AstVisitor for AstVisitorData<'_> {
         for yul_expression in context.yul_function_call.arguments.iter() {
             let mut expression_context = YulExpressionContext {
                 source_units: context.source_units,
+                ast_index: context.ast_index,
                 current_source_unit: context.current_source_unit,
                 contract_definition: context.contract_definition,
                 definition_node: context.definition_node,
@@ -0,0 +1,327 @@
+use std::io;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Identifier,
+    Number,
+    String,
+    HexString,
+    UnicodeString,
+    Punctuation,
+    EndOfFile,
+}
+
+#[derive(Clone, Debug)]
+pub struct DocComment {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub value: Option<Vec<u8>>,
+    pub start: usize,
+    pub end: usize,
+    pub documentation: Option<DocComment>,
+}
+
+const PUNCTUATION: &[&str] = &[
+    ">>>=", ">>>", "<<=", ">>=", "**", "==", "!=", "<=", ">=", "&&", "||", "++", "--", "+=", "-=", "*=", "/=",
+    "%=", "|=", "&=", "^=", "<<", ">>", "=>", "->", ":=", "(", ")", "{", "}", "[", "]", ";", ",", ".", ":",
+    "?", "=", "<", ">", "+", "-", "*", "/", "%", "!", "~", "&", "|", "^", "@",
+];
+
+pub struct Lexer<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    pending_documentation: Option<DocComment>,
+    pub license: Option<String>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            bytes: source.as_bytes(),
+            position: 0,
+            pending_documentation: None,
+            license: None,
+        }
+    }
+
+    pub fn tokenize(mut self) -> io::Result<(Vec<Token>, Option<String>)> {
+        let mut tokens = vec![];
+
+        loop {
+            let token = self.next_token()?;
+            let is_end_of_file = token.kind == TokenKind::EndOfFile;
+
+            tokens.push(token);
+
+            if is_end_of_file {
+                break;
+            }
+        }
+
+        Ok((tokens, self.license))
+    }
+
+    fn error(&self, message: &str) -> io::Error {
+        let line = self.source[..self.position].chars().filter(|&c| c == '\n').count() + 1;
+        io::Error::new(io::ErrorKind::InvalidData, format!("{} at line {}", message, line))
+    }
+
+    fn peek(&self, offset: usize) -> u8 {
+        self.bytes.get(self.position + offset).copied().unwrap_or(0)
+    }
+
+    fn scan_license(&mut self, comment: &str) {
+        if self.license.is_some() {
+            return;
+        }
+
+        if let Some(index) = comment.find("SPDX-License-Identifier:") {
+            let license = comment[index + "SPDX-License-Identifier:".len()..]
+                .lines()
+                .next()
+                .unwrap_or("")
+                .trim_end_matches("*/")
+                .trim();
+
+            if !license.is_empty() {
+                self.license = Some(license.to_string());
+            }
+        }
+    }
+
+    fn skip_trivia(&mut self) -> io::Result<()> {
+        loop {
+            match (self.peek(0), self.peek(1)) {
+                (c, _) if c.is_ascii_whitespace() => {
+                    self.position += 1;
+                }
+
+                (b'/', b'/') => {
+                    let start = self.position;
+
+                    while self.position < self.bytes.len() && self.peek(0) != b'\n' {
+                        self.position += 1;
+                    }
+
+                    let comment = &self.source[start..self.position];
+                    self.scan_license(comment);
+
+                    if comment.starts_with("///") && !comment.starts_with("////") {
+                        let text = comment[3..].trim_start_matches(' ').trim_end().to_string();
+
+                        self.pending_documentation = Some(match self.pending_documentation.take() {
+                            Some(documentation) if self.source[documentation.end..start].trim().is_empty() => DocComment {
+                                text: format!("{}\n{}", documentation.text, text),
+                                start: documentation.start,
+                                end: self.position,
+                            },
+
+                            _ => DocComment {
+                                text,
+                                start,
+                                end: self.position,
+                            },
+                        });
+                    } else {
+                        self.pending_documentation = None;
+                    }
+                }
+
+                (b'/', b'*') => {
+                    let start = self.position;
+
+                    match self.source[start + 2..].find("*/") {
+                        Some(index) => self.position = start + 2 + index + 2,
+                        None => return Err(self.error("Unterminated comment")),
+                    }
+
+                    let comment = &self.source[start..self.position];
+                    self.scan_license(comment);
+
+                    if comment.starts_with("/**") && comment.len() > 4 {
+                        let text = comment[3..comment.len() - 2]
+                            .lines()
+                            .map(|line| line.trim().trim_start_matches('*').trim_start_matches(' '))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                            .trim()
+                            .to_string();
+
+                        self.pending_documentation = Some(DocComment {
+                            text,
+                            start,
+                            end: self.position,
+                        });
+                    } else {
+                        self.pending_documentation = None;
+                    }
+                }
+
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> io::Result<Token> {
+        self.skip_trivia()?;
+
+        let start = self.position;
+        let documentation = self.pending_documentation.take();
+
+        let c = self.peek(0);
+
+        let (kind, value) = if start >= self.bytes.len() {
+            (TokenKind::EndOfFile, None)
+        } else if c.is_ascii_digit() || (c == b'.' && self.peek(1).is_ascii_digit()) {
+            self.scan_number()?;
+            (TokenKind::Number, None)
+        } else if c == b'"' || c == b'\'' {
+            (TokenKind::String, Some(self.scan_string()?))
+        } else if c.is_ascii_alphabetic() || c == b'_' || c == b'$' {
+            while self.peek(0).is_ascii_alphanumeric() || self.peek(0) == b'_' || self.peek(0) == b'$' {
+                self.position += 1;
+            }
+
+            match (&self.source[start..self.position], self.peek(0)) {
+                ("hex", b'"' | b'\'') => {
+                    let text = self.scan_string()?;
+
+                    let digits: Vec<u8> = text.into_iter().filter(|&c| c != b'_').collect();
+
+                    if !digits.len().is_multiple_of(2) || !digits.iter().all(u8::is_ascii_hexdigit) {
+                        return Err(self.error("Invalid hex string literal"));
+                    }
+
+                    let value = digits
+                        .chunks(2)
+                        .map(|chunk| u8::from_str_radix(std::str::from_utf8(chunk).unwrap_or("00"), 16).unwrap_or(0))
+                        .collect();
+
+                    (TokenKind::HexString, Some(value))
+                }
+
+                ("unicode", b'"' | b'\'') => (TokenKind::UnicodeString, Some(self.scan_string()?)),
+
+                _ => (TokenKind::Identifier, None),
+            }
+        } else {
+            match PUNCTUATION.iter().find(|punctuation| self.source[start..].starts_with(*punctuation)) {
+                Some(punctuation) => self.position += punctuation.len(),
+                None => return Err(self.error(format!("Unexpected character '{}'", self.source[start..].chars().next().unwrap_or(' ')).as_str())),
+            }
+
+            (TokenKind::Punctuation, None)
+        };
+
+        Ok(Token {
+            kind,
+            text: self.source[start..self.position].to_string(),
+            value,
+            start,
+            end: self.position,
+            documentation,
+        })
+    }
+
+    fn scan_number(&mut self) -> io::Result<()> {
+        if self.peek(0) == b'0' && (self.peek(1) == b'x' || self.peek(1) == b'X') {
+            self.position += 2;
+            let digits_start = self.position;
+
+            while self.peek(0).is_ascii_hexdigit() || self.peek(0) == b'_' {
+                self.position += 1;
+            }
+
+            if !self.source[digits_start..self.position].bytes().any(|c| c.is_ascii_hexdigit()) {
+                return Err(self.error("Invalid hex number literal"));
+            }
+
+            return Ok(());
+        }
+
+        while self.peek(0).is_ascii_digit() || self.peek(0) == b'_' {
+            self.position += 1;
+        }
+
+        if self.peek(0) == b'.' && self.peek(1).is_ascii_digit() {
+            self.position += 1;
+
+            while self.peek(0).is_ascii_digit() || self.peek(0) == b'_' {
+                self.position += 1;
+            }
+        }
+
+        if (self.peek(0) == b'e' || self.peek(0) == b'E') && (self.peek(1).is_ascii_digit() || (self.peek(1) == b'-' && self.peek(2).is_ascii_digit())) {
+            self.position += 2;
+
+            while self.peek(0).is_ascii_digit() || self.peek(0) == b'_' {
+                self.position += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn scan_string(&mut self) -> io::Result<Vec<u8>> {
+        let quote = self.peek(0);
+        self.position += 1;
+
+        let mut value = vec![];
+
+        loop {
+            match self.peek(0) {
+                0 if self.position >= self.bytes.len() => return Err(self.error("Unterminated string literal")),
+                b'\n' => return Err(self.error("Unterminated string literal")),
+
+                c if c == quote => {
+                    self.position += 1;
+                    return Ok(value);
+                }
+
+                b'\\' => {
+                    self.position += 1;
+
+                    let c = self.peek(0);
+                    self.position += 1;
+
+                    match c {
+                        b'n' => value.push(b'\n'),
+                        b'r' => value.push(b'\r'),
+                        b't' => value.push(b'\t'),
+                        b'\n' => {}
+
+                        b'x' => {
+                            let digits = self.source.get(self.position..self.position + 2).unwrap_or("");
+                            value.push(u8::from_str_radix(digits, 16).map_err(|_| self.error("Invalid hex escape"))?);
+                            self.position += 2;
+                        }
+
+                        b'u' => {
+                            let digits = self.source.get(self.position..self.position + 4).unwrap_or("");
+                            let code_point = u32::from_str_radix(digits, 16).map_err(|_| self.error("Invalid unicode escape"))?;
+                            let mut buffer = [0; 4];
+                            value.extend_from_slice(char::from_u32(code_point).unwrap_or('\u{fffd}').encode_utf8(&mut buffer).as_bytes());
+                            self.position += 4;
+                        }
+
+                        c => value.push(c),
+                    }
+                }
+
+                c => {
+                    value.push(c);
+                    self.position += 1;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,2419 @@
+mod lexer;
+mod resolver;
+
+pub use self::resolver::resolve;
+
+use self::lexer::{Lexer, Token, TokenKind};
+use crate::ast::*;
+use eth_lang_utils::ast::*;
+use std::{collections::HashMap, io};
+
+const SUBDENOMINATIONS: &[&str] = &[
+    "wei", "gwei", "szabo", "finney", "ether", "seconds", "minutes", "hours", "days", "weeks", "years",
+];
+
+const ASSIGNMENT_OPERATORS: &[&str] = &[
+    "=", "|=", "^=", "&=", "<<=", ">>=", ">>>=", "+=", "-=", "*=", "/=", "%=",
+];
+
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "assembly", "break", "continue", "delete", "do", "else", "emit", "for", "if", "new", "return", "revert", "try",
+    "unchecked", "while",
+];
+
+pub fn is_elementary_type_name(name: &str) -> bool {
+    fn has_valid_size(suffix: &str, is_valid: fn(usize) -> bool) -> bool {
+        suffix.is_empty() || suffix.parse().map(is_valid).unwrap_or(false)
+    }
+
+    match name {
+        "address" | "bool" | "string" | "bytes" | "byte" | "var" => true,
+        name if name.starts_with("bytes") => has_valid_size(&name[5..], |size| (1..=32).contains(&size)) && name.len() > 5,
+        name if name.starts_with("uint") => has_valid_size(&name[4..], |size| size % 8 == 0 && (8..=256).contains(&size)),
+        name if name.starts_with("int") => has_valid_size(&name[3..], |size| size % 8 == 0 && (8..=256).contains(&size)),
+        name if name.starts_with("ufixed") || name.starts_with("fixed") => {
+            let suffix = name.trim_start_matches('u').trim_start_matches("fixed");
+            suffix.is_empty() || suffix.split('x').count() == 2 && suffix.split('x').all(|part| part.parse::<usize>().is_ok())
+        }
+        _ => false,
+    }
+}
+
+fn empty_type_descriptions() -> TypeDescriptions {
+    TypeDescriptions {
+        type_identifier: None,
+        type_string: None,
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub struct Parser {
+    next_node_id: NodeID,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self { next_node_id: 1 }
+    }
+}
+
+impl Parser {
+    pub fn new(first_node_id: NodeID) -> Self {
+        Self { next_node_id: first_node_id }
+    }
+
+    pub fn next_node_id(&self) -> NodeID {
+        self.next_node_id
+    }
+
+    pub fn parse(&mut self, source: &str, absolute_path: &str, file_index: usize) -> io::Result<SourceUnit> {
+        let (tokens, license) = Lexer::new(source).tokenize().map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {}", absolute_path, error))
+        })?;
+
+        let mut state = ParserState {
+            source,
+            absolute_path,
+            file_index,
+            tokens,
+            position: 0,
+            next_node_id: self.next_node_id,
+            scopes: vec![],
+            return_parameters: None,
+        };
+
+        let result = state.parse_source_unit(license);
+        self.next_node_id = state.next_node_id;
+
+        result
+    }
+
+    //
+    // Yul objects are wrapped in contracts of the YulObject kind with a single `code` function containing their code as
+    // inline assembly, so the assembly analyzers can visit them. Nested objects become separate contracts, and a file
+    // with only a code block becomes an unnamed one.
+    //
+
+    pub fn parse_yul(&mut self, source: &str, absolute_path: &str, file_index: usize) -> io::Result<SourceUnit> {
+        let object = yul::parser::parse_object(source, file_index).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {}", absolute_path, error))
+        })?;
+
+        let id = self.next_id();
+        let mut nodes = vec![];
+
+        self.push_yul_object(object, id, &mut nodes);
+
+        let exported_symbols = nodes
+            .iter()
+            .filter_map(|node| match node {
+                SourceUnitNode::ContractDefinition(definition) if !definition.name.is_empty() => Some((definition.name.clone(), vec![definition.id])),
+                _ => None,
+            })
+            .collect();
+
+        let license = source
+            .lines()
+            .find_map(|line| line.split("SPDX-License-Identifier:").nth(1))
+            .map(|license| license.trim().trim_end_matches("*/").trim().to_string())
+            .filter(|license| !license.is_empty());
+
+        Ok(SourceUnit {
+            license,
+            nodes,
+            exported_symbols: Some(exported_symbols),
+            absolute_path: Some(absolute_path.to_string()),
+            id,
+            source: Some(source.to_string()),
+            compilation_id: None,
+            line_table: Default::default(),
+        })
+    }
+
+    fn next_id(&mut self) -> NodeID {
+        let id = self.next_node_id;
+        self.next_node_id += 1;
+        id
+    }
+
+    fn push_yul_object(&mut self, object: yul::ast::YulObject, scope: NodeID, nodes: &mut Vec<SourceUnitNode>) {
+        let src = object.src.unwrap_or_default();
+        let code_src = object.code.src.clone().unwrap_or_default();
+        let empty_src = format!("{}:0:{}", code_src.split(':').next().unwrap_or("0"), src.split(':').nth(2).unwrap_or("0"));
+
+        let contract_id = self.next_id();
+
+        let function_definition = FunctionDefinition {
+            base_functions: None,
+            implemented: true,
+            body: Some(Block {
+                statements: vec![Statement::InlineAssembly(InlineAssembly {
+                    ast: Some(object.code),
+                    evm_version: None,
+                    external_references: vec![],
+                    operations: None,
+                    src: code_src.clone(),
+                    id: self.next_id(),
+                })],
+                src: code_src.clone(),
+                id: self.next_id(),
+            }),
+            documentation: None,
+            function_selector: None,
+            kind: FunctionKind::Function,
+            modifiers: vec![],
+            name: "code".to_string(),
+            name_location: None,
+            overrides: None,
+            parameters: ParameterList {
+                parameters: vec![],
+                src: empty_src.clone(),
+                id: self.next_id(),
+            },
+            return_parameters: ParameterList {
+                parameters: vec![],
+                src: empty_src,
+                id: self.next_id(),
+            },
+            scope: contract_id,
+            state_mutability: StateMutability::NonPayable,
+            super_function: None,
+            r#virtual: Some(false),
+            visibility: Visibility::External,
+            src: code_src,
+            id: self.next_id(),
+        };
+
+        nodes.push(SourceUnitNode::ContractDefinition(ContractDefinition {
+            name: object.name,
+            name_location: None,
+            documentation: None,
+            kind: ContractKind::YulObject,
+            is_abstract: Some(false),
+            base_contracts: vec![],
+            contract_dependencies: vec![],
+            used_errors: Some(vec![]),
+            nodes: vec![ContractDefinitionNode::FunctionDefinition(function_definition)],
+            scope,
+            fully_implemented: Some(true),
+            linearized_base_contracts: Some(vec![contract_id]),
+            src,
+            id: contract_id,
+        }));
+
+        for object in object.objects {
+            self.push_yul_object(object, scope, nodes);
+        }
+    }
+}
+
+pub fn parse_source_unit(source: &str, absolute_path: &str) -> io::Result<SourceUnit> {
+    let mut source_units = vec![Parser::default().parse(source, absolute_path, 0)?];
+    resolve(source_units.as_mut_slice());
+    Ok(source_units.remove(0))
+}
+
+struct ParserState<'a> {
+    source: &'a str,
+    absolute_path: &'a str,
+    file_index: usize,
+    tokens: Vec<Token>,
+    position: usize,
+    next_node_id: NodeID,
+    scopes: Vec<NodeID>,
+    return_parameters: Option<NodeID>,
+}
+
+impl<'a> ParserState<'a> {
+    //
+    // Token helpers
+    //
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.position.min(self.tokens.len() - 1)]
+    }
+
+    fn peek(&self, offset: usize) -> &Token {
+        &self.tokens[(self.position + offset).min(self.tokens.len() - 1)]
+    }
+
+    fn at(&self, text: &str) -> bool {
+        let token = self.token();
+        matches!(token.kind, TokenKind::Identifier | TokenKind::Punctuation) && token.text == text
+    }
+
+    fn peek_is(&self, offset: usize, text: &str) -> bool {
+        let token = self.peek(offset);
+        matches!(token.kind, TokenKind::Identifier | TokenKind::Punctuation) && token.text == text
+    }
+
+    fn at_identifier(&self) -> bool {
+        self.token().kind == TokenKind::Identifier
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.token().clone();
+
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        if self.at(text) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn previous_end(&self) -> usize {
+        match self.position {
+            0 => 0,
+            position => self.tokens[position - 1].end,
+        }
+    }
+
+    fn error(&self, message: String) -> io::Error {
+        self.error_at(self.token().start, message)
+    }
+
+    fn error_at(&self, start: usize, message: String) -> io::Error {
+        let line = self.source[..start].chars().filter(|&c| c == '\n').count() + 1;
+        let column = start - self.source[..start].rfind('\n').map(|index| index + 1).unwrap_or(0) + 1;
+
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}:{}:{}: {}", self.absolute_path, line, column, message),
+        )
+    }
+
+    fn unexpected(&self, expected: &str) -> io::Error {
+        let found = match self.token().kind {
+            TokenKind::EndOfFile => "end of file".to_string(),
+            _ => format!("'{}'", self.token().text),
+        };
+
+        self.error(format!("Expected {}, found {}", expected, found))
+    }
+
+    fn expect(&mut self, text: &str) -> io::Result<Token> {
+        if self.at(text) {
+            Ok(self.advance())
+        } else {
+            Err(self.unexpected(format!("'{}'", text).as_str()))
+        }
+    }
+
+    fn expect_identifier(&mut self) -> io::Result<Token> {
+        if self.at_identifier() {
+            Ok(self.advance())
+        } else {
+            Err(self.unexpected("identifier"))
+        }
+    }
+
+    fn next_id(&mut self) -> NodeID {
+        let id = self.next_node_id;
+        self.next_node_id += 1;
+        id
+    }
+
+    fn src(&self, start: usize) -> String {
+        format!("{}:{}:{}", start, self.previous_end().max(start) - start, self.file_index)
+    }
+
+    fn token_src(&self, token: &Token) -> String {
+        format!("{}:{}:{}", token.start, token.end - token.start, self.file_index)
+    }
+
+    fn scope(&self) -> NodeID {
+        self.scopes.last().copied().unwrap_or(0)
+    }
+
+    fn documentation(&mut self, token: &Token) -> Option<Documentation> {
+        let documentation = token.documentation.as_ref()?;
+
+        Some(Documentation::Structured(Some(StructuredDocumentation {
+            text: documentation.text.clone(),
+            src: format!("{}:{}:{}", documentation.start, documentation.end - documentation.start, self.file_index),
+            id: self.next_id(),
+        })))
+    }
+
+    fn skip_balanced(&mut self, open: &str, close: &str) -> io::Result<()> {
+        self.expect(open)?;
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.token().kind {
+                TokenKind::EndOfFile => return Err(self.unexpected(format!("'{}'", close).as_str())),
+                _ if self.at(open) => depth += 1,
+                _ if self.at(close) => depth -= 1,
+                _ => {}
+            }
+
+            self.advance();
+        }
+
+        Ok(())
+    }
+
+    //
+    // Source units
+    //
+
+    fn parse_source_unit(&mut self, license: Option<String>) -> io::Result<SourceUnit> {
+        let id = self.next_id();
+        self.scopes.push(id);
+
+        let mut nodes = vec![];
+
+        while self.token().kind != TokenKind::EndOfFile {
+            if self.at("pragma") {
+                nodes.push(SourceUnitNode::PragmaDirective(self.parse_pragma_directive()?));
+            } else if self.at("import") {
+                nodes.push(SourceUnitNode::ImportDirective(self.parse_import_directive(id)?));
+            } else if self.at("contract") || self.at("interface") || self.at("library") || self.at("abstract") {
+                nodes.push(SourceUnitNode::ContractDefinition(self.parse_contract_definition()?));
+            } else if self.at("struct") {
+                nodes.push(SourceUnitNode::StructDefinition(self.parse_struct_definition()?));
+            } else if self.at("enum") {
+                nodes.push(SourceUnitNode::EnumDefinition(self.parse_enum_definition()?));
+            } else if self.at("error") && self.peek(1).kind == TokenKind::Identifier && self.peek_is(2, "(") {
+                nodes.push(SourceUnitNode::ErrorDefinition(self.parse_error_definition()?));
+            } else if self.at("type") && self.peek(1).kind == TokenKind::Identifier && self.peek_is(2, "is") {
+                nodes.push(SourceUnitNode::UserDefinedValueTypeDefinition(self.parse_user_defined_value_type_definition()?));
+            } else if self.at("function") && !self.peek_is(1, "(") {
+                //
+                // Free functions cannot specify a visibility and are always internal
+                //
+
+                let mut function_definition = self.parse_function_definition(&ContractKind::Contract)?;
+                function_definition.visibility = Visibility::Internal;
+
+                nodes.push(SourceUnitNode::FunctionDefinition(function_definition));
+            } else if self.at("event") {
+                nodes.push(SourceUnitNode::EventDefinition(self.parse_event_definition()?));
+            } else if self.at("using") {
+                nodes.push(SourceUnitNode::UsingForDirective(self.parse_using_for_directive()?));
+            } else if self.eat(";") {
+                continue;
+            } else {
+                let variable_declaration = self.parse_state_variable_declaration(true)?;
+                nodes.push(SourceUnitNode::VariableDeclaration(variable_declaration));
+            }
+        }
+
+        self.scopes.pop();
+
+        let mut exported_symbols = HashMap::new();
+
+        for node in nodes.iter() {
+            let (name, node_id) = match node {
+                SourceUnitNode::ContractDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::StructDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::EnumDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::EventDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::ErrorDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::FunctionDefinition(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::VariableDeclaration(definition) => (definition.name.clone(), definition.id),
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) => (definition.name.clone(), definition.id),
+                _ => continue,
+            };
+
+            exported_symbols.insert(name, vec![node_id]);
+        }
+
+        Ok(SourceUnit {
+            license,
+            nodes,
+            exported_symbols: Some(exported_symbols),
+            absolute_path: Some(self.absolute_path.to_string()),
+            id,
+            source: Some(self.source.to_string()),
+            compilation_id: None,
+            line_table: Default::default(),
+        })
+    }
+
+    fn parse_pragma_directive(&mut self) -> io::Result<PragmaDirective> {
+        let start = self.expect("pragma")?.start;
+
+        let mut literals = vec![];
+
+        while !self.at(";") {
+            if self.token().kind == TokenKind::EndOfFile {
+                return Err(self.unexpected("';'"));
+            }
+
+            literals.push(self.advance().text);
+        }
+
+        self.expect(";")?;
+
+        Ok(PragmaDirective {
+            literals,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn resolve_import_path(&self, file: &str) -> String {
+        if !file.starts_with("./") && !file.starts_with("../") {
+            return file.to_string();
+        }
+
+        let mut components: Vec<&str> = self.absolute_path.split('/').collect();
+        components.pop();
+
+        for component in file.split('/') {
+            match component {
+                "." | "" => {}
+                ".." => {
+                    components.pop();
+                }
+                component => components.push(component),
+            }
+        }
+
+        components.join("/")
+    }
+
+    fn parse_import_directive(&mut self, scope: NodeID) -> io::Result<ImportDirective> {
+        let start = self.expect("import")?.start;
+
+        let mut unit_alias = String::new();
+        let mut name_location = None;
+        let mut symbol_aliases = vec![];
+
+        let file = if self.at("{") {
+            self.advance();
+
+            while !self.at("}") {
+                let foreign = self.expect_identifier()?;
+
+                let (local, local_location) = if self.eat("as") {
+                    let local = self.expect_identifier()?;
+                    (Some(local.text.clone()), Some(self.token_src(&local)))
+                } else {
+                    (None, None)
+                };
+
+                symbol_aliases.push(SymbolAlias {
+                    foreign: Expression::Identifier(Identifier {
+                        argument_types: None,
+                        name: foreign.text.clone(),
+                        overloaded_declarations: vec![],
+                        referenced_declaration: 0,
+                        type_descriptions: empty_type_descriptions(),
+                        src: self.token_src(&foreign),
+                        id: self.next_id(),
+                    }),
+                    local,
+                    name_location: local_location,
+                });
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+
+            self.expect("}")?;
+            self.expect("from")?;
+            self.parse_import_path()?
+        } else if self.at("*") {
+            self.advance();
+            self.expect("as")?;
+
+            let alias = self.expect_identifier()?;
+            unit_alias = alias.text.clone();
+            name_location = Some(self.token_src(&alias));
+
+            self.expect("from")?;
+            self.parse_import_path()?
+        } else {
+            let file = self.parse_import_path()?;
+
+            if self.eat("as") {
+                let alias = self.expect_identifier()?;
+                unit_alias = alias.text.clone();
+                name_location = Some(self.token_src(&alias));
+            }
+
+            file
+        };
+
+        self.expect(";")?;
+
+        Ok(ImportDirective {
+            absolute_path: Some(self.resolve_import_path(file.as_str())),
+            file,
+            source_unit: 0,
+            scope,
+            unit_alias,
+            name_location,
+            symbol_aliases,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_import_path(&mut self) -> io::Result<String> {
+        if self.token().kind != TokenKind::String {
+            return Err(self.unexpected("import path"));
+        }
+
+        let token = self.advance();
+        Ok(String::from_utf8_lossy(token.value.as_deref().unwrap_or(&[])).to_string())
+    }
+
+    //
+    // Contracts
+    //
+
+    fn parse_contract_definition(&mut self) -> io::Result<ContractDefinition> {
+        let first_token = self.token().clone();
+        let start = first_token.start;
+        let documentation = self.documentation(&first_token);
+
+        let is_abstract = self.eat("abstract");
+
+        let kind = match self.advance().text.as_str() {
+            "contract" => ContractKind::Contract,
+            "interface" => ContractKind::Interface,
+            "library" => ContractKind::Library,
+            _ => return Err(self.error("Expected contract, interface or library".to_string())),
+        };
+
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        let id = self.next_id();
+        let scope = self.scope();
+
+        let mut base_contracts = vec![];
+
+        if self.eat("is") {
+            loop {
+                let base_start = self.token().start;
+                let base_name = self.parse_identifier_path()?;
+
+                let arguments = if self.at("(") {
+                    Some(self.parse_call_arguments()?.1)
+                } else {
+                    None
+                };
+
+                base_contracts.push(InheritanceSpecifier {
+                    base_name,
+                    arguments,
+                    src: self.src(base_start),
+                    id: self.next_id(),
+                });
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+        }
+
+        self.expect("{")?;
+        self.scopes.push(id);
+
+        let mut nodes = vec![];
+
+        while !self.at("}") {
+            if self.token().kind == TokenKind::EndOfFile {
+                return Err(self.unexpected("'}'"));
+            }
+
+            if self.eat(";") {
+                continue;
+            }
+
+            nodes.push(self.parse_contract_definition_node(&kind)?);
+        }
+
+        self.scopes.pop();
+        self.expect("}")?;
+
+        Ok(ContractDefinition {
+            name: name.text,
+            name_location,
+            documentation,
+            kind,
+            is_abstract: Some(is_abstract),
+            base_contracts,
+            contract_dependencies: vec![],
+            used_errors: Some(vec![]),
+            nodes,
+            scope,
+            fully_implemented: None,
+            linearized_base_contracts: Some(vec![id]),
+            src: self.src(start),
+            id,
+        })
+    }
+
+    fn parse_contract_definition_node(&mut self, contract_kind: &ContractKind) -> io::Result<ContractDefinitionNode> {
+        Ok(if self.at("using") {
+            ContractDefinitionNode::UsingForDirective(self.parse_using_for_directive()?)
+        } else if self.at("struct") {
+            ContractDefinitionNode::StructDefinition(self.parse_struct_definition()?)
+        } else if self.at("enum") {
+            ContractDefinitionNode::EnumDefinition(self.parse_enum_definition()?)
+        } else if self.at("event") {
+            ContractDefinitionNode::EventDefinition(self.parse_event_definition()?)
+        } else if self.at("error") && self.peek(1).kind == TokenKind::Identifier && self.peek_is(2, "(") {
+            ContractDefinitionNode::ErrorDefinition(self.parse_error_definition()?)
+        } else if self.at("modifier") {
+            ContractDefinitionNode::ModifierDefinition(self.parse_modifier_definition()?)
+        } else if self.at("type") && self.peek(1).kind == TokenKind::Identifier && self.peek_is(2, "is") {
+            ContractDefinitionNode::UserDefinedValueTypeDefinition(self.parse_user_defined_value_type_definition()?)
+        } else if self.at("function") && self.peek_is(1, "(") {
+            //
+            // Unnamed functions are either legacy fallback functions or state variables of function type
+            //
+
+            let position = self.position;
+            let next_node_id = self.next_node_id;
+
+            match self.parse_state_variable_declaration(false) {
+                Ok(variable_declaration) => ContractDefinitionNode::VariableDeclaration(variable_declaration),
+
+                Err(_) => {
+                    self.position = position;
+                    self.next_node_id = next_node_id;
+
+                    ContractDefinitionNode::FunctionDefinition(self.parse_function_definition(contract_kind)?)
+                }
+            }
+        } else if self.at("function") || self.at("constructor") || self.at("fallback") || self.at("receive") {
+            ContractDefinitionNode::FunctionDefinition(self.parse_function_definition(contract_kind)?)
+        } else {
+            ContractDefinitionNode::VariableDeclaration(self.parse_state_variable_declaration(false)?)
+        })
+    }
+
+    fn parse_identifier_path(&mut self) -> io::Result<IdentifierPath> {
+        let start = self.token().start;
+        let mut name = self.expect_identifier()?.text;
+
+        while self.at(".") && self.peek(1).kind == TokenKind::Identifier {
+            self.advance();
+            name.push('.');
+            name.push_str(self.advance().text.as_str());
+        }
+
+        Ok(IdentifierPath {
+            name,
+            referenced_declaration: None,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_using_for_directive(&mut self) -> io::Result<UsingForDirective> {
+        let start = self.expect("using")?.start;
+
+        let mut library_name = None;
+        let mut function_list = vec![];
+
+        if self.eat("{") {
+            while !self.at("}") {
+                let function = self.parse_identifier_path()?;
+
+                function_list.push(if self.eat("as") {
+                    UsingForFunction::Operator {
+                        definition: function,
+                        operator: self.advance().text,
+                    }
+                } else {
+                    UsingForFunction::Function { function }
+                });
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+
+            self.expect("}")?;
+        } else {
+            library_name = Some(self.parse_identifier_path()?);
+        }
+
+        self.expect("for")?;
+
+        let type_name = if self.eat("*") {
+            None
+        } else {
+            Some(self.parse_type_name()?)
+        };
+
+        let global = self.eat("global");
+        self.expect(";")?;
+
+        Ok(UsingForDirective {
+            library_name,
+            function_list,
+            type_name,
+            global,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_struct_definition(&mut self) -> io::Result<StructDefinition> {
+        let start = self.expect("struct")?.start;
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        let id = self.next_id();
+        let scope = self.scope();
+
+        self.expect("{")?;
+        self.scopes.push(id);
+
+        let mut members = vec![];
+
+        while !self.at("}") {
+            let member_start = self.token().start;
+            let type_name = self.parse_type_name()?;
+            let member_name = self.expect_identifier()?;
+            self.expect(";")?;
+
+            members.push(self.variable_declaration(
+                member_start,
+                type_name,
+                &member_name,
+                StorageLocation::Default,
+                None,
+            ));
+        }
+
+        self.scopes.pop();
+        self.expect("}")?;
+
+        Ok(StructDefinition {
+            name: name.text,
+            name_location,
+            visibility: Visibility::Public,
+            members,
+            scope,
+            canonical_name: None,
+            src: self.src(start),
+            id,
+        })
+    }
+
+    fn parse_enum_definition(&mut self) -> io::Result<EnumDefinition> {
+        let start = self.expect("enum")?.start;
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        self.expect("{")?;
+
+        let mut members = vec![];
+
+        while !self.at("}") {
+            let member = self.expect_identifier()?;
+
+            members.push(EnumValue {
+                name: member.text.clone(),
+                name_location: Some(self.token_src(&member)),
+                src: self.token_src(&member),
+                id: self.next_id(),
+            });
+
+            if !self.eat(",") {
+                break;
+            }
+        }
+
+        self.expect("}")?;
+
+        Ok(EnumDefinition {
+            name: name.text,
+            name_location,
+            members,
+            canonical_name: None,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_event_definition(&mut self) -> io::Result<EventDefinition> {
+        let first_token = self.expect("event")?;
+        let documentation = self.documentation(&first_token);
+
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        let id = self.next_id();
+        self.scopes.push(id);
+        let parameters = self.parse_parameter_list(true)?;
+        self.scopes.pop();
+
+        let anonymous = self.eat("anonymous");
+        self.expect(";")?;
+
+        Ok(EventDefinition {
+            anonymous,
+            documentation,
+            name: name.text,
+            name_location,
+            parameters,
+            src: self.src(first_token.start),
+            id,
+        })
+    }
+
+    fn parse_error_definition(&mut self) -> io::Result<ErrorDefinition> {
+        let first_token = self.expect("error")?;
+        let documentation = self.documentation(&first_token);
+
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        let id = self.next_id();
+        self.scopes.push(id);
+        let parameters = self.parse_parameter_list(false)?;
+        self.scopes.pop();
+
+        self.expect(";")?;
+
+        Ok(ErrorDefinition {
+            documentation,
+            name: name.text,
+            name_location,
+            parameters,
+            src: self.src(first_token.start),
+            id,
+        })
+    }
+
+    fn parse_user_defined_value_type_definition(&mut self) -> io::Result<UserDefinedValueTypeDefinition> {
+        let start = self.expect("type")?.start;
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        self.expect("is")?;
+        let underlying_type = self.parse_type_name()?;
+        self.expect(";")?;
+
+        Ok(UserDefinedValueTypeDefinition {
+            underlying_type,
+            name: name.text,
+            name_location,
+            canonical_name: None,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_override_specifier(&mut self) -> io::Result<OverrideSpecifier> {
+        let start = self.expect("override")?.start;
+
+        let mut overrides = vec![];
+
+        if self.eat("(") {
+            while !self.at(")") {
+                overrides.push(self.parse_identifier_path()?);
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+
+            self.expect(")")?;
+        }
+
+        Ok(OverrideSpecifier {
+            overrides,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_modifier_definition(&mut self) -> io::Result<ModifierDefinition> {
+        let first_token = self.expect("modifier")?;
+        let documentation = self.documentation(&first_token);
+
+        let name = self.expect_identifier()?;
+        let name_location = Some(self.token_src(&name));
+
+        let id = self.next_id();
+        self.scopes.push(id);
+
+        let parameters = if self.at("(") {
+            self.parse_parameter_list(false)?
+        } else {
+            ParameterList {
+                parameters: vec![],
+                src: format!("{}:0:{}", self.previous_end(), self.file_index),
+                id: self.next_id(),
+            }
+        };
+
+        let mut is_virtual = false;
+        let mut overrides = None;
+
+        loop {
+            if self.eat("virtual") {
+                is_virtual = true;
+            } else if self.at("override") {
+                overrides = Some(self.parse_override_specifier()?);
+            } else {
+                break;
+            }
+        }
+
+        let previous_return_parameters = self.return_parameters.replace(0);
+
+        let body = if self.at("{") {
+            self.parse_block()?
+        } else {
+            let semicolon = self.expect(";")?;
+
+            Block {
+                statements: vec![],
+                src: self.token_src(&semicolon),
+                id: self.next_id(),
+            }
+        };
+
+        self.return_parameters = previous_return_parameters;
+        self.scopes.pop();
+
+        Ok(ModifierDefinition {
+            body,
+            overrides,
+            documentation,
+            name: name.text,
+            name_location,
+            parameters,
+            r#virtual: Some(is_virtual),
+            visibility: Visibility::Internal,
+            src: self.src(first_token.start),
+            id,
+        })
+    }
+
+    fn parse_function_definition(&mut self, contract_kind: &ContractKind) -> io::Result<FunctionDefinition> {
+        let first_token = self.advance();
+        let documentation = self.documentation(&first_token);
+
+        let (kind, name, name_location) = match first_token.text.as_str() {
+            "constructor" => (FunctionKind::Constructor, String::new(), None),
+            "fallback" => (FunctionKind::Fallback, String::new(), None),
+            "receive" => (FunctionKind::Receive, String::new(), None),
+
+            _ if self.at("(") => (FunctionKind::Fallback, String::new(), None),
+
+            _ => {
+                let name = self.advance();
+
+                if name.kind != TokenKind::Identifier {
+                    return Err(self.error(format!("Expected function name, found '{}'", name.text)));
+                }
+
+                let name_location = Some(self.token_src(&name));
+                (FunctionKind::Function, name.text, name_location)
+            }
+        };
+
+        let id = self.next_id();
+        self.scopes.push(id);
+
+        let parameters = self.parse_parameter_list(false)?;
+
+        let mut visibility = None;
+        let mut state_mutability = StateMutability::NonPayable;
+        let mut is_virtual = false;
+        let mut overrides = None;
+        let mut modifiers = vec![];
+
+        loop {
+            let text = self.token().text.clone();
+
+            match text.as_str() {
+                "public" | "private" | "internal" | "external" if self.at_identifier() => {
+                    self.advance();
+
+                    visibility = Some(match text.as_str() {
+                        "public" => Visibility::Public,
+                        "private" => Visibility::Private,
+                        "internal" => Visibility::Internal,
+                        _ => Visibility::External,
+                    });
+                }
+
+                "pure" | "view" | "payable" | "constant" if self.at_identifier() => {
+                    self.advance();
+
+                    state_mutability = match text.as_str() {
+                        "pure" => StateMutability::Pure,
+                        "payable" => StateMutability::Payable,
+                        _ => StateMutability::View,
+                    };
+                }
+
+                "virtual" if self.at_identifier() => {
+                    self.advance();
+                    is_virtual = true;
+                }
+
+                "override" if self.at_identifier() => {
+                    overrides = Some(self.parse_override_specifier()?);
+                }
+
+                "returns" => break,
+
+                _ if self.at_identifier() => {
+                    let modifier_start = self.token().start;
+                    let modifier_name = self.parse_identifier_path()?;
+
+                    let arguments = if self.at("(") {
+                        Some(self.parse_call_arguments()?.1)
+                    } else {
+                        None
+                    };
+
+                    modifiers.push(ModifierInvocation {
+                        arguments,
+                        modifier_name,
+                        src: self.src(modifier_start),
+                        id: self.next_id(),
+                        kind: None,
+                    });
+                }
+
+                _ => break,
+            }
+        }
+
+        let return_parameters = if self.eat("returns") {
+            self.parse_parameter_list(false)?
+        } else {
+            ParameterList {
+                parameters: vec![],
+                src: format!("{}:0:{}", self.previous_end(), self.file_index),
+                id: self.next_id(),
+            }
+        };
+
+        let previous_return_parameters = self.return_parameters.replace(return_parameters.id);
+
+        let body = if self.at("{") {
+            Some(self.parse_block()?)
+        } else {
+            self.expect(";")?;
+            None
+        };
+
+        self.return_parameters = previous_return_parameters;
+        self.scopes.pop();
+
+        let visibility = visibility.unwrap_or(match (contract_kind, &kind) {
+            (ContractKind::Interface, _) | (_, FunctionKind::Fallback | FunctionKind::Receive) => Visibility::External,
+            _ => Visibility::Public,
+        });
+
+        Ok(FunctionDefinition {
+            base_functions: None,
+            implemented: body.is_some(),
+            body,
+            documentation,
+            function_selector: None,
+            kind,
+            modifiers,
+            name,
+            name_location,
+            overrides,
+            parameters,
+            return_parameters,
+            scope: self.scope(),
+            state_mutability,
+            super_function: None,
+            r#virtual: Some(is_virtual),
+            visibility,
+            src: self.src(first_token.start),
+            id,
+        })
+    }
+
+    fn parse_parameter_list(&mut self, is_event: bool) -> io::Result<ParameterList> {
+        let start = self.expect("(")?.start;
+
+        let mut parameters = vec![];
+
+        while !self.at(")") {
+            let parameter_start = self.token().start;
+            let type_name = self.parse_type_name()?;
+
+            let mut indexed = if is_event { Some(false) } else { None };
+
+            if is_event && self.eat("indexed") {
+                indexed = Some(true);
+            }
+
+            let storage_location = self.parse_storage_location();
+
+            let name = if self.at_identifier() {
+                Some(self.advance())
+            } else {
+                None
+            };
+
+            let mut parameter = match name.as_ref() {
+                Some(name) => self.variable_declaration(parameter_start, type_name, name, storage_location, None),
+
+                None => {
+                    let id = self.next_id();
+
+                    VariableDeclaration {
+                        base_functions: None,
+                        constant: false,
+                        documentation: None,
+                        function_selector: None,
+                        indexed: None,
+                        mutability: Some(Mutability::Mutable),
+                        name: String::new(),
+                        name_location: None,
+                        overrides: None,
+                        scope: self.scope(),
+                        state_variable: false,
+                        storage_location,
+                        type_descriptions: empty_type_descriptions(),
+                        type_name: Some(type_name),
+                        value: None,
+                        visibility: Visibility::Internal,
+                        src: self.src(parameter_start),
+                        id,
+                    }
+                }
+            };
+
+            parameter.indexed = indexed;
+            parameters.push(parameter);
+
+            if !self.eat(",") {
+                break;
+            }
+        }
+
+        self.expect(")")?;
+
+        Ok(ParameterList {
+            parameters,
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_storage_location(&mut self) -> StorageLocation {
+        let storage_location = match self.token().text.as_str() {
+            "memory" => StorageLocation::Memory,
+            "storage" => StorageLocation::Storage,
+            "calldata" => StorageLocation::Calldata,
+            _ => return StorageLocation::Default,
+        };
+
+        self.advance();
+        storage_location
+    }
+
+    fn variable_declaration(
+        &mut self,
+        start: usize,
+        type_name: TypeName,
+        name: &Token,
+        storage_location: StorageLocation,
+        value: Option<Expression>,
+    ) -> VariableDeclaration {
+        VariableDeclaration {
+            base_functions: None,
+            constant: false,
+            documentation: None,
+            function_selector: None,
+            indexed: None,
+            mutability: Some(Mutability::Mutable),
+            name: name.text.clone(),
+            name_location: Some(self.token_src(name)),
+            overrides: None,
+            scope: self.scope(),
+            state_variable: false,
+            storage_location,
+            type_descriptions: empty_type_descriptions(),
+            type_name: Some(type_name),
+            value,
+            visibility: Visibility::Internal,
+            src: self.src(start),
+            id: self.next_id(),
+        }
+    }
+
+    fn parse_state_variable_declaration(&mut self, is_file_level: bool) -> io::Result<VariableDeclaration> {
+        let first_token = self.token().clone();
+        let documentation = self.documentation(&first_token);
+
+        let type_name = self.parse_type_name()?;
+
+        let mut visibility = Visibility::Internal;
+        let mut mutability = Mutability::Mutable;
+        let mut overrides = None;
+
+        loop {
+            match self.token().text.as_str() {
+                "public" => visibility = Visibility::Public,
+                "private" => visibility = Visibility::Private,
+                "internal" => visibility = Visibility::Internal,
+                "constant" => mutability = Mutability::Constant,
+                "immutable" => mutability = Mutability::Immutable,
+
+                "override" => {
+                    overrides = Some(self.parse_override_specifier()?);
+                    continue;
+                }
+
+                _ => break,
+            }
+
+            self.advance();
+        }
+
+        let name = self.expect_identifier()?;
+
+        let value = if self.eat("=") {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        self.expect(";")?;
+
+        let mut variable_declaration = self.variable_declaration(first_token.start, type_name, &name, StorageLocation::Default, value);
+
+        variable_declaration.documentation = documentation;
+        variable_declaration.constant = mutability == Mutability::Constant;
+        variable_declaration.mutability = Some(mutability);
+        variable_declaration.overrides = overrides;
+        variable_declaration.state_variable = !is_file_level;
+        variable_declaration.visibility = visibility;
+
+        Ok(variable_declaration)
+    }
+
+    //
+    // Type names
+    //
+
+    fn parse_type_name(&mut self) -> io::Result<TypeName> {
+        let mut type_name = if self.at("mapping") {
+            self.advance();
+            self.expect("(")?;
+
+            let key_type = self.parse_type_name()?;
+
+            if self.at_identifier() {
+                self.advance();
+            }
+
+            self.expect("=>")?;
+
+            let value_type = self.parse_type_name()?;
+
+            if self.at_identifier() {
+                self.advance();
+            }
+
+            self.expect(")")?;
+
+            TypeName::Mapping(Mapping {
+                key_type: Box::new(key_type),
+                value_type: Box::new(value_type),
+                type_descriptions: empty_type_descriptions(),
+            })
+        } else if self.at("function") {
+            self.advance();
+
+            let parameter_types = self.parse_parameter_list(false)?;
+
+            let mut visibility = Visibility::Internal;
+            let mut state_mutability = StateMutability::NonPayable;
+
+            loop {
+                match self.token().text.as_str() {
+                    "internal" => visibility = Visibility::Internal,
+                    "external" => visibility = Visibility::External,
+                    "pure" => state_mutability = StateMutability::Pure,
+                    "view" => state_mutability = StateMutability::View,
+                    "payable" => state_mutability = StateMutability::Payable,
+                    _ => break,
+                }
+
+                self.advance();
+            }
+
+            let return_parameter_types = if self.at("returns") && self.peek_is(1, "(") {
+                self.advance();
+                self.parse_parameter_list(false)?
+            } else {
+                ParameterList {
+                    parameters: vec![],
+                    src: format!("{}:0:{}", self.previous_end(), self.file_index),
+                    id: self.next_id(),
+                }
+            };
+
+            TypeName::FunctionTypeName(FunctionTypeName {
+                visibility,
+                state_mutability,
+                parameter_types,
+                return_parameter_types,
+                type_descriptions: empty_type_descriptions(),
+            })
+        } else if self.at_identifier() && is_elementary_type_name(self.token().text.as_str()) {
+            let name = self.advance().text;
+
+            let state_mutability = if name == "address" {
+                if self.eat("payable") {
+                    Some(StateMutability::Payable)
+                } else {
+                    Some(StateMutability::NonPayable)
+                }
+            } else {
+                None
+            };
+
+            TypeName::ElementaryTypeName(ElementaryTypeName {
+                state_mutability,
+                name,
+                type_descriptions: empty_type_descriptions(),
+            })
+        } else if self.at_identifier() {
+            let path_node = self.parse_identifier_path()?;
+
+            TypeName::UserDefinedTypeName(UserDefinedTypeName {
+                path_node: Some(path_node),
+                referenced_declaration: 0,
+                name: None,
+                type_descriptions: empty_type_descriptions(),
+            })
+        } else {
+            return Err(self.unexpected("type name"));
+        };
+
+        while self.at("[") {
+            self.advance();
+
+            let length = if self.at("]") {
+                None
+            } else {
+                match self.parse_expression()? {
+                    Expression::Literal(literal) => Some(literal),
+                    _ => None,
+                }
+            };
+
+            self.expect("]")?;
+
+            type_name = TypeName::ArrayTypeName(ArrayTypeName {
+                base_type: Box::new(type_name),
+                length,
+                type_descriptions: empty_type_descriptions(),
+            });
+        }
+
+        Ok(type_name)
+    }
+
+    //
+    // Statements
+    //
+
+    fn parse_block(&mut self) -> io::Result<Block> {
+        let start = self.expect("{")?.start;
+
+        let id = self.next_id();
+        self.scopes.push(id);
+
+        let mut statements = vec![];
+
+        while !self.at("}") {
+            if self.token().kind == TokenKind::EndOfFile {
+                return Err(self.unexpected("'}'"));
+            }
+
+            statements.push(self.parse_statement()?);
+        }
+
+        self.scopes.pop();
+        self.expect("}")?;
+
+        Ok(Block {
+            statements,
+            src: self.src(start),
+            id,
+        })
+    }
+
+    fn parse_block_or_statement(&mut self) -> io::Result<BlockOrStatement> {
+        if self.at("{") {
+            Ok(BlockOrStatement::Block(Box::new(self.parse_block()?)))
+        } else {
+            Ok(BlockOrStatement::Statement(Box::new(self.parse_statement()?)))
+        }
+    }
+
+    fn unhandled_node(&mut self, node_type: NodeType, start: usize) -> UnhandledNode {
+        UnhandledNode::new(
+            format!("{:?}", node_type).as_str(),
+            Some(self.src(start)),
+            Some(self.next_id()),
+        )
+    }
+
+    fn parse_statement(&mut self) -> io::Result<Statement> {
+        let start = self.token().start;
+
+        match self.token().text.as_str() {
+            "{" if self.token().kind == TokenKind::Punctuation => {
+                Ok(Statement::Block(self.parse_block()?))
+            }
+
+            "unchecked" if self.peek_is(1, "{") => {
+                self.advance();
+                Ok(Statement::UncheckedBlock(self.parse_block()?))
+            }
+
+            "if" => {
+                self.advance();
+                self.expect("(")?;
+                let condition = self.parse_expression()?;
+                self.expect(")")?;
+
+                let true_body = self.parse_block_or_statement()?;
+
+                let false_body = if self.eat("else") {
+                    Some(self.parse_block_or_statement()?)
+                } else {
+                    None
+                };
+
+                Ok(Statement::IfStatement(IfStatement {
+                    condition,
+                    true_body,
+                    false_body,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "for" => {
+                self.advance();
+                self.expect("(")?;
+
+                let id = self.next_id();
+                self.scopes.push(id);
+
+                let initialization_expression = if self.eat(";") {
+                    None
+                } else {
+                    Some(Box::new(self.parse_simple_statement()?))
+                };
+
+                let condition = if self.at(";") {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+
+                self.expect(";")?;
+
+                let loop_expression = if self.at(")") {
+                    None
+                } else {
+                    let start = self.token().start;
+                    let expression = self.parse_expression()?;
+
+                    Some(Box::new(Statement::ExpressionStatement(ExpressionStatement {
+                        expression,
+                        src: self.src(start),
+                        id: self.next_id(),
+                    })))
+                };
+
+                self.expect(")")?;
+
+                let body = self.parse_block_or_statement()?;
+
+                self.scopes.pop();
+
+                Ok(Statement::ForStatement(ForStatement {
+                    initialization_expression,
+                    condition,
+                    loop_expression,
+                    body,
+                    src: self.src(start),
+                    id,
+                }))
+            }
+
+            "while" => {
+                self.advance();
+                self.expect("(")?;
+                let condition = self.parse_expression()?;
+                self.expect(")")?;
+
+                let body = self.parse_block_or_statement()?;
+
+                Ok(Statement::WhileStatement(WhileStatement {
+                    condition,
+                    body,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "do" => {
+                self.advance();
+                let body = self.parse_block_or_statement()?;
+                self.expect("while")?;
+                self.expect("(")?;
+                let condition = self.parse_expression()?;
+                self.expect(")")?;
+                self.expect(";")?;
+
+                Ok(Statement::DoWhileStatement(DoWhileStatement {
+                    condition,
+                    body,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "break" if self.peek_is(1, ";") => {
+                self.advance();
+                self.advance();
+
+                Ok(Statement::Break(Break {
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "continue" if self.peek_is(1, ";") => {
+                self.advance();
+                self.advance();
+
+                Ok(Statement::Continue(Continue {
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "throw" if self.peek_is(1, ";") => {
+                self.advance();
+                self.advance();
+                Ok(Statement::UnhandledStatement(self.unhandled_node(NodeType::Throw, start)))
+            }
+
+            "_" if self.peek_is(1, ";") => {
+                self.advance();
+                self.advance();
+
+                Ok(Statement::PlaceholderStatement(PlaceholderStatement {
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "return" => {
+                self.advance();
+
+                let expression = if self.at(";") {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+
+                self.expect(";")?;
+
+                Ok(Statement::Return(Return {
+                    function_return_parameters: self.return_parameters.unwrap_or(0),
+                    expression,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "emit" => {
+                let start = self.advance().start;
+                let event_call = self.parse_expression()?;
+                self.expect(";")?;
+
+                Ok(Statement::EmitStatement(EmitStatement {
+                    event_call,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "revert" if self.peek(1).kind == TokenKind::Identifier => {
+                let start = self.advance().start;
+
+                let error_call = match self.parse_expression()? {
+                    Expression::FunctionCall(function_call) => function_call,
+                    _ => return Err(self.error("Expected error call in revert statement".to_string())),
+                };
+
+                self.expect(";")?;
+
+                Ok(Statement::RevertStatement(RevertStatement {
+                    error_call,
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            "try" => self.parse_try_statement(),
+
+            "assembly" => self.parse_inline_assembly(),
+
+            _ => {
+                let statement = self.parse_simple_statement()?;
+                Ok(statement)
+            }
+        }
+    }
+
+    fn parse_try_statement(&mut self) -> io::Result<Statement> {
+        let start = self.expect("try")?.start;
+        let id = self.next_id();
+
+        let external_call = match self.parse_expression()? {
+            Expression::FunctionCall(mut function_call) => {
+                function_call.try_call = Some(true);
+                function_call
+            }
+
+            _ => return Err(self.error("Expected external function call in try statement".to_string())),
+        };
+
+        let mut clauses = vec![];
+
+        let clause_start = self.token().start;
+        let clause_id = self.next_id();
+        self.scopes.push(clause_id);
+
+        let parameters = if self.eat("returns") {
+            Some(self.parse_parameter_list(false)?)
+        } else {
+            None
+        };
+
+        clauses.push(TryCatchClause {
+            block: self.parse_block()?,
+            error_name: Some(String::new()),
+            parameters,
+            src: self.src(clause_start),
+            id: clause_id,
+        });
+
+        self.scopes.pop();
+
+        while self.at("catch") {
+            let clause_start = self.advance().start;
+            let clause_id = self.next_id();
+            self.scopes.push(clause_id);
+
+            let error_name = if self.at_identifier() {
+                self.advance().text
+            } else {
+                String::new()
+            };
+
+            let parameters = if self.at("(") {
+                Some(self.parse_parameter_list(false)?)
+            } else {
+                None
+            };
+
+            clauses.push(TryCatchClause {
+                block: self.parse_block()?,
+                error_name: Some(error_name),
+                parameters,
+                src: self.src(clause_start),
+                id: clause_id,
+            });
+
+            self.scopes.pop();
+        }
+
+        Ok(Statement::TryStatement(TryStatement {
+            clauses,
+            external_call,
+            src: self.src(start),
+            id,
+        }))
+    }
+
+    fn parse_inline_assembly(&mut self) -> io::Result<Statement> {
+        let start = self.expect("assembly")?.start;
+
+        if self.token().kind == TokenKind::String {
+            self.advance();
+        }
+
+        if self.at("(") {
+            self.skip_balanced("(", ")")?;
+        }
+
+        let body_start = self.token().start;
+        self.skip_balanced("{", "}")?;
+        let operations = self.source[body_start..self.previous_end()].to_string();
+
+        let ast = yul::parser::parse_block(operations.as_str(), body_start, self.file_index)
+            .map_err(|error| self.error_at(body_start, format!("Invalid inline assembly: {}", error)))?;
+
+        Ok(Statement::InlineAssembly(InlineAssembly {
+            ast: Some(ast),
+            evm_version: None,
+            external_references: vec![],
+            operations: Some(operations),
+            src: self.src(start),
+            id: self.next_id(),
+        }))
+    }
+
+    fn parse_simple_statement(&mut self) -> io::Result<Statement> {
+        let start = self.token().start;
+
+        if let Some(statement) = self.try_parse_variable_declaration_statement(start)? {
+            return Ok(statement);
+        }
+
+        let expression = self.parse_expression()?;
+        self.expect(";")?;
+
+        Ok(Statement::ExpressionStatement(ExpressionStatement {
+            expression,
+            src: self.src(start),
+            id: self.next_id(),
+        }))
+    }
+
+    fn try_parse_variable_declaration_statement(&mut self, start: usize) -> io::Result<Option<Statement>> {
+        let position = self.position;
+        let next_node_id = self.next_node_id;
+
+        let declarations = if self.at("(") {
+            self.try_parse_tuple_declarations()
+        } else {
+            self.try_parse_variable_declaration().map(|declaration| declaration.map(|declaration| vec![Some(declaration)]))
+        };
+
+        let declarations = match declarations {
+            Ok(Some(declarations)) if self.at("=") || (declarations.len() == 1 && self.at(";")) => declarations,
+
+            _ => {
+                self.position = position;
+                self.next_node_id = next_node_id;
+                return Ok(None);
+            }
+        };
+
+        let initial_value = if self.eat("=") {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        self.expect(";")?;
+
+        Ok(Some(Statement::VariableDeclarationStatement(VariableDeclarationStatement {
+            assignments: declarations.iter().map(|declaration| declaration.as_ref().map(|declaration| declaration.id)).collect(),
+            declarations,
+            initial_value,
+            src: self.src(start),
+            id: self.next_id(),
+        })))
+    }
+
+    fn try_parse_variable_declaration(&mut self) -> io::Result<Option<VariableDeclaration>> {
+        let start = self.token().start;
+
+        //
+        // Statement keywords are lexed as identifiers, so `delete x;` would otherwise parse as a declaration of `x`
+        //
+
+        if (!self.at_identifier() && !self.at("mapping")) || STATEMENT_KEYWORDS.contains(&self.token().text.as_str()) {
+            return Ok(None);
+        }
+
+        let type_name = self.parse_type_name()?;
+        let storage_location = self.parse_storage_location();
+
+        if !self.at_identifier() {
+            return Ok(None);
+        }
+
+        let name = self.advance();
+
+        Ok(Some(self.variable_declaration(start, type_name, &name, storage_location, None)))
+    }
+
+    fn try_parse_tuple_declarations(&mut self) -> io::Result<Option<Vec<Option<VariableDeclaration>>>> {
+        self.expect("(")?;
+
+        let mut declarations = vec![];
+
+        loop {
+            if self.at(",") || self.at(")") {
+                declarations.push(None);
+            } else {
+                match self.try_parse_variable_declaration()? {
+                    Some(declaration) => declarations.push(Some(declaration)),
+                    None => return Ok(None),
+                }
+            }
+
+            if !self.eat(",") {
+                break;
+            }
+        }
+
+        self.expect(")")?;
+
+        if declarations.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+
+        Ok(Some(declarations))
+    }
+
+    //
+    // Expressions
+    //
+
+    fn parse_expression(&mut self) -> io::Result<Expression> {
+        let start = self.token().start;
+        let expression = self.parse_binary_operation(0)?;
+
+        if self.eat("?") {
+            let true_expression = self.parse_expression()?;
+            self.expect(":")?;
+            let false_expression = self.parse_expression()?;
+
+            return Ok(Expression::Conditional(Conditional {
+                condition: Box::new(expression),
+                true_expression: Box::new(true_expression),
+                false_expression: Box::new(false_expression),
+                argument_types: None,
+                is_constant: false,
+                is_l_value: false,
+                is_pure: false,
+                l_value_requested: false,
+                type_descriptions: empty_type_descriptions(),
+                src: self.src(start),
+                id: self.next_id(),
+            }));
+        }
+
+        if self.token().kind == TokenKind::Punctuation && ASSIGNMENT_OPERATORS.contains(&self.token().text.as_str()) {
+            let operator = self.advance().text;
+            let right_hand_side = self.parse_expression()?;
+
+            return Ok(Expression::Assignment(Assignment {
+                left_hand_side: Box::new(expression),
+                right_hand_side: Box::new(right_hand_side),
+                operator,
+                argument_types: None,
+                is_constant: false,
+                is_l_value: false,
+                is_pure: false,
+                l_value_requested: false,
+                type_descriptions: empty_type_descriptions(),
+                src: self.src(start),
+                id: self.next_id(),
+            }));
+        }
+
+        Ok(expression)
+    }
+
+    fn binary_operator_precedence(&self) -> Option<usize> {
+        if self.token().kind != TokenKind::Punctuation {
+            return None;
+        }
+
+        Some(match self.token().text.as_str() {
+            "||" => 1,
+            "&&" => 2,
+            "==" | "!=" => 3,
+            "<" | ">" | "<=" | ">=" => 4,
+            "|" => 5,
+            "^" => 6,
+            "&" => 7,
+            "<<" | ">>" | ">>>" => 8,
+            "+" | "-" => 9,
+            "*" | "/" | "%" => 10,
+            "**" => 11,
+            _ => return None,
+        })
+    }
+
+    fn parse_binary_operation(&mut self, minimum_precedence: usize) -> io::Result<Expression> {
+        let start = self.token().start;
+        let mut expression = self.parse_unary_operation()?;
+
+        while let Some(precedence) = self.binary_operator_precedence() {
+            if precedence < minimum_precedence {
+                break;
+            }
+
+            let operator = self.advance().text;
+
+            let right_expression = if operator == "**" {
+                self.parse_binary_operation(precedence)?
+            } else {
+                self.parse_binary_operation(precedence + 1)?
+            };
+
+            expression = Expression::BinaryOperation(BinaryOperation {
+                common_type: empty_type_descriptions(),
+                left_expression: Box::new(expression),
+                right_expression: Box::new(right_expression),
+                operator,
+                function: None,
+                argument_types: None,
+                is_constant: false,
+                is_l_value: false,
+                is_pure: false,
+                l_value_requested: false,
+                type_descriptions: empty_type_descriptions(),
+                src: self.src(start),
+                id: self.next_id(),
+            });
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_unary_operation(&mut self) -> io::Result<Expression> {
+        let start = self.token().start;
+
+        let is_prefix_operator = match self.token().kind {
+            TokenKind::Punctuation => matches!(self.token().text.as_str(), "!" | "~" | "-" | "+" | "++" | "--"),
+            TokenKind::Identifier => self.token().text == "delete",
+            _ => false,
+        };
+
+        if is_prefix_operator {
+            let operator = self.advance().text;
+            let sub_expression = self.parse_unary_operation()?;
+
+            return Ok(Expression::UnaryOperation(UnaryOperation {
+                prefix: true,
+                sub_expression: Box::new(sub_expression),
+                operator,
+                function: None,
+                argument_types: None,
+                is_constant: false,
+                is_l_value: false,
+                is_pure: false,
+                l_value_requested: false,
+                type_descriptions: empty_type_descriptions(),
+                src: self.src(start),
+                id: self.next_id(),
+            }));
+        }
+
+        let mut expression = self.parse_primary_expression()?;
+
+        loop {
+            if self.at("++") || self.at("--") {
+                let operator = self.advance().text;
+
+                expression = Expression::UnaryOperation(UnaryOperation {
+                    prefix: false,
+                    sub_expression: Box::new(expression),
+                    operator,
+                    function: None,
+                    argument_types: None,
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: empty_type_descriptions(),
+                    src: self.src(start),
+                    id: self.next_id(),
+                });
+            } else if self.at(".") {
+                self.advance();
+
+                let member_name = self.advance();
+
+                if member_name.kind != TokenKind::Identifier {
+                    return Err(self.error(format!("Expected member name, found '{}'", member_name.text)));
+                }
+
+                expression = Expression::MemberAccess(MemberAccess {
+                    member_name: member_name.text,
+                    expression: Box::new(expression),
+                    referenced_declaration: None,
+                    argument_types: None,
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: empty_type_descriptions(),
+                    src: self.src(start),
+                    id: self.next_id(),
+                });
+            } else if self.at("[") {
+                self.advance();
+
+                let start_expression = if self.at("]") || self.at(":") {
+                    None
+                } else {
+                    Some(Box::new(self.parse_expression()?))
+                };
+
+                if self.eat(":") {
+                    let end_expression = if self.at("]") {
+                        None
+                    } else {
+                        Some(Box::new(self.parse_expression()?))
+                    };
+
+                    self.expect("]")?;
+
+                    expression = Expression::IndexRangeAccess(IndexRangeAccess {
+                        base_expression: Box::new(expression),
+                        start_expression,
+                        end_expression,
+                        is_constant: false,
+                        is_l_value: false,
+                        is_pure: false,
+                        l_value_requested: false,
+                        type_descriptions: empty_type_descriptions(),
+                        src: self.src(start),
+                        id: self.next_id(),
+                    });
+                } else {
+                    self.expect("]")?;
+
+                    expression = match start_expression {
+                        Some(index_expression) => Expression::IndexAccess(IndexAccess {
+                            base_expression: Box::new(expression),
+                            index_expression,
+                            argument_types: None,
+                            is_constant: false,
+                            is_l_value: false,
+                            is_pure: false,
+                            l_value_requested: false,
+                            type_descriptions: empty_type_descriptions(),
+                            src: self.src(start),
+                            id: self.next_id(),
+                        }),
+
+                        None => Expression::UnhandledExpression(self.unhandled_node(NodeType::IndexAccess, start)),
+                    };
+                }
+            } else if self.at("(") {
+                let (names, arguments) = self.parse_call_arguments()?;
+
+                expression = Expression::FunctionCall(FunctionCall {
+                    kind: FunctionCallKind::FunctionCall,
+                    try_call: Some(false),
+                    names,
+                    arguments,
+                    expression: Box::new(expression),
+                    argument_types: None,
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: empty_type_descriptions(),
+                    src: self.src(start),
+                    id: self.next_id(),
+                });
+            } else if self.at("{") && self.peek(1).kind == TokenKind::Identifier && self.peek_is(2, ":") {
+                self.advance();
+
+                let mut names = vec![];
+                let mut options = vec![];
+
+                while !self.at("}") {
+                    names.push(self.expect_identifier()?.text);
+                    self.expect(":")?;
+                    options.push(self.parse_expression()?);
+
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+
+                self.expect("}")?;
+
+                expression = Expression::FunctionCallOptions(FunctionCallOptions {
+                    names,
+                    options,
+                    arguments: None,
+                    argument_types: None,
+                    expression: Box::new(expression),
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: empty_type_descriptions(),
+                    src: self.src(start),
+                    id: self.next_id(),
+                });
+            } else {
+                break;
+            }
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_call_arguments(&mut self) -> io::Result<(Vec<String>, Vec<Expression>)> {
+        self.expect("(")?;
+
+        let mut names = vec![];
+        let mut arguments = vec![];
+
+        if self.at("{") {
+            self.advance();
+
+            while !self.at("}") {
+                names.push(self.expect_identifier()?.text);
+                self.expect(":")?;
+                arguments.push(self.parse_expression()?);
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+
+            self.expect("}")?;
+        } else {
+            while !self.at(")") {
+                arguments.push(self.parse_expression()?);
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+        }
+
+        self.expect(")")?;
+
+        Ok((names, arguments))
+    }
+
+    fn literal(&mut self, start: usize, kind: LiteralKind, value: Option<String>, hex_value: String, subdenomination: Option<String>) -> Expression {
+        let type_string = match kind {
+            LiteralKind::Bool => "bool".to_string(),
+            LiteralKind::Number => format!("int_const {}", value.as_deref().unwrap_or("")),
+            LiteralKind::Address => "address".to_string(),
+            LiteralKind::String => format!("literal_string \"{}\"", value.as_deref().unwrap_or("")),
+            LiteralKind::HexString => format!("literal_string hex\"{}\"", hex_value),
+        };
+
+        Expression::Literal(Literal {
+            hex_value: Some(hex_value),
+            value,
+            subdenomination,
+            kind,
+            argument_types: None,
+            is_constant: false,
+            is_l_value: false,
+            is_pure: true,
+            l_value_requested: false,
+            type_descriptions: TypeDescriptions {
+                type_identifier: None,
+                type_string: Some(type_string),
+            },
+            src: self.src(start),
+            id: self.next_id(),
+        })
+    }
+
+    fn parse_primary_expression(&mut self) -> io::Result<Expression> {
+        let token = self.token().clone();
+        let start = token.start;
+
+        match token.kind {
+            TokenKind::Number => {
+                self.advance();
+
+                let subdenomination = if self.at_identifier() && SUBDENOMINATIONS.contains(&self.token().text.as_str()) {
+                    Some(self.advance().text)
+                } else {
+                    None
+                };
+
+                let kind = if token.text.len() == 42 && token.text.starts_with("0x") && token.text.chars().any(|c| c.is_ascii_uppercase()) {
+                    LiteralKind::Address
+                } else {
+                    LiteralKind::Number
+                };
+
+                let hex_value = hex_encode(token.text.as_bytes());
+                Ok(self.literal(start, kind, Some(token.text), hex_value, subdenomination))
+            }
+
+            TokenKind::String | TokenKind::UnicodeString | TokenKind::HexString => {
+                let mut bytes = vec![];
+
+                while matches!(self.token().kind, TokenKind::String | TokenKind::UnicodeString | TokenKind::HexString) {
+                    bytes.extend(self.advance().value.unwrap_or_default());
+                }
+
+                let kind = if token.kind == TokenKind::HexString {
+                    LiteralKind::HexString
+                } else {
+                    LiteralKind::String
+                };
+
+                let hex_value = hex_encode(bytes.as_slice());
+                let value = String::from_utf8(bytes).ok();
+
+                Ok(self.literal(start, kind, value, hex_value, None))
+            }
+
+            TokenKind::Punctuation if token.text == "(" || token.text == "[" => {
+                let is_inline_array = token.text == "[";
+                let close = if is_inline_array { "]" } else { ")" };
+
+                self.advance();
+
+                let mut components = vec![];
+
+                loop {
+                    if self.at(",") || self.at(close) {
+                        components.push(None);
+                    } else {
+                        components.push(Some(self.parse_expression()?));
+                    }
+
+                    if !self.eat(",") {
+                        break;
+                    }
+                }
+
+                self.expect(close)?;
+
+                if components.len() == 1 && components[0].is_none() {
+                    components.clear();
+                }
+
+                Ok(Expression::TupleExpression(TupleExpression {
+                    components,
+                    argument_types: None,
+                    is_inline_array,
+                    is_constant: false,
+                    is_l_value: false,
+                    is_pure: false,
+                    l_value_requested: false,
+                    type_descriptions: empty_type_descriptions(),
+                    src: self.src(start),
+                    id: self.next_id(),
+                }))
+            }
+
+            TokenKind::Identifier => match token.text.as_str() {
+                "true" | "false" => {
+                    self.advance();
+                    let hex_value = hex_encode(token.text.as_bytes());
+                    Ok(self.literal(start, LiteralKind::Bool, Some(token.text), hex_value, None))
+                }
+
+                "new" => {
+                    self.advance();
+                    let type_name = self.parse_type_name()?;
+
+                    Ok(Expression::NewExpression(NewExpression {
+                        argument_types: None,
+                        type_descriptions: empty_type_descriptions(),
+                        type_name,
+                        is_constant: false,
+                        is_l_value: false,
+                        is_pure: false,
+                        l_value_requested: false,
+                        src: self.src(start),
+                        id: self.next_id(),
+                    }))
+                }
+
+                "payable" if self.peek_is(1, "(") => {
+                    self.advance();
+
+                    Ok(Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression {
+                        type_name: TypeName::ElementaryTypeName(ElementaryTypeName {
+                            state_mutability: Some(StateMutability::Payable),
+                            name: "address".to_string(),
+                            type_descriptions: empty_type_descriptions(),
+                        }),
+                        argument_types: None,
+                        is_constant: false,
+                        is_l_value: false,
+                        is_pure: true,
+                        l_value_requested: false,
+                        type_descriptions: empty_type_descriptions(),
+                        src: self.src(start),
+                        id: self.next_id(),
+                    }))
+                }
+
+                name if is_elementary_type_name(name) => {
+                    let type_name = self.parse_type_name()?;
+
+                    if let TypeName::ArrayTypeName(_) = type_name {
+                        return Ok(Expression::UnhandledExpression(self.unhandled_node(NodeType::IndexAccess, start)));
+                    }
+
+                    Ok(Expression::ElementaryTypeNameExpression(ElementaryTypeNameExpression {
+                        type_name,
+                        argument_types: None,
+                        is_constant: false,
+                        is_l_value: false,
+                        is_pure: true,
+                        l_value_requested: false,
+                        type_descriptions: empty_type_descriptions(),
+                        src: self.src(start),
+                        id: self.next_id(),
+                    }))
+                }
+
+                _ => {
+                    self.advance();
+
+                    Ok(Expression::Identifier(Identifier {
+                        argument_types: None,
+                        name: token.text,
+                        overloaded_declarations: vec![],
+                        referenced_declaration: 0,
+                        type_descriptions: empty_type_descriptions(),
+                        src: self.src(start),
+                        id: self.next_id(),
+                    }))
+                }
+            },
+
+            _ => Err(self.unexpected("expression")),
+        }
+    }
+}
@@ -0,0 +1,1595 @@
+use crate::ast::*;
+use eth_lang_utils::ast::*;
+use std::collections::{HashMap, HashSet};
+
+const BUILTINS: &[(&str, NodeID, &str)] = &[
+    ("abi", -1, "abi"),
+    ("addmod", -2, "function (uint256,uint256,uint256) pure returns (uint256)"),
+    ("assert", -3, "function (bool) pure"),
+    ("block", -4, "block"),
+    ("blockhash", -5, "function (uint256) view returns (bytes32)"),
+    ("ecrecover", -6, "function (bytes32,uint8,bytes32,bytes32) pure returns (address)"),
+    ("gasleft", -7, "function () view returns (uint256)"),
+    ("keccak256", -8, "function (bytes memory) pure returns (bytes32)"),
+    ("msg", -15, "msg"),
+    ("mulmod", -16, "function (uint256,uint256,uint256) pure returns (uint256)"),
+    ("now", -17, "uint256"),
+    ("require", -18, "function (bool) pure"),
+    ("revert", -19, "function () pure"),
+    ("ripemd160", -20, "function (bytes memory) pure returns (bytes20)"),
+    ("selfdestruct", -21, "function (address payable)"),
+    ("sha256", -22, "function (bytes memory) pure returns (bytes32)"),
+    ("super", -25, "super"),
+    ("tx", -26, "tx"),
+    ("type", -27, "function () pure"),
+    ("this", -28, "this"),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+enum DeclarationKind {
+    Contract(ContractKind),
+    Struct,
+    Enum,
+    EnumValue,
+    UserDefinedValueType,
+    Event,
+    Error,
+    Function,
+    Modifier,
+    Variable,
+    Import(NodeID),
+}
+
+#[derive(Clone, Debug)]
+struct Declaration {
+    name: String,
+    kind: DeclarationKind,
+    unit_id: NodeID,
+    contract_id: Option<NodeID>,
+    canonical_name: String,
+    type_name: Option<TypeName>,
+    storage_location: StorageLocation,
+    state_variable: bool,
+    parameters: Vec<(TypeName, StorageLocation)>,
+    return_parameters: Vec<(TypeName, StorageLocation)>,
+    state_mutability: Option<StateMutability>,
+    visibility: Option<Visibility>,
+    implemented: bool,
+}
+
+impl Declaration {
+    fn new(name: &str, kind: DeclarationKind, unit_id: NodeID, contract_id: Option<NodeID>, contract_name: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            unit_id,
+            contract_id,
+            canonical_name: match contract_name {
+                Some(contract_name) => format!("{}.{}", contract_name, name),
+                None => name.to_string(),
+            },
+            type_name: None,
+            storage_location: StorageLocation::Default,
+            state_variable: false,
+            parameters: vec![],
+            return_parameters: vec![],
+            state_mutability: None,
+            visibility: None,
+            implemented: true,
+        }
+    }
+
+    fn is_callable(&self) -> bool {
+        matches!(self.kind, DeclarationKind::Function | DeclarationKind::Event | DeclarationKind::Error | DeclarationKind::Modifier)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Type(NodeID),
+    Instance(NodeID),
+    Typed(Box<TypeName>, NodeID, Option<NodeID>),
+    Function(NodeID),
+    Unit(NodeID),
+    Super,
+}
+
+fn parameters(parameter_list: &ParameterList) -> Vec<(TypeName, StorageLocation)> {
+    parameter_list.parameters
+        .iter()
+        .filter_map(|parameter| Some((parameter.type_name.clone()?, parameter.storage_location)))
+        .collect()
+}
+
+fn normalized_elementary_type_name(name: &str) -> &str {
+    match name {
+        "uint" => "uint256",
+        "int" => "int256",
+        "byte" => "bytes1",
+        "fixed" => "fixed128x18",
+        "ufixed" => "ufixed128x18",
+        name => name,
+    }
+}
+
+pub fn resolve(source_units: &mut [SourceUnit]) {
+    let mut resolver = Resolver::default();
+
+    for source_unit in source_units.iter() {
+        resolver.collect_source_unit(source_unit);
+    }
+
+    resolver.collect_imports(source_units);
+
+    for source_unit in source_units.iter_mut() {
+        resolver.resolve_using_for_directives(source_unit);
+    }
+
+    for source_unit in source_units.iter_mut() {
+        resolver.resolve_source_unit(source_unit);
+    }
+}
+
+#[derive(Default)]
+struct Resolver {
+    declarations: HashMap<NodeID, Declaration>,
+    unit_ids: HashMap<String, NodeID>,
+    unit_definitions: HashMap<NodeID, Vec<(String, NodeID)>>,
+    unit_symbols: HashMap<NodeID, Vec<(String, NodeID)>>,
+    members: HashMap<NodeID, Vec<(String, NodeID)>>,
+    base_names: HashMap<NodeID, Vec<String>>,
+    linearizations: HashMap<NodeID, Vec<NodeID>>,
+    using_for_libraries: HashMap<NodeID, Vec<NodeID>>,
+    using_for_functions: HashMap<NodeID, Vec<NodeID>>,
+    using_for_operators: HashMap<NodeID, Vec<(String, NodeID)>>,
+
+    unit_id: NodeID,
+    contract_id: Option<NodeID>,
+    locals: Vec<Vec<(String, NodeID)>>,
+}
+
+impl Resolver {
+    //
+    // Declaration collection
+    //
+
+    fn declare(&mut self, id: NodeID, declaration: Declaration) {
+        self.declarations.insert(id, declaration);
+    }
+
+    fn declare_function_like(
+        &mut self,
+        id: NodeID,
+        name: &str,
+        kind: DeclarationKind,
+        contract: Option<(NodeID, &str)>,
+        parameter_list: &ParameterList,
+    ) -> &mut Declaration {
+        let mut declaration = Declaration::new(name, kind, self.unit_id, contract.map(|(id, _)| id), contract.map(|(_, name)| name));
+        declaration.parameters = parameters(parameter_list);
+        self.declare(id, declaration);
+        self.declarations.get_mut(&id).unwrap()
+    }
+
+    fn declare_variable(&mut self, variable_declaration: &VariableDeclaration, contract: Option<(NodeID, &str)>) {
+        let mut declaration = Declaration::new(
+            variable_declaration.name.as_str(),
+            DeclarationKind::Variable,
+            self.unit_id,
+            contract.map(|(id, _)| id),
+            contract.map(|(_, name)| name),
+        );
+
+        declaration.type_name = variable_declaration.type_name.clone();
+        declaration.storage_location = variable_declaration.storage_location;
+        declaration.state_variable = variable_declaration.state_variable;
+        declaration.visibility = Some(variable_declaration.visibility);
+
+        self.declare(variable_declaration.id, declaration);
+    }
+
+    fn collect_struct_definition(&mut self, struct_definition: &StructDefinition, contract: Option<(NodeID, &str)>) {
+        let declaration = Declaration::new(
+            struct_definition.name.as_str(),
+            DeclarationKind::Struct,
+            self.unit_id,
+            contract.map(|(id, _)| id),
+            contract.map(|(_, name)| name),
+        );
+
+        let canonical_name = declaration.canonical_name.clone();
+        self.declare(struct_definition.id, declaration);
+
+        let mut members = vec![];
+
+        for member in struct_definition.members.iter() {
+            self.declare_variable(member, Some((struct_definition.id, canonical_name.as_str())));
+            members.push((member.name.clone(), member.id));
+        }
+
+        self.members.insert(struct_definition.id, members);
+    }
+
+    fn collect_enum_definition(&mut self, enum_definition: &EnumDefinition, contract: Option<(NodeID, &str)>) {
+        let declaration = Declaration::new(
+            enum_definition.name.as_str(),
+            DeclarationKind::Enum,
+            self.unit_id,
+            contract.map(|(id, _)| id),
+            contract.map(|(_, name)| name),
+        );
+
+        let canonical_name = declaration.canonical_name.clone();
+        self.declare(enum_definition.id, declaration);
+
+        let mut members = vec![];
+
+        for member in enum_definition.members.iter() {
+            let declaration = Declaration::new(
+                member.name.as_str(),
+                DeclarationKind::EnumValue,
+                self.unit_id,
+                Some(enum_definition.id),
+                Some(canonical_name.as_str()),
+            );
+
+            self.declare(member.id, declaration);
+            members.push((member.name.clone(), member.id));
+        }
+
+        self.members.insert(enum_definition.id, members);
+    }
+
+    fn collect_user_defined_value_type_definition(&mut self, definition: &UserDefinedValueTypeDefinition, contract: Option<(NodeID, &str)>) {
+        let mut declaration = Declaration::new(
+            definition.name.as_str(),
+            DeclarationKind::UserDefinedValueType,
+            self.unit_id,
+            contract.map(|(id, _)| id),
+            contract.map(|(_, name)| name),
+        );
+
+        declaration.type_name = Some(definition.underlying_type.clone());
+        self.declare(definition.id, declaration);
+    }
+
+    fn collect_source_unit(&mut self, source_unit: &SourceUnit) {
+        self.unit_id = source_unit.id;
+
+        if let Some(absolute_path) = source_unit.absolute_path.as_ref() {
+            self.unit_ids.insert(absolute_path.clone(), source_unit.id);
+        }
+
+        let mut definitions = vec![];
+
+        for node in source_unit.nodes.iter() {
+            match node {
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    self.collect_contract_definition(contract_definition);
+                    definitions.push((contract_definition.name.clone(), contract_definition.id));
+                }
+
+                SourceUnitNode::StructDefinition(struct_definition) => {
+                    self.collect_struct_definition(struct_definition, None);
+                    definitions.push((struct_definition.name.clone(), struct_definition.id));
+                }
+
+                SourceUnitNode::EnumDefinition(enum_definition) => {
+                    self.collect_enum_definition(enum_definition, None);
+                    definitions.push((enum_definition.name.clone(), enum_definition.id));
+                }
+
+                SourceUnitNode::ErrorDefinition(error_definition) => {
+                    self.declare_function_like(error_definition.id, error_definition.name.as_str(), DeclarationKind::Error, None, &error_definition.parameters);
+                    definitions.push((error_definition.name.clone(), error_definition.id));
+                }
+
+                SourceUnitNode::EventDefinition(event_definition) => {
+                    self.declare_function_like(event_definition.id, event_definition.name.as_str(), DeclarationKind::Event, None, &event_definition.parameters);
+                    definitions.push((event_definition.name.clone(), event_definition.id));
+                }
+
+                SourceUnitNode::FunctionDefinition(function_definition) => {
+                    let declaration = self.declare_function_like(function_definition.id, function_definition.name.as_str(), DeclarationKind::Function, None, &function_definition.parameters);
+                    declaration.return_parameters = parameters(&function_definition.return_parameters);
+                    declaration.state_mutability = Some(function_definition.state_mutability);
+                    declaration.visibility = Some(function_definition.visibility);
+                    declaration.implemented = function_definition.implemented;
+                    definitions.push((function_definition.name.clone(), function_definition.id));
+                }
+
+                SourceUnitNode::VariableDeclaration(variable_declaration) => {
+                    self.declare_variable(variable_declaration, None);
+                    definitions.push((variable_declaration.name.clone(), variable_declaration.id));
+                }
+
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.collect_user_defined_value_type_definition(definition, None);
+                    definitions.push((definition.name.clone(), definition.id));
+                }
+
+                _ => {}
+            }
+        }
+
+        self.unit_definitions.insert(source_unit.id, definitions);
+    }
+
+    fn collect_contract_definition(&mut self, contract_definition: &ContractDefinition) {
+        let contract = Some((contract_definition.id, contract_definition.name.as_str()));
+
+        let mut declaration = Declaration::new(
+            contract_definition.name.as_str(),
+            DeclarationKind::Contract(contract_definition.kind.clone()),
+            self.unit_id,
+            None,
+            None,
+        );
+
+        declaration.implemented = !contract_definition.is_abstract.unwrap_or(false);
+        self.declare(contract_definition.id, declaration);
+
+        self.base_names.insert(
+            contract_definition.id,
+            contract_definition.base_contracts.iter().map(|base| base.base_name.name.clone()).collect(),
+        );
+
+        let mut members = vec![];
+
+        for node in contract_definition.nodes.iter() {
+            match node {
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    self.collect_struct_definition(struct_definition, contract);
+                    members.push((struct_definition.name.clone(), struct_definition.id));
+                }
+
+                ContractDefinitionNode::EnumDefinition(enum_definition) => {
+                    self.collect_enum_definition(enum_definition, contract);
+                    members.push((enum_definition.name.clone(), enum_definition.id));
+                }
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => {
+                    self.declare_variable(variable_declaration, contract);
+                    members.push((variable_declaration.name.clone(), variable_declaration.id));
+                }
+
+                ContractDefinitionNode::EventDefinition(event_definition) => {
+                    self.declare_function_like(event_definition.id, event_definition.name.as_str(), DeclarationKind::Event, contract, &event_definition.parameters);
+                    members.push((event_definition.name.clone(), event_definition.id));
+                }
+
+                ContractDefinitionNode::ErrorDefinition(error_definition) => {
+                    self.declare_function_like(error_definition.id, error_definition.name.as_str(), DeclarationKind::Error, contract, &error_definition.parameters);
+                    members.push((error_definition.name.clone(), error_definition.id));
+                }
+
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => {
+                    let declaration = self.declare_function_like(modifier_definition.id, modifier_definition.name.as_str(), DeclarationKind::Modifier, contract, &modifier_definition.parameters);
+                    declaration.implemented = modifier_definition.body.src.split(':').nth(1) != Some("1");
+                    members.push((modifier_definition.name.clone(), modifier_definition.id));
+                }
+
+                ContractDefinitionNode::FunctionDefinition(function_definition) => {
+                    let declaration = self.declare_function_like(function_definition.id, function_definition.name.as_str(), DeclarationKind::Function, contract, &function_definition.parameters);
+                    declaration.return_parameters = parameters(&function_definition.return_parameters);
+                    declaration.state_mutability = Some(function_definition.state_mutability);
+                    declaration.visibility = Some(function_definition.visibility);
+                    declaration.implemented = function_definition.implemented;
+
+                    if function_definition.kind == FunctionKind::Function {
+                        members.push((function_definition.name.clone(), function_definition.id));
+                    }
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    self.collect_user_defined_value_type_definition(definition, contract);
+                    members.push((definition.name.clone(), definition.id));
+                }
+
+                ContractDefinitionNode::UsingForDirective(_) => {}
+            }
+        }
+
+        self.members.insert(contract_definition.id, members);
+    }
+
+    fn exported_symbols(&self, unit_id: NodeID, imports: &HashMap<NodeID, Vec<&ImportDirective>>, visited: &mut HashSet<NodeID>) -> Vec<(String, NodeID)> {
+        if !visited.insert(unit_id) {
+            return vec![];
+        }
+
+        let mut result = self.unit_definitions.get(&unit_id).cloned().unwrap_or_default();
+
+        for import_directive in imports.get(&unit_id).map(Vec::as_slice).unwrap_or(&[]) {
+            let imported_unit_id = match import_directive.absolute_path.as_ref().and_then(|path| self.unit_ids.get(path)) {
+                Some(&imported_unit_id) => imported_unit_id,
+                None => continue,
+            };
+
+            if !import_directive.unit_alias.is_empty() {
+                result.push((import_directive.unit_alias.clone(), import_directive.id));
+                continue;
+            }
+
+            let imported_symbols = self.exported_symbols(imported_unit_id, imports, visited);
+
+            if import_directive.symbol_aliases.is_empty() {
+                result.extend(imported_symbols);
+                continue;
+            }
+
+            for symbol_alias in import_directive.symbol_aliases.iter() {
+                let foreign_name = match &symbol_alias.foreign {
+                    Expression::Identifier(identifier) => identifier.name.as_str(),
+                    _ => continue,
+                };
+
+                let local_name = symbol_alias.local.as_deref().unwrap_or(foreign_name);
+
+                for (name, id) in imported_symbols.iter() {
+                    if name == foreign_name {
+                        result.push((local_name.to_string(), *id));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn collect_imports(&mut self, source_units: &[SourceUnit]) {
+        let mut imports: HashMap<NodeID, Vec<&ImportDirective>> = HashMap::new();
+
+        for source_unit in source_units.iter() {
+            for import_directive in source_unit.import_directives() {
+                imports.entry(source_unit.id).or_default().push(import_directive);
+
+                if let Some(&imported_unit_id) = import_directive.absolute_path.as_ref().and_then(|path| self.unit_ids.get(path)) {
+                    if !import_directive.unit_alias.is_empty() {
+                        let declaration = Declaration::new(
+                            import_directive.unit_alias.as_str(),
+                            DeclarationKind::Import(imported_unit_id),
+                            source_unit.id,
+                            None,
+                            None,
+                        );
+
+                        self.declarations.insert(import_directive.id, declaration);
+                    }
+                }
+            }
+        }
+
+        for source_unit in source_units.iter() {
+            let symbols = self.exported_symbols(source_unit.id, &imports, &mut HashSet::new());
+            self.unit_symbols.insert(source_unit.id, symbols);
+        }
+    }
+
+    //
+    // Lookups
+    //
+
+    fn resolve_base_contract(&self, contract_id: NodeID, name: &str) -> Option<NodeID> {
+        let unit_id = self.declarations.get(&contract_id)?.unit_id;
+
+        self.lookup_path(name, unit_id, None)
+            .filter(|id| matches!(self.declarations.get(id).map(|declaration| &declaration.kind), Some(DeclarationKind::Contract(_))))
+    }
+
+    fn linearize(&mut self, contract_id: NodeID, visiting: &mut HashSet<NodeID>) -> Vec<NodeID> {
+        if let Some(linearization) = self.linearizations.get(&contract_id) {
+            return linearization.clone();
+        }
+
+        if !visiting.insert(contract_id) {
+            return vec![contract_id];
+        }
+
+        let base_ids: Vec<NodeID> = self.base_names
+            .get(&contract_id)
+            .cloned()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|name| self.resolve_base_contract(contract_id, name))
+            .collect();
+
+        //
+        // C3 linearization, with the most derived base contract listed last
+        //
+
+        let mut sequences: Vec<Vec<NodeID>> = base_ids
+            .iter()
+            .rev()
+            .map(|&base_id| self.linearize(base_id, visiting))
+            .collect();
+
+        sequences.push(base_ids.iter().rev().copied().collect());
+
+        let mut result = vec![contract_id];
+
+        loop {
+            sequences.retain(|sequence| !sequence.is_empty());
+
+            if sequences.is_empty() {
+                break;
+            }
+
+            let candidate = sequences
+                .iter()
+                .map(|sequence| sequence[0])
+                .find(|candidate| !sequences.iter().any(|sequence| sequence[1..].contains(candidate)));
+
+            let candidate = match candidate {
+                Some(candidate) => candidate,
+                None => break,
+            };
+
+            result.push(candidate);
+
+            for sequence in sequences.iter_mut() {
+                sequence.retain(|&id| id != candidate);
+            }
+        }
+
+        visiting.remove(&contract_id);
+        self.linearizations.insert(contract_id, result.clone());
+
+        result
+    }
+
+    fn contract_members(&self, contract_id: NodeID, name: &str, skip_first: bool) -> Vec<NodeID> {
+        let linearization = self.linearizations.get(&contract_id).cloned().unwrap_or_else(|| vec![contract_id]);
+
+        let mut result: Vec<NodeID> = vec![];
+        let mut parameter_counts = vec![];
+
+        for &id in linearization.iter().skip(if skip_first { 1 } else { 0 }) {
+            for &(_, member_id) in self.members.get(&id).map(Vec::as_slice).unwrap_or(&[]).iter().filter(|(member_name, _)| member_name == name) {
+                let declaration = &self.declarations[&member_id];
+
+                //
+                // Overridden functions are hidden by the most derived definition
+                //
+
+                if declaration.is_callable() {
+                    if parameter_counts.contains(&declaration.parameters.len()) {
+                        continue;
+                    }
+
+                    parameter_counts.push(declaration.parameters.len());
+                } else if !result.is_empty() {
+                    continue;
+                }
+
+                result.push(member_id);
+            }
+
+            if !result.is_empty() && !self.declarations[&result[0]].is_callable() {
+                break;
+            }
+        }
+
+        result
+    }
+
+    fn lookup(&self, name: &str, unit_id: NodeID, contract_id: Option<NodeID>, include_locals: bool) -> Vec<NodeID> {
+        if include_locals {
+            for scope in self.locals.iter().rev() {
+                if let Some((_, id)) = scope.iter().rev().find(|(local_name, _)| local_name == name) {
+                    return vec![*id];
+                }
+            }
+        }
+
+        if let Some(contract_id) = contract_id {
+            let result = self.contract_members(contract_id, name, false);
+
+            if !result.is_empty() {
+                return result;
+            }
+
+            if let Some(contract_declaration) = self.declarations.get(&contract_id) {
+                if contract_declaration.name == name {
+                    return vec![contract_id];
+                }
+            }
+        }
+
+        self.unit_symbols
+            .get(&unit_id)
+            .map(|symbols| symbols.iter().filter(|(symbol_name, _)| symbol_name == name).map(|(_, id)| *id).collect())
+            .unwrap_or_default()
+    }
+
+    fn select_overload(&self, candidates: &[NodeID], argument_count: Option<usize>) -> Option<NodeID> {
+        match argument_count {
+            Some(argument_count) if candidates.len() > 1 => candidates
+                .iter()
+                .find(|id| self.declarations[id].parameters.len() == argument_count)
+                .or_else(|| candidates.first())
+                .copied(),
+
+            _ => candidates.first().copied(),
+        }
+    }
+
+    fn member_candidates(&self, value: &Value, name: &str) -> Vec<NodeID> {
+        match value {
+            Value::Type(id) | Value::Instance(id) => match self.declarations.get(id).map(|declaration| &declaration.kind) {
+                Some(DeclarationKind::Contract(_)) => self.contract_members(*id, name, false),
+
+                Some(DeclarationKind::Enum) | Some(DeclarationKind::Struct) => self.members
+                    .get(id)
+                    .map(|members| members.iter().filter(|(member_name, _)| member_name == name).map(|(_, id)| *id).collect())
+                    .unwrap_or_default(),
+
+                _ => vec![],
+            },
+
+            Value::Unit(unit_id) => self.lookup(name, *unit_id, None, false),
+
+            Value::Super => match self.contract_id {
+                Some(contract_id) => self.contract_members(contract_id, name, true),
+                None => vec![],
+            },
+
+            _ => vec![],
+        }
+    }
+
+    fn lookup_path(&self, path: &str, unit_id: NodeID, contract_id: Option<NodeID>) -> Option<NodeID> {
+        let mut components = path.split('.');
+
+        let mut id = *self.lookup(components.next()?, unit_id, contract_id, false).first()?;
+
+        for component in components {
+            let value = self.declaration_value(id)?;
+            id = *self.member_candidates(&value, component).first()?;
+        }
+
+        Some(id)
+    }
+
+    fn type_value(&self, type_name: &TypeName, unit_id: NodeID, contract_id: Option<NodeID>) -> Option<Value> {
+        match type_name {
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                let id = match user_defined_type_name.referenced_declaration {
+                    0 => self.lookup_path(user_defined_type_name.path_node.as_ref()?.name.as_str(), unit_id, contract_id)?,
+                    id => id,
+                };
+
+                Some(Value::Instance(id))
+            }
+
+            TypeName::ArrayTypeName(_) | TypeName::Mapping(_) => Some(Value::Typed(Box::new(type_name.clone()), unit_id, contract_id)),
+
+            _ => None,
+        }
+    }
+
+    fn declaration_value(&self, id: NodeID) -> Option<Value> {
+        let declaration = self.declarations.get(&id)?;
+
+        match &declaration.kind {
+            DeclarationKind::Contract(_) | DeclarationKind::Struct | DeclarationKind::Enum | DeclarationKind::UserDefinedValueType => Some(Value::Type(id)),
+            DeclarationKind::Import(unit_id) => Some(Value::Unit(*unit_id)),
+            DeclarationKind::Function | DeclarationKind::Event | DeclarationKind::Error | DeclarationKind::Modifier => Some(Value::Function(id)),
+            DeclarationKind::EnumValue => declaration.contract_id.map(Value::Instance),
+
+            DeclarationKind::Variable => {
+                let contract_id = match declaration.contract_id {
+                    Some(contract_id) if matches!(self.declarations.get(&contract_id).map(|declaration| &declaration.kind), Some(DeclarationKind::Contract(_))) => Some(contract_id),
+                    Some(struct_id) => self.declarations.get(&struct_id).and_then(|declaration| declaration.contract_id),
+                    None => None,
+                };
+
+                self.type_value(declaration.type_name.as_ref()?, declaration.unit_id, contract_id)
+            }
+        }
+    }
+
+    //
+    // Type strings
+    //
+
+    fn location_suffix(storage_location: &StorageLocation, state_variable: bool) -> &'static str {
+        match storage_location {
+            _ if state_variable => " storage ref",
+            StorageLocation::Storage => " storage pointer",
+            StorageLocation::Memory => " memory",
+            StorageLocation::Calldata => " calldata",
+            StorageLocation::Default => "",
+        }
+    }
+
+    fn type_string(&self, type_name: &TypeName, unit_id: NodeID, contract_id: Option<NodeID>, location: &str) -> String {
+        match type_name {
+            TypeName::ElementaryTypeName(elementary_type_name) => {
+                let name = normalized_elementary_type_name(elementary_type_name.name.as_str());
+
+                match name {
+                    "address" if elementary_type_name.state_mutability == Some(StateMutability::Payable) => "address payable".to_string(),
+                    "string" | "bytes" => format!("{}{}", name, location),
+                    name => name.to_string(),
+                }
+            }
+
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                let id = match user_defined_type_name.referenced_declaration {
+                    0 => user_defined_type_name.path_node.as_ref().and_then(|path_node| self.lookup_path(path_node.name.as_str(), unit_id, contract_id)),
+                    id => Some(id),
+                };
+
+                match id.and_then(|id| self.declarations.get(&id)) {
+                    Some(Declaration { kind: DeclarationKind::Contract(ContractKind::Library), name, .. }) => format!("library {}", name),
+                    Some(Declaration { kind: DeclarationKind::Contract(_), name, .. }) => format!("contract {}", name),
+                    Some(Declaration { kind: DeclarationKind::Struct, canonical_name, .. }) => format!("struct {}{}", canonical_name, location),
+                    Some(Declaration { kind: DeclarationKind::Enum, canonical_name, .. }) => format!("enum {}", canonical_name),
+                    Some(declaration) => declaration.canonical_name.clone(),
+                    None => user_defined_type_name.to_string(),
+                }
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => format!(
+                "{}[{}]{}",
+                self.type_string(array_type_name.base_type.as_ref(), unit_id, contract_id, location),
+                array_type_name.length.as_ref().and_then(|length| length.value.clone()).unwrap_or_default(),
+                location,
+            ),
+
+            TypeName::Mapping(mapping) => format!(
+                "mapping({} => {})",
+                self.type_string(mapping.key_type.as_ref(), unit_id, contract_id, ""),
+                self.type_string(mapping.value_type.as_ref(), unit_id, contract_id, ""),
+            ),
+
+            TypeName::FunctionTypeName(function_type_name) => self.function_type_string(
+                parameters(&function_type_name.parameter_types).as_slice(),
+                parameters(&function_type_name.return_parameter_types).as_slice(),
+                Some(function_type_name.state_mutability),
+                Some(function_type_name.visibility),
+                unit_id,
+                contract_id,
+            ),
+
+            TypeName::String(name) => name.clone(),
+        }
+    }
+
+    fn function_type_string(
+        &self,
+        parameters: &[(TypeName, StorageLocation)],
+        return_parameters: &[(TypeName, StorageLocation)],
+        state_mutability: Option<StateMutability>,
+        visibility: Option<Visibility>,
+        unit_id: NodeID,
+        contract_id: Option<NodeID>,
+    ) -> String {
+        let type_list = |parameters: &[(TypeName, StorageLocation)]| {
+            parameters
+                .iter()
+                .map(|(type_name, storage_location)| self.type_string(type_name, unit_id, contract_id, Self::location_suffix(storage_location, false)))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let mut result = format!("function ({})", type_list(parameters));
+
+        match state_mutability {
+            Some(StateMutability::NonPayable) | None => {}
+            Some(state_mutability) => result.push_str(format!(" {}", state_mutability).as_str()),
+        }
+
+        if let Some(Visibility::External) = visibility {
+            result.push_str(" external");
+        }
+
+        if !return_parameters.is_empty() {
+            result.push_str(format!(" returns ({})", type_list(return_parameters)).as_str());
+        }
+
+        result
+    }
+
+    fn declaration_type_string(&self, id: NodeID) -> Option<String> {
+        if id < 0 {
+            return BUILTINS.iter().find(|(_, builtin_id, _)| *builtin_id == id).map(|(_, _, type_string)| type_string.to_string());
+        }
+
+        let declaration = self.declarations.get(&id)?;
+
+        let contract_id = match declaration.contract_id {
+            Some(contract_id) if matches!(self.declarations.get(&contract_id).map(|declaration| &declaration.kind), Some(DeclarationKind::Contract(_))) => Some(contract_id),
+            Some(struct_id) => self.declarations.get(&struct_id).and_then(|declaration| declaration.contract_id),
+            None => None,
+        };
+
+        Some(match &declaration.kind {
+            DeclarationKind::Contract(_) => format!("type(contract {})", declaration.name),
+            DeclarationKind::Struct => format!("type(struct {} storage pointer)", declaration.canonical_name),
+            DeclarationKind::Enum => format!("type(enum {})", declaration.canonical_name),
+            DeclarationKind::EnumValue => format!("enum {}", self.declarations.get(&declaration.contract_id?)?.canonical_name),
+            DeclarationKind::UserDefinedValueType => format!("type({})", declaration.canonical_name),
+            DeclarationKind::Import(_) => format!("module \"{}\"", declaration.name),
+
+            DeclarationKind::Modifier => format!(
+                "modifier ({})",
+                declaration.parameters
+                    .iter()
+                    .map(|(type_name, storage_location)| self.type_string(type_name, declaration.unit_id, contract_id, Self::location_suffix(storage_location, false)))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+
+            DeclarationKind::Function | DeclarationKind::Event | DeclarationKind::Error => self.function_type_string(
+                declaration.parameters.as_slice(),
+                declaration.return_parameters.as_slice(),
+                declaration.state_mutability,
+                declaration.visibility,
+                declaration.unit_id,
+                contract_id,
+            ),
+
+            DeclarationKind::Variable => self.type_string(
+                declaration.type_name.as_ref()?,
+                declaration.unit_id,
+                contract_id,
+                Self::location_suffix(&declaration.storage_location, declaration.state_variable),
+            ),
+        })
+    }
+
+    //
+    // Source units and definitions
+    //
+
+    fn resolve_source_unit(&mut self, source_unit: &mut SourceUnit) {
+        self.unit_id = source_unit.id;
+        self.contract_id = None;
+
+        if let Some(symbols) = self.unit_symbols.get(&source_unit.id) {
+            let mut exported_symbols: HashMap<String, Vec<NodeID>> = HashMap::new();
+
+            for (name, id) in symbols.iter() {
+                exported_symbols.entry(name.clone()).or_default().push(*id);
+            }
+
+            source_unit.exported_symbols = Some(exported_symbols);
+        }
+
+        for node in source_unit.nodes.iter_mut() {
+            match node {
+                SourceUnitNode::ImportDirective(import_directive) => self.resolve_import_directive(import_directive),
+                SourceUnitNode::ContractDefinition(contract_definition) => self.resolve_contract_definition(contract_definition),
+                SourceUnitNode::StructDefinition(struct_definition) => self.resolve_struct_definition(struct_definition),
+                SourceUnitNode::EnumDefinition(enum_definition) => enum_definition.canonical_name = Some(enum_definition.name.clone()),
+                SourceUnitNode::EventDefinition(event_definition) => self.resolve_parameter_list(&mut event_definition.parameters, false),
+                SourceUnitNode::ErrorDefinition(error_definition) => self.resolve_parameter_list(&mut error_definition.parameters, false),
+                SourceUnitNode::FunctionDefinition(function_definition) => self.resolve_function_definition(function_definition),
+                SourceUnitNode::VariableDeclaration(variable_declaration) => self.resolve_variable_declaration(variable_declaration, false),
+
+                SourceUnitNode::UserDefinedValueTypeDefinition(definition) => {
+                    definition.canonical_name = Some(definition.name.clone());
+                    self.resolve_type_name(&mut definition.underlying_type, "");
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    //
+    // Using-for directives are resolved before any expressions, since attached functions and operators may be used in other source units
+    //
+
+    fn resolve_using_for_directives(&mut self, source_unit: &mut SourceUnit) {
+        self.unit_id = source_unit.id;
+        self.contract_id = None;
+
+        for node in source_unit.nodes.iter_mut() {
+            match node {
+                SourceUnitNode::UsingForDirective(using_for_directive) => self.resolve_using_for_directive(using_for_directive),
+
+                SourceUnitNode::ContractDefinition(contract_definition) => {
+                    self.linearize(contract_definition.id, &mut HashSet::new());
+                    self.contract_id = Some(contract_definition.id);
+
+                    for node in contract_definition.nodes.iter_mut() {
+                        if let ContractDefinitionNode::UsingForDirective(using_for_directive) = node {
+                            self.resolve_using_for_directive(using_for_directive);
+                        }
+                    }
+
+                    self.contract_id = None;
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    fn resolve_using_for_directive(&mut self, using_for_directive: &mut UsingForDirective) {
+        if let Some(type_name) = using_for_directive.type_name.as_mut() {
+            self.resolve_type_name(type_name, "");
+        }
+
+        //
+        // Global directives are attached to the type itself, all others to the enclosing contract or source unit
+        //
+
+        let scope_id = match (using_for_directive.global, using_for_directive.type_name.as_ref()) {
+            (true, Some(TypeName::UserDefinedTypeName(user_defined_type_name))) => user_defined_type_name.referenced_declaration,
+            _ => self.contract_id.unwrap_or(self.unit_id),
+        };
+
+        if let Some(library_name) = using_for_directive.library_name.as_mut() {
+            self.resolve_identifier_path(library_name);
+
+            if let Some(library_id) = library_name.referenced_declaration {
+                self.using_for_libraries.entry(scope_id).or_default().push(library_id);
+            }
+        }
+
+        for function in using_for_directive.function_list.iter_mut() {
+            let (identifier_path, operator) = match function {
+                UsingForFunction::Operator { definition, operator } => (definition, Some(operator.clone())),
+                UsingForFunction::Function { function } => (function, None),
+            };
+
+            self.resolve_identifier_path(identifier_path);
+
+            let function_id = match identifier_path.referenced_declaration {
+                Some(function_id) => function_id,
+                None => continue,
+            };
+
+            match operator {
+                Some(operator) => self.using_for_operators.entry(scope_id).or_default().push((operator, function_id)),
+                None => self.using_for_functions.entry(scope_id).or_default().push(function_id),
+            }
+        }
+    }
+
+    fn resolve_import_directive(&mut self, import_directive: &mut ImportDirective) {
+        let imported_unit_id = match import_directive.absolute_path.as_ref().and_then(|path| self.unit_ids.get(path)) {
+            Some(&imported_unit_id) => imported_unit_id,
+            None => return,
+        };
+
+        import_directive.source_unit = imported_unit_id;
+
+        for symbol_alias in import_directive.symbol_aliases.iter_mut() {
+            if let Expression::Identifier(identifier) = &mut symbol_alias.foreign {
+                let candidates = self.lookup(identifier.name.as_str(), imported_unit_id, None, false);
+
+                if let Some(&id) = candidates.first() {
+                    identifier.referenced_declaration = id;
+                }
+
+                if candidates.len() > 1 {
+                    identifier.overloaded_declarations = candidates;
+                }
+            }
+        }
+    }
+
+    fn resolve_contract_definition(&mut self, contract_definition: &mut ContractDefinition) {
+        let contract_id = contract_definition.id;
+        let linearization = self.linearize(contract_id, &mut HashSet::new());
+
+        for base_contract in contract_definition.base_contracts.iter_mut() {
+            base_contract.base_name.referenced_declaration = self.resolve_base_contract(contract_id, base_contract.base_name.name.as_str());
+        }
+
+        //
+        // A contract is fully implemented when the most derived definition of every function and modifier has a body
+        //
+
+        let mut seen = HashSet::new();
+        let mut fully_implemented = contract_definition.kind != ContractKind::Interface;
+
+        for id in linearization.iter() {
+            for (name, member_id) in self.members.get(id).map(Vec::as_slice).unwrap_or(&[]) {
+                let declaration = &self.declarations[member_id];
+
+                if !matches!(declaration.kind, DeclarationKind::Function | DeclarationKind::Modifier) {
+                    continue;
+                }
+
+                if seen.insert((name.clone(), declaration.parameters.len())) && !declaration.implemented {
+                    fully_implemented = false;
+                }
+            }
+        }
+
+        contract_definition.fully_implemented = Some(fully_implemented);
+        contract_definition.linearized_base_contracts = Some(linearization);
+
+        self.contract_id = Some(contract_id);
+
+        let contract_name = contract_definition.name.clone();
+
+        for node in contract_definition.nodes.iter_mut() {
+            match node {
+                ContractDefinitionNode::StructDefinition(struct_definition) => {
+                    struct_definition.canonical_name = Some(format!("{}.{}", contract_name, struct_definition.name));
+                    self.resolve_struct_definition(struct_definition);
+                }
+
+                ContractDefinitionNode::EnumDefinition(enum_definition) => {
+                    enum_definition.canonical_name = Some(format!("{}.{}", contract_name, enum_definition.name));
+                }
+
+                ContractDefinitionNode::UserDefinedValueTypeDefinition(definition) => {
+                    definition.canonical_name = Some(format!("{}.{}", contract_name, definition.name));
+                    self.resolve_type_name(&mut definition.underlying_type, "");
+                }
+
+                ContractDefinitionNode::VariableDeclaration(variable_declaration) => self.resolve_variable_declaration(variable_declaration, false),
+                ContractDefinitionNode::EventDefinition(event_definition) => self.resolve_parameter_list(&mut event_definition.parameters, false),
+                ContractDefinitionNode::ErrorDefinition(error_definition) => self.resolve_parameter_list(&mut error_definition.parameters, false),
+                ContractDefinitionNode::ModifierDefinition(modifier_definition) => self.resolve_modifier_definition(modifier_definition),
+                ContractDefinitionNode::FunctionDefinition(function_definition) => self.resolve_function_definition(function_definition),
+                ContractDefinitionNode::UsingForDirective(_) => {}
+            }
+        }
+
+        self.contract_id = None;
+    }
+
+    fn resolve_struct_definition(&mut self, struct_definition: &mut StructDefinition) {
+        if struct_definition.canonical_name.is_none() {
+            struct_definition.canonical_name = Some(struct_definition.name.clone());
+        }
+
+        for member in struct_definition.members.iter_mut() {
+            self.resolve_variable_declaration(member, false);
+        }
+    }
+
+    fn resolve_identifier_path(&mut self, identifier_path: &mut IdentifierPath) {
+        identifier_path.referenced_declaration = self.lookup_path(identifier_path.name.as_str(), self.unit_id, self.contract_id);
+    }
+
+    fn resolve_type_name(&mut self, type_name: &mut TypeName, location: &str) {
+        let type_string = self.type_string(type_name, self.unit_id, self.contract_id, location);
+
+        match type_name {
+            TypeName::ElementaryTypeName(elementary_type_name) => {
+                elementary_type_name.type_descriptions.type_string = Some(type_string);
+            }
+
+            TypeName::UserDefinedTypeName(user_defined_type_name) => {
+                if let Some(path_node) = user_defined_type_name.path_node.as_mut() {
+                    self.resolve_identifier_path(path_node);
+                    user_defined_type_name.referenced_declaration = path_node.referenced_declaration.unwrap_or(0);
+                }
+
+                user_defined_type_name.type_descriptions.type_string = Some(type_string);
+            }
+
+            TypeName::ArrayTypeName(array_type_name) => {
+                self.resolve_type_name(array_type_name.base_type.as_mut(), location);
+                array_type_name.type_descriptions.type_string = Some(type_string);
+            }
+
+            TypeName::Mapping(mapping) => {
+                self.resolve_type_name(mapping.key_type.as_mut(), "");
+                self.resolve_type_name(mapping.value_type.as_mut(), "");
+                mapping.type_descriptions.type_string = Some(type_string);
+            }
+
+            TypeName::FunctionTypeName(function_type_name) => {
+                self.resolve_parameter_list(&mut function_type_name.parameter_types, false);
+                self.resolve_parameter_list(&mut function_type_name.return_parameter_types, false);
+                function_type_name.type_descriptions.type_string = Some(type_string);
+            }
+
+            TypeName::String(_) => {}
+        }
+    }
+
+    fn resolve_variable_declaration(&mut self, variable_declaration: &mut VariableDeclaration, is_local: bool) {
+        let location = Self::location_suffix(&variable_declaration.storage_location, variable_declaration.state_variable);
+
+        if let Some(type_name) = variable_declaration.type_name.as_mut() {
+            self.resolve_type_name(type_name, location);
+        }
+
+        if let Some(overrides) = variable_declaration.overrides.as_mut() {
+            for identifier_path in overrides.overrides.iter_mut() {
+                self.resolve_identifier_path(identifier_path);
+            }
+        }
+
+        if let Some(value) = variable_declaration.value.as_mut() {
+            self.resolve_expression(value, None);
+        }
+
+        if is_local {
+            let contract = self.contract_id.map(|contract_id| (contract_id, self.declarations[&contract_id].name.clone()));
+            self.declare_variable(variable_declaration, contract.as_ref().map(|(id, name)| (*id, name.as_str())));
+
+            if !variable_declaration.name.is_empty() {
+                if let Some(scope) = self.locals.last_mut() {
+                    scope.push((variable_declaration.name.clone(), variable_declaration.id));
+                }
+            }
+        }
+
+        variable_declaration.type_descriptions.type_string = Some(
+            self.declaration_type_string(variable_declaration.id)
+                .or_else(|| variable_declaration.type_name.as_ref().map(|type_name| self.type_string(type_name, self.unit_id, self.contract_id, location)))
+                .unwrap_or_default(),
+        );
+    }
+
+    fn resolve_parameter_list(&mut self, parameter_list: &mut ParameterList, is_local: bool) {
+        for parameter in parameter_list.parameters.iter_mut() {
+            self.resolve_variable_declaration(parameter, is_local);
+        }
+    }
+
+    fn resolve_modifier_definition(&mut self, modifier_definition: &mut ModifierDefinition) {
+        self.locals.push(vec![]);
+
+        self.resolve_parameter_list(&mut modifier_definition.parameters, true);
+
+        if let Some(overrides) = modifier_definition.overrides.as_mut() {
+            for identifier_path in overrides.overrides.iter_mut() {
+                self.resolve_identifier_path(identifier_path);
+            }
+        }
+
+        self.resolve_block(&mut modifier_definition.body);
+
+        self.locals.pop();
+    }
+
+    fn resolve_function_definition(&mut self, function_definition: &mut FunctionDefinition) {
+        self.locals.push(vec![]);
+
+        self.resolve_parameter_list(&mut function_definition.parameters, true);
+        self.resolve_parameter_list(&mut function_definition.return_parameters, true);
+
+        if let Some(overrides) = function_definition.overrides.as_mut() {
+            for identifier_path in overrides.overrides.iter_mut() {
+                self.resolve_identifier_path(identifier_path);
+            }
+        }
+
+        for modifier in function_definition.modifiers.iter_mut() {
+            self.resolve_identifier_path(&mut modifier.modifier_name);
+
+            let is_contract = modifier.modifier_name.referenced_declaration
+                .and_then(|id| self.declarations.get(&id))
+                .map(|declaration| matches!(declaration.kind, DeclarationKind::Contract(_)))
+                .unwrap_or(false);
+
+            modifier.kind = Some(if is_contract {
+                ModifierInvocationKind::BaseConstructorSpecifier
+            } else {
+                ModifierInvocationKind::ModifierInvocation
+            });
+
+            if let Some(arguments) = modifier.arguments.as_mut() {
+                for argument in arguments.iter_mut() {
+                    self.resolve_expression(argument, None);
+                }
+            }
+        }
+
+        if let Some(body) = function_definition.body.as_mut() {
+            self.resolve_block(body);
+        }
+
+        self.locals.pop();
+    }
+
+    //
+    // Statements
+    //
+
+    fn resolve_block(&mut self, block: &mut Block) {
+        self.locals.push(vec![]);
+
+        for statement in block.statements.iter_mut() {
+            self.resolve_statement(statement);
+        }
+
+        self.locals.pop();
+    }
+
+    fn resolve_block_or_statement(&mut self, block_or_statement: &mut BlockOrStatement) {
+        match block_or_statement {
+            BlockOrStatement::Block(block) => self.resolve_block(block),
+
+            BlockOrStatement::Statement(statement) => {
+                self.locals.push(vec![]);
+                self.resolve_statement(statement);
+                self.locals.pop();
+            }
+        }
+    }
+
+    fn resolve_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::VariableDeclarationStatement(variable_declaration_statement) => {
+                if let Some(initial_value) = variable_declaration_statement.initial_value.as_mut() {
+                    self.resolve_expression(initial_value, None);
+                }
+
+                for declaration in variable_declaration_statement.declarations.iter_mut().flatten() {
+                    self.resolve_variable_declaration(declaration, true);
+                }
+            }
+
+            Statement::IfStatement(if_statement) => {
+                self.resolve_expression(&mut if_statement.condition, None);
+                self.resolve_block_or_statement(&mut if_statement.true_body);
+
+                if let Some(false_body) = if_statement.false_body.as_mut() {
+                    self.resolve_block_or_statement(false_body);
+                }
+            }
+
+            Statement::ForStatement(for_statement) => {
+                self.locals.push(vec![]);
+
+                if let Some(initialization_expression) = for_statement.initialization_expression.as_mut() {
+                    self.resolve_statement(initialization_expression);
+                }
+
+                if let Some(condition) = for_statement.condition.as_mut() {
+                    self.resolve_expression(condition, None);
+                }
+
+                if let Some(loop_expression) = for_statement.loop_expression.as_mut() {
+                    self.resolve_statement(loop_expression);
+                }
+
+                self.resolve_block_or_statement(&mut for_statement.body);
+
+                self.locals.pop();
+            }
+
+            Statement::WhileStatement(while_statement) => {
+                self.resolve_expression(&mut while_statement.condition, None);
+                self.resolve_block_or_statement(&mut while_statement.body);
+            }
+
+            Statement::DoWhileStatement(do_while_statement) => {
+                self.resolve_block_or_statement(&mut do_while_statement.body);
+                self.resolve_expression(&mut do_while_statement.condition, None);
+            }
+
+            Statement::EmitStatement(emit_statement) => {
+                self.resolve_expression(&mut emit_statement.event_call, None);
+            }
+
+            Statement::TryStatement(try_statement) => {
+                self.resolve_function_call(&mut try_statement.external_call);
+
+                for clause in try_statement.clauses.iter_mut() {
+                    self.locals.push(vec![]);
+
+                    if let Some(parameters) = clause.parameters.as_mut() {
+                        self.resolve_parameter_list(parameters, true);
+                    }
+
+                    self.resolve_block(&mut clause.block);
+
+                    self.locals.pop();
+                }
+            }
+
+            Statement::Block(block) | Statement::UncheckedBlock(block) => self.resolve_block(block),
+
+            Statement::Return(return_statement) => {
+                if let Some(expression) = return_statement.expression.as_mut() {
+                    self.resolve_expression(expression, None);
+                }
+            }
+
+            Statement::RevertStatement(revert_statement) => {
+                self.resolve_function_call(&mut revert_statement.error_call);
+            }
+
+            Statement::ExpressionStatement(expression_statement) => {
+                self.resolve_expression(&mut expression_statement.expression, None);
+            }
+
+            Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::PlaceholderStatement(_)
+            | Statement::InlineAssembly(_)
+            | Statement::UnhandledStatement(_) => {}
+        }
+    }
+
+    //
+    // Expressions
+    //
+
+    fn resolve_identifier(&mut self, identifier: &mut Identifier, argument_count: Option<usize>) -> Option<Value> {
+        let candidates = self.lookup(identifier.name.as_str(), self.unit_id, self.contract_id, true);
+
+        let id = match self.select_overload(candidates.as_slice(), argument_count) {
+            Some(id) => id,
+
+            None => {
+                let (_, id, type_string) = BUILTINS.iter().find(|(name, _, _)| *name == identifier.name)?;
+
+                identifier.referenced_declaration = *id;
+
+                identifier.type_descriptions.type_string = Some(match (*id, argument_count) {
+                    (-18, Some(2)) => "function (bool,string memory) pure".to_string(),
+                    (-19, Some(1)) => "function (string memory) pure".to_string(),
+                    (-28, _) => format!("contract {}", self.declarations.get(&self.contract_id?)?.name),
+                    (-25, _) => format!("type(contract super {})", self.declarations.get(&self.contract_id?)?.name),
+                    _ => type_string.to_string(),
+                });
+
+                return match *id {
+                    -28 => self.contract_id.map(Value::Instance),
+                    -25 => Some(Value::Super),
+                    _ => None,
+                };
+            }
+        };
+
+        identifier.referenced_declaration = id;
+
+        if candidates.len() > 1 {
+            identifier.overloaded_declarations = candidates;
+        }
+
+        identifier.type_descriptions.type_string = self.declaration_type_string(id);
+
+        self.declaration_value(id)
+    }
+
+    fn resolve_member_access(&mut self, member_access: &mut MemberAccess, argument_count: Option<usize>) -> Option<Value> {
+        let value = self.resolve_expression(member_access.expression.as_mut(), None);
+
+        let mut candidates = match value.as_ref() {
+            Some(value) => self.member_candidates(value, member_access.member_name.as_str()),
+            None => vec![],
+        };
+
+        let mut argument_count = argument_count;
+
+        //
+        // Functions attached with `using for` take the accessed expression as their first argument.
+        // They are looked up in the current contract hierarchy, then the current source unit, then the accessed type itself.
+        //
+
+        if candidates.is_empty() && !matches!(value, Some(Value::Type(_)) | Some(Value::Unit(_)) | Some(Value::Super)) {
+            let mut scope_ids: Vec<NodeID> = self.contract_id
+                .and_then(|contract_id| self.linearizations.get(&contract_id))
+                .cloned()
+                .unwrap_or_default();
+
+            scope_ids.push(self.unit_id);
+
+            if let Some(Value::Instance(type_id)) = value.as_ref() {
+                scope_ids.push(*type_id);
+            }
+
+            for scope_id in scope_ids {
+                for &library_id in self.using_for_libraries.get(&scope_id).map(Vec::as_slice).unwrap_or(&[]) {
+                    candidates.extend(self.contract_members(library_id, member_access.member_name.as_str(), false));
+                }
+
+                for &function_id in self.using_for_functions.get(&scope_id).map(Vec::as_slice).unwrap_or(&[]) {
+                    if self.declarations.get(&function_id).map(|declaration| declaration.name == member_access.member_name).unwrap_or(false) {
+                        candidates.push(function_id);
+                    }
+                }
+
+                if !candidates.is_empty() {
+                    argument_count = argument_count.map(|argument_count| argument_count + 1);
+                    break;
+                }
+            }
+        }
+
+        let id = self.select_overload(candidates.as_slice(), argument_count)?;
+
+        member_access.referenced_declaration = Some(id);
+        member_access.type_descriptions.type_string = self.declaration_type_string(id);
+
+        self.declaration_value(id)
+    }
+
+    fn resolve_function_call(&mut self, function_call: &mut FunctionCall) -> Option<Value> {
+        for argument in function_call.arguments.iter_mut() {
+            self.resolve_expression(argument, None);
+        }
+
+        let argument_count = Some(function_call.arguments.len());
+
+        if let Expression::NewExpression(new_expression) = function_call.expression.as_mut() {
+            self.resolve_type_name(&mut new_expression.type_name, " memory");
+
+            let value = self.type_value(&new_expression.type_name, self.unit_id, self.contract_id);
+            function_call.type_descriptions.type_string = Some(self.type_string(&new_expression.type_name, self.unit_id, self.contract_id, " memory"));
+
+            return value;
+        }
+
+        let value = self.resolve_expression(function_call.expression.as_mut(), argument_count);
+
+        if let Expression::ElementaryTypeNameExpression(elementary_type_name_expression) = function_call.expression.as_ref() {
+            function_call.kind = FunctionCallKind::TypeConversion;
+            function_call.type_descriptions.type_string = Some(self.type_string(&elementary_type_name_expression.type_name, self.unit_id, self.contract_id, " memory"));
+            return None;
+        }
+
+        match value? {
+            Value::Type(id) => {
+                let declaration = self.declarations.get(&id)?;
+
+                if declaration.kind == DeclarationKind::Struct {
+                    function_call.kind = FunctionCallKind::StructConstructorCall;
+                    function_call.type_descriptions.type_string = Some(format!("struct {} memory", declaration.canonical_name));
+                } else {
+                    function_call.kind = FunctionCallKind::TypeConversion;
+
+                    function_call.type_descriptions.type_string = Some(match &declaration.kind {
+                        DeclarationKind::Contract(ContractKind::Library) => format!("library {}", declaration.name),
+                        DeclarationKind::Contract(_) => format!("contract {}", declaration.name),
+                        DeclarationKind::Enum => format!("enum {}", declaration.canonical_name),
+                        _ => declaration.canonical_name.clone(),
+                    });
+                }
+
+                Some(Value::Instance(id))
+            }
+
+            Value::Function(id) => {
+                let (type_string, value) = self.function_return_value(id)?;
+                function_call.type_descriptions.type_string = Some(type_string);
+                value
+            }
+
+            _ => None,
+        }
+    }
+
+    fn function_return_value(&self, id: NodeID) -> Option<(String, Option<Value>)> {
+        let declaration = self.declarations.get(&id)?;
+
+        let (type_name, storage_location) = match declaration.return_parameters.as_slice() {
+            [return_parameter] => return_parameter,
+            _ => return None,
+        };
+
+        let type_string = self.type_string(type_name, declaration.unit_id, declaration.contract_id, Self::location_suffix(storage_location, false));
+
+        Some((type_string, self.type_value(type_name, declaration.unit_id, declaration.contract_id)))
+    }
+
+    //
+    // User-defined operators are bound to functions by global `using for` directives on the type of their operand
+    //
+
+    fn user_defined_operator(&self, value: Option<&Value>, operator: &str, parameter_count: usize) -> Option<NodeID> {
+        let type_id = match value? {
+            Value::Instance(type_id) => type_id,
+            _ => return None,
+        };
+
+        self.using_for_operators
+            .get(type_id)?
+            .iter()
+            .find(|(bound_operator, id)| bound_operator == operator && self.declarations.get(id).map(|declaration| declaration.parameters.len()) == Some(parameter_count))
+            .map(|&(_, id)| id)
+    }
+
+    fn resolve_expression(&mut self, expression: &mut Expression, argument_count: Option<usize>) -> Option<Value> {
+        match expression {
+            Expression::Identifier(identifier) => self.resolve_identifier(identifier, argument_count),
+            Expression::MemberAccess(member_access) => self.resolve_member_access(member_access, argument_count),
+            Expression::FunctionCall(function_call) => self.resolve_function_call(function_call),
+
+            Expression::FunctionCallOptions(function_call_options) => {
+                for option in function_call_options.options.iter_mut() {
+                    self.resolve_expression(option, None);
+                }
+
+                self.resolve_expression(function_call_options.expression.as_mut(), argument_count)
+            }
+
+            Expression::UnaryOperation(unary_operation) => {
+                let value = self.resolve_expression(unary_operation.sub_expression.as_mut(), None);
+
+                unary_operation.function = self.user_defined_operator(value.as_ref(), unary_operation.operator.as_str(), 1);
+
+                let (type_string, value) = self.function_return_value(unary_operation.function?)?;
+                unary_operation.type_descriptions.type_string = Some(type_string);
+                value
+            }
+
+            Expression::BinaryOperation(binary_operation) => {
+                let value = self.resolve_expression(binary_operation.left_expression.as_mut(), None);
+                self.resolve_expression(binary_operation.right_expression.as_mut(), None);
+
+                binary_operation.function = self.user_defined_operator(value.as_ref(), binary_operation.operator.as_str(), 2);
+
+                let (type_string, value) = self.function_return_value(binary_operation.function?)?;
+                binary_operation.type_descriptions.type_string = Some(type_string);
+                value
+            }
+
+            Expression::Conditional(conditional) => {
+                self.resolve_expression(conditional.condition.as_mut(), None);
+                let value = self.resolve_expression(conditional.true_expression.as_mut(), None);
+                self.resolve_expression(conditional.false_expression.as_mut(), None);
+                value
+            }
+
+            Expression::Assignment(assignment) => {
+                self.resolve_expression(assignment.right_hand_side.as_mut(), None);
+                self.resolve_expression(assignment.left_hand_side.as_mut(), None)
+            }
+
+            Expression::IndexAccess(index_access) => {
+                self.resolve_expression(index_access.index_expression.as_mut(), None);
+
+                let (type_name, unit_id, contract_id) = match self.resolve_expression(index_access.base_expression.as_mut(), None)? {
+                    Value::Typed(type_name, unit_id, contract_id) => (type_name, unit_id, contract_id),
+                    _ => return None,
+                };
+
+                match type_name.as_ref() {
+                    TypeName::ArrayTypeName(array_type_name) => self.type_value(array_type_name.base_type.as_ref(), unit_id, contract_id),
+                    TypeName::Mapping(mapping) => self.type_value(mapping.value_type.as_ref(), unit_id, contract_id),
+                    _ => None,
+                }
+            }
+
+            Expression::IndexRangeAccess(index_range_access) => {
+                if let Some(start_expression) = index_range_access.start_expression.as_mut() {
+                    self.resolve_expression(start_expression, None);
+                }
+
+                if let Some(end_expression) = index_range_access.end_expression.as_mut() {
+                    self.resolve_expression(end_expression, None);
+                }
+
+                self.resolve_expression(index_range_access.base_expression.as_mut(), None)
+            }
+
+            Expression::TupleExpression(tuple_expression) => {
+                let mut values: Vec<Option<Value>> = tuple_expression.components
+                    .iter_mut()
+                    .map(|component| component.as_mut().and_then(|component| self.resolve_expression(component, None)))
+                    .collect();
+
+                if values.len() == 1 && !tuple_expression.is_inline_array {
+                    values.remove(0)
+                } else {
+                    None
+                }
+            }
+
+            Expression::NewExpression(new_expression) => {
+                self.resolve_type_name(&mut new_expression.type_name, " memory");
+                None
+            }
+
+            Expression::ElementaryTypeNameExpression(elementary_type_name_expression) => {
+                self.resolve_type_name(&mut elementary_type_name_expression.type_name, "");
+
+                let type_string = self.type_string(&elementary_type_name_expression.type_name, self.unit_id, self.contract_id, "");
+                elementary_type_name_expression.type_descriptions.type_string = Some(format!("type({})", type_string));
+
+                None
+            }
+
+            Expression::Literal(_) | Expression::UnhandledExpression(_) => None,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use solidity::ast::*;
+use yul::ast::*;
+
+#[test]
+fn legacy_inline_assembly_is_parsed_without_source_locations() {
+    let inline_assembly: InlineAssembly = serde_json::from_value(serde_json::json!({
+        "nodeType": "InlineAssembly",
+        "externalReferences": [],
+        "operations": "{\n    let x := mload(0x40)\n}",
+        "src": "120:40:0",
+        "id": 7
+    })).unwrap();
+
+    let ast = inline_assembly.ast.unwrap();
+    assert_eq!(ast.src, None);
+
+    match ast.statements.as_slice() {
+        [YulStatement::YulVariableDeclaration(YulVariableDeclaration { value: Some(YulExpression::YulFunctionCall(function_call)), src, .. })] => {
+            assert_eq!(src, &None);
+            assert_eq!(function_call.src, None);
+            assert_eq!(function_call.function_name.name, "mload");
+        }
+
+        statements => panic!("Unexpected statements: {:?}", statements),
+    }
+}
+
+#[test]
+fn serialized_source_units_are_deserialized_again() {
+    let source_unit = solidity::parser::parse_source_unit(
+        r#"
+            library L { function inc(uint x) internal pure returns (uint) { return x + 1; } }
+
+            contract C {
+                using L for uint;
+
+                modifier m() { _; }
+
+                function f(uint n) external m returns (uint i) {
+                    do { i++; if (i > n) { break; } else { continue; } } while (i < 10);
+                    assembly { for { } 1 { } { if n { break } continue } }
+                    return i.inc();
+                }
+
+                function g() external pure { assembly { function h() { leave } } }
+            }
+        "#,
+        "C.sol",
+    ).unwrap();
+
+    let json = serde_json::to_string(&source_unit).unwrap();
+    let deserialized: SourceUnit = serde_json::from_str(json.as_str()).unwrap();
+
+    assert_eq!(deserialized.nodes, source_unit.nodes);
+}
+
+fn find_node_mut<'a>(value: &'a mut serde_json::Value, node_type: &str) -> Option<&'a mut serde_json::Value> {
+    if value.get("nodeType").and_then(serde_json::Value::as_str) == Some(node_type) {
+        return Some(value);
+    }
+
+    match value {
+        serde_json::Value::Array(values) => values.iter_mut().find_map(|value| find_node_mut(value, node_type)),
+        serde_json::Value::Object(fields) => fields.values_mut().find_map(|value| find_node_mut(value, node_type)),
+        _ => None,
+    }
+}
+
+#[test]
+fn errors_are_reported_against_the_innermost_failing_node() {
+    let source_unit = solidity::parser::parse_source_unit(
+        "contract C { function f() external pure returns (uint) { return 1 + 2; } }",
+        "C.sol",
+    ).unwrap();
+
+    let mut value = serde_json::to_value(&source_unit).unwrap();
+    value["nodeType"] = "SourceUnit".into();
+
+    let literal = find_node_mut(&mut value, "Literal").unwrap();
+    let src = literal["src"].as_str().unwrap().to_string();
+    literal["kind"] = "bogus".into();
+
+    let error = deserialize_source_unit(&value).unwrap_err();
+
+    assert_eq!(error.node_type.as_deref(), Some("Literal"));
+    assert_eq!(error.src, Some(src));
+    assert!(error.path.starts_with("nodes[0].nodes[0].body.statements[0]"), "{}", error.path);
+    assert!(error.message.contains("bogus"), "{}", error.message);
+
+    assert_eq!(locate_deserialization_error(&serde_json::json!({ "sources": { "C.sol": { "ast": value } } })).unwrap().path, format!("sources[\"C.sol\"].ast.{}", error.path));
+}
+
+#[test]
+fn errors_outside_of_tagged_nodes_are_reported_against_the_source_unit() {
+    let source_unit = solidity::parser::parse_source_unit("contract C {}", "C.sol").unwrap();
+
+    let mut value = serde_json::to_value(&source_unit).unwrap();
+    value["nodeType"] = "SourceUnit".into();
+    value["nodes"] = 5.into();
+
+    let error = deserialize_source_unit(&value).unwrap_err();
+
+    assert_eq!(error.node_type.as_deref(), Some("SourceUnit"));
+    assert_eq!(error.path, "");
+}
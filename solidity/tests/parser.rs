@@ -0,0 +1,190 @@
+use solidity::{ast::*, parser::parse_source_unit};
+
+fn function_statements<'a>(source_unit: &'a SourceUnit, function_name: &str) -> &'a [Statement] {
+    source_unit.contract_definitions()
+        .into_iter()
+        .flat_map(|contract_definition| contract_definition.function_definitions())
+        .find(|function_definition| function_definition.name == function_name)
+        .and_then(|function_definition| function_definition.body.as_ref())
+        .map(|body| body.statements.as_slice())
+        .unwrap()
+}
+
+#[test]
+fn delete_is_parsed_as_an_expression_statement() {
+    let source_unit = parse_source_unit(
+        "contract C { struct S { uint a; } S s; function f() external { delete s; } }",
+        "C.sol",
+    ).unwrap();
+
+    match function_statements(&source_unit, "f") {
+        [Statement::ExpressionStatement(ExpressionStatement {
+            expression: Expression::UnaryOperation(UnaryOperation { operator, sub_expression, .. }),
+            ..
+        })] => {
+            assert_eq!(operator, "delete");
+            assert!(matches!(sub_expression.as_ref(), Expression::Identifier(Identifier { name, .. }) if name == "s"));
+        }
+
+        statements => panic!("Unexpected statements: {:?}", statements),
+    }
+}
+
+#[test]
+fn statement_keywords_are_not_parsed_as_declarations() {
+    let source_unit = parse_source_unit(
+        "contract C {
+            event E(uint x);
+            error Failed(uint x);
+            uint x;
+            function f() external returns (uint) { emit E(x); revert Failed(x); }
+            function g() external returns (uint) { return x; }
+        }",
+        "C.sol",
+    ).unwrap();
+
+    assert!(matches!(
+        function_statements(&source_unit, "f"),
+        [Statement::EmitStatement(_), Statement::RevertStatement(_)]
+    ));
+
+    assert!(matches!(function_statements(&source_unit, "g"), [Statement::Return(_)]));
+}
+
+#[test]
+fn variable_declarations_are_still_parsed() {
+    let source_unit = parse_source_unit(
+        "contract C {
+            struct S { uint a; }
+            function h() internal pure returns (uint, uint, uint) { return (1, 2, 3); }
+            function f() external view {
+                uint x = 1;
+                S memory s;
+                mapping(uint => uint) storage m;
+                (uint a, , uint b) = h();
+            }
+        }",
+        "C.sol",
+    ).unwrap();
+
+    let statements = function_statements(&source_unit, "f");
+    assert_eq!(statements.len(), 4);
+
+    let names: Vec<Vec<Option<&str>>> = statements.iter()
+        .map(|statement| match statement {
+            Statement::VariableDeclarationStatement(statement) => statement.declarations.iter()
+                .map(|declaration| declaration.as_ref().map(|declaration| declaration.name.as_str()))
+                .collect(),
+
+            statement => panic!("Unexpected statement: {:?}", statement),
+        })
+        .collect();
+
+    assert_eq!(names, vec![
+        vec![Some("x")],
+        vec![Some("s")],
+        vec![Some("m")],
+        vec![Some("a"), None, Some("b")],
+    ]);
+}
+
+#[test]
+fn relative_import_paths_are_resolved_against_the_importing_file() {
+    let source_unit = parse_source_unit(
+        "import \"./B.sol\"; import \"../lib/C.sol\"; import \"@oz/D.sol\";",
+        "contracts/tokens/A.sol",
+    ).unwrap();
+
+    let absolute_paths: Vec<Option<&str>> = source_unit.import_directives()
+        .iter()
+        .map(|import_directive| import_directive.absolute_path.as_deref())
+        .collect();
+
+    assert_eq!(absolute_paths, vec![
+        Some("contracts/tokens/B.sol"),
+        Some("contracts/lib/C.sol"),
+        Some("@oz/D.sol"),
+    ]);
+}
+
+#[test]
+fn inline_assembly_is_parsed_into_a_yul_block() {
+    let source_unit = parse_source_unit(
+        "contract C { function f() external { assembly { let x := add(1, 2) } } }",
+        "C.sol",
+    ).unwrap();
+
+    match function_statements(&source_unit, "f") {
+        [Statement::InlineAssembly(InlineAssembly { ast: Some(ast), .. })] => assert_eq!(ast.statements.len(), 1),
+        statements => panic!("Unexpected statements: {:?}", statements),
+    }
+}
+
+#[test]
+fn invalid_inline_assembly_is_an_error() {
+    let error = parse_source_unit(
+        "contract C { function f() external { assembly { let := } } }",
+        "C.sol",
+    ).unwrap_err();
+
+    assert!(error.to_string().contains("Invalid inline assembly"), "{}", error);
+}
+
+#[test]
+fn try_statement_id_is_allocated_before_its_children() {
+    let source_unit = parse_source_unit(
+        "interface I { function g() external returns (uint); } contract C { I i; function f() external { try i.g() returns (uint x) { x; } catch { } } }",
+        "C.sol",
+    ).unwrap();
+
+    match function_statements(&source_unit, "f") {
+        [Statement::TryStatement(try_statement)] => {
+            assert!(try_statement.id < try_statement.external_call.id);
+            assert!(try_statement.clauses.iter().all(|clause| try_statement.id < clause.id));
+        }
+
+        statements => panic!("Unexpected statements: {:?}", statements),
+    }
+}
+
+#[test]
+fn try_statements_are_displayed() {
+    let source_unit = parse_source_unit(
+        "interface I { function g() external returns (uint); } contract C { I i; function f() external { try i.g() returns (uint x) { x; } catch Error(string memory reason) { reason; } catch { } } }",
+        "C.sol",
+    ).unwrap();
+
+    match function_statements(&source_unit, "f") {
+        [statement @ Statement::TryStatement(_)] => assert_eq!(
+            statement.to_string(),
+            "try i.g() returns (uint x) {\n\tx;\n} catch Error(string memory reason) {\n\treason;\n} catch {\n}",
+        ),
+
+        statements => panic!("Unexpected statements: {:?}", statements),
+    }
+}
+
+#[test]
+fn function_type_names_are_displayed() {
+    let source_unit = parse_source_unit(
+        "contract C { function(uint) external view returns (bool) internal callback; }",
+        "C.sol",
+    ).unwrap();
+
+    let variable_declaration = source_unit.contract_definitions()[0].variable_declarations()[0];
+
+    assert_eq!(
+        variable_declaration.type_name.as_ref().unwrap().to_string(),
+        "function (uint) external view returns (bool)",
+    );
+}
+
+#[test]
+fn hex_number_without_digits_is_an_error() {
+    for source in ["contract C { uint x = 0x; }", "contract C { uint x = 0x_; }"] {
+        let error = parse_source_unit(source, "C.sol").unwrap_err();
+        assert!(error.to_string().contains("Invalid hex number literal"), "{}", error);
+    }
+
+    assert!(parse_source_unit("contract C { uint x = 0x1_f; }", "C.sol").is_ok());
+}
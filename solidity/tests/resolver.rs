@@ -0,0 +1,197 @@
+use eth_lang_utils::ast::*;
+use solidity::{
+    ast::*,
+    parser::{self, Parser},
+};
+
+fn identifier_declarations(source_units: &[SourceUnit], name: &str) -> Vec<NodeID> {
+    let ast_index = AstIndex::new(source_units);
+
+    let mut result: Vec<(NodeID, NodeID)> = ast_index.iter()
+        .filter_map(|(&id, _)| match ast_index.expression(id) {
+            Some(Expression::Identifier(identifier)) if identifier.name == name => Some((id, identifier.referenced_declaration)),
+            _ => None,
+        })
+        .collect();
+
+    result.sort();
+    result.into_iter().map(|(_, referenced_declaration)| referenced_declaration).collect()
+}
+
+fn state_variable_id(source_unit: &SourceUnit, name: &str) -> NodeID {
+    source_unit.contract_definitions()
+        .into_iter()
+        .flat_map(|contract_definition| contract_definition.variable_declarations())
+        .find(|variable_declaration| variable_declaration.name == name)
+        .map(|variable_declaration| variable_declaration.id)
+        .unwrap()
+}
+
+#[test]
+fn delete_does_not_shadow_the_deleted_state_variable() {
+    let source_unit = parser::parse_source_unit(
+        "contract C {
+            struct S { uint a; }
+            S s;
+            function f() external returns (uint) { delete s; return s.a; }
+        }",
+        "C.sol",
+    ).unwrap();
+
+    let s = state_variable_id(&source_unit, "s");
+    assert_eq!(identifier_declarations(&[source_unit], "s"), vec![s, s]);
+}
+
+#[test]
+fn local_variables_shadow_state_variables() {
+    let source_unit = parser::parse_source_unit(
+        "contract C {
+            uint x;
+            function f() external view returns (uint) { uint x = 1; return x; }
+            function g() external view returns (uint) { return x; }
+        }",
+        "C.sol",
+    ).unwrap();
+
+    let state_variable = state_variable_id(&source_unit, "x");
+
+    let local_variable = source_unit.contract_definitions()[0]
+        .function_definitions()
+        .into_iter()
+        .find(|function_definition| function_definition.name == "f")
+        .and_then(|function_definition| function_definition.body.as_ref())
+        .and_then(|body| match body.statements.first() {
+            Some(Statement::VariableDeclarationStatement(statement)) => statement.declarations[0].as_ref().map(|declaration| declaration.id),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_ne!(local_variable, state_variable);
+    assert_eq!(identifier_declarations(&[source_unit], "x"), vec![local_variable, state_variable]);
+}
+
+#[test]
+fn references_are_resolved_across_imported_source_units() {
+    let mut parser = Parser::default();
+
+    let mut source_units = vec![
+        parser.parse(
+            "import \"./B.sol\"; contract A is B { function g() external view returns (uint) { return LIMIT + f(); } }",
+            "contracts/A.sol",
+            0,
+        ).unwrap(),
+
+        parser.parse(
+            "uint constant LIMIT = 10; contract B { function f() internal pure returns (uint) { return 1; } }",
+            "contracts/B.sol",
+            1,
+        ).unwrap(),
+    ];
+
+    parser::resolve(source_units.as_mut_slice());
+
+    let b = &source_units[1];
+
+    let limit = b.nodes.iter()
+        .find_map(|node| match node {
+            SourceUnitNode::VariableDeclaration(variable_declaration) => Some(variable_declaration.id),
+            _ => None,
+        })
+        .unwrap();
+
+    let contract_b = b.contract_definitions()[0];
+    let f = contract_b.function_definitions()[0].id;
+
+    assert_eq!(source_units[0].import_directives()[0].source_unit, b.id);
+    assert_eq!(source_units[0].contract_definitions()[0].base_contracts[0].base_name.referenced_declaration, Some(contract_b.id));
+    assert_eq!(identifier_declarations(&source_units, "LIMIT"), vec![limit]);
+    assert_eq!(identifier_declarations(&source_units, "f"), vec![f]);
+}
+
+#[test]
+fn node_ids_are_unique_across_source_units() {
+    let mut parser = Parser::default();
+
+    let source_units = [
+        parser.parse("contract A { uint x; function f() external {} }", "A.sol", 0).unwrap(),
+        parser.parse("contract B { uint y; function g() external {} }", "B.sol", 1).unwrap(),
+    ];
+
+    let mut ids: Vec<NodeID> = source_units.iter()
+        .flat_map(|source_unit| {
+            let mut ids: Vec<NodeID> = source_unit.descendants().iter().filter_map(|node| node.id()).collect();
+            ids.push(source_unit.id);
+            ids
+        })
+        .collect();
+
+    let count = ids.len();
+    ids.sort();
+    ids.dedup();
+
+    assert_eq!(ids.len(), count);
+}
+
+#[test]
+fn ast_index_covers_the_same_nodes_as_children() {
+    let mut parser = Parser::default();
+
+    let source_unit = parser.parse(
+        r#"
+        pragma solidity ^0.8.0;
+        import "./B.sol";
+
+        type Price is uint128;
+        error Failed(uint code);
+
+        library L { function inc(uint x) internal pure returns (uint) { return x + 1; } }
+
+        contract Base { constructor(uint x) {} }
+
+        contract A is Base(1) {
+            using L for uint;
+
+            struct S { uint a; mapping(uint => uint) b; }
+            enum E { One, Two }
+            event Moved(address indexed from, uint amount);
+
+            uint[] values;
+            function(uint) external returns (uint) callback;
+
+            modifier only(uint x) { require(x > 0, "zero"); _; }
+
+            function f(uint x) external only(x) returns (uint y) {
+                uint[] memory local = new uint[](x);
+                (uint a, ) = (x, 2);
+                for (uint i = 0; i < x; i++) { if (i == 1) continue; else break; }
+                while (a > 0) { a--; }
+                do { a++; } while (a < 2);
+                unchecked { y = x.inc() + local.length + values[0:1].length; }
+                try this.f{gas: 1000}(x) returns (uint z) { y = z; } catch Error(string memory) { revert Failed(1); } catch {}
+                emit Moved(msg.sender, x > 1 ? x : 1);
+                assembly { let c := 1 }
+                delete values;
+                return y;
+            }
+        }
+        "#,
+        "A.sol",
+        0,
+    ).unwrap();
+
+    let source_units = [source_unit];
+    let ast_index = AstIndex::new(&source_units);
+
+    let mut indexed: Vec<NodeID> = ast_index.iter().map(|(&id, _)| id).collect();
+    indexed.sort();
+
+    let mut children: Vec<NodeID> = source_units[0].descendants()
+        .iter()
+        .filter(|node| !matches!(node.node_type(), "IdentifierPath" | "UserDefinedTypeName" | "OverrideSpecifier" | "StructuredDocumentation"))
+        .filter_map(|node| node.id())
+        .chain([source_units[0].id])
+        .collect();
+    children.sort();
+
+    assert_eq!(indexed, children);
+}
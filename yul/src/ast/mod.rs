@@ -1,5 +1,5 @@
 use eth_lang_utils::ast::*;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -19,19 +19,35 @@ pub struct ExternalReferenceData {
     value_size: NodeID,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum YulExpression {
     YulLiteral(YulLiteral),
     YulIdentifier(YulIdentifier),
     YulFunctionCall(YulFunctionCall),
 
-    #[serde(rename_all = "camelCase")]
-    UnhandledYulExpression {
-        node_type: String,
-        src: Option<String>,
-        id: Option<NodeID>,
-    },
+    UnhandledYulExpression(UnhandledNode),
+}
+
+impl<'de> Deserialize<'de> for YulExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("YulLiteral") => YulExpression::YulLiteral(Deserialize::deserialize(content)?),
+            Some("YulIdentifier") => YulExpression::YulIdentifier(Deserialize::deserialize(content)?),
+            Some("YulFunctionCall") => YulExpression::YulFunctionCall(Deserialize::deserialize(content)?),
+            _ => YulExpression::UnhandledYulExpression(Deserialize::deserialize(content)?),
+        }))
+    }
+}
+
+impl Serialize for YulExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            YulExpression::YulLiteral(node) => serialize_tagged(serializer, NodeType::YulLiteral, node),
+            YulExpression::YulIdentifier(node) => serialize_tagged(serializer, NodeType::YulIdentifier, node),
+            YulExpression::YulFunctionCall(node) => serialize_tagged(serializer, NodeType::YulFunctionCall, node),
+            YulExpression::UnhandledYulExpression(node) => node.serialize(serializer),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -40,6 +56,8 @@ pub struct YulLiteral {
     pub kind: YulLiteralKind,
     pub value: Option<String>,
     pub hex_value: Option<String>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -56,6 +74,8 @@ pub enum YulLiteralKind {
 #[serde(rename_all = "camelCase")]
 pub struct YulIdentifier {
     pub name: String,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -63,12 +83,16 @@ pub struct YulIdentifier {
 pub struct YulFunctionCall {
     pub function_name: YulIdentifier,
     pub arguments: Vec<YulExpression>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulBlock {
     pub statements: Vec<YulStatement>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 pub struct YulBlockContext<'a, 'b> {
@@ -76,21 +100,59 @@ pub struct YulBlockContext<'a, 'b> {
     pub yul_block: &'a YulBlock,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum YulStatement {
+    YulForLoop(YulForLoop),
     YulIf(YulIf),
     YulSwitch(YulSwitch),
     YulAssignment(YulAssignment),
     YulVariableDeclaration(YulVariableDeclaration),
     YulExpressionStatement(YulExpressionStatement),
+    YulFunctionDefinition(YulFunctionDefinition),
+    YulBlock(YulBlock),
+    YulBreak(YulBreak),
+    YulContinue(YulContinue),
+    YulLeave(YulLeave),
+
+    UnhandledYulStatement(UnhandledNode),
+}
+
+impl<'de> Deserialize<'de> for YulStatement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_tagged(deserializer, |node_type, content| Ok(match node_type {
+            Some("YulForLoop") => YulStatement::YulForLoop(Deserialize::deserialize(content)?),
+            Some("YulIf") => YulStatement::YulIf(Deserialize::deserialize(content)?),
+            Some("YulSwitch") => YulStatement::YulSwitch(Deserialize::deserialize(content)?),
+            Some("YulAssignment") => YulStatement::YulAssignment(Deserialize::deserialize(content)?),
+            Some("YulVariableDeclaration") => YulStatement::YulVariableDeclaration(Deserialize::deserialize(content)?),
+            Some("YulExpressionStatement") => YulStatement::YulExpressionStatement(Deserialize::deserialize(content)?),
+            Some("YulFunctionDefinition") => YulStatement::YulFunctionDefinition(Deserialize::deserialize(content)?),
+            Some("YulBlock") => YulStatement::YulBlock(Deserialize::deserialize(content)?),
+            Some("YulBreak") => YulStatement::YulBreak(Deserialize::deserialize(content)?),
+            Some("YulContinue") => YulStatement::YulContinue(Deserialize::deserialize(content)?),
+            Some("YulLeave") => YulStatement::YulLeave(Deserialize::deserialize(content)?),
+            _ => YulStatement::UnhandledYulStatement(Deserialize::deserialize(content)?),
+        }))
+    }
+}
 
-    #[serde(rename_all = "camelCase")]
-    UnhandledYulStatement {
-        node_type: String,
-        src: Option<String>,
-        id: Option<NodeID>,
-    },
+impl Serialize for YulStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            YulStatement::YulForLoop(node) => serialize_tagged(serializer, NodeType::YulForLoop, node),
+            YulStatement::YulIf(node) => serialize_tagged(serializer, NodeType::YulIf, node),
+            YulStatement::YulSwitch(node) => serialize_tagged(serializer, NodeType::YulSwitch, node),
+            YulStatement::YulAssignment(node) => serialize_tagged(serializer, NodeType::YulAssignment, node),
+            YulStatement::YulVariableDeclaration(node) => serialize_tagged(serializer, NodeType::YulVariableDeclaration, node),
+            YulStatement::YulExpressionStatement(node) => serialize_tagged(serializer, NodeType::YulExpressionStatement, node),
+            YulStatement::YulFunctionDefinition(node) => serialize_tagged(serializer, NodeType::YulFunctionDefinition, node),
+            YulStatement::YulBlock(node) => serialize_tagged(serializer, NodeType::YulBlock, node),
+            YulStatement::YulBreak(node) => serialize_tagged(serializer, NodeType::YulBreak, node),
+            YulStatement::YulContinue(node) => serialize_tagged(serializer, NodeType::YulContinue, node),
+            YulStatement::YulLeave(node) => serialize_tagged(serializer, NodeType::YulLeave, node),
+            YulStatement::UnhandledYulStatement(node) => node.serialize(serializer),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -98,6 +160,8 @@ pub enum YulStatement {
 pub struct YulIf {
     pub condition: YulExpression,
     pub body: YulBlock,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -105,13 +169,37 @@ pub struct YulIf {
 pub struct YulSwitch {
     pub cases: Vec<YulCase>,
     pub expression: YulExpression,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulCase {
     pub body: YulBlock,
-    pub value: YulExpression,
+    #[serde(deserialize_with = "deserialize_yul_case_value")]
+    pub value: Option<YulExpression>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+//
+// The value of a default case is serialized as the string "default"
+//
+
+fn deserialize_yul_case_value<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<YulExpression>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum YulCaseValue {
+        Default(String),
+        Value(YulExpression),
+    }
+
+    Ok(match YulCaseValue::deserialize(deserializer)? {
+        YulCaseValue::Default(value) if value == "default" => None,
+        YulCaseValue::Default(value) => return Err(D::Error::custom(format!("Invalid yul case value: {}", value))),
+        YulCaseValue::Value(value) => Some(value),
+    })
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -119,13 +207,17 @@ pub struct YulCase {
 pub struct YulAssignment {
     pub value: YulExpression,
     pub variable_names: Vec<YulIdentifier>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulVariableDeclaration {
-    pub value: YulExpression,
+    pub value: Option<YulExpression>,
     pub variables: Vec<YulTypedName>,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
@@ -133,10 +225,430 @@ pub struct YulVariableDeclaration {
 pub struct YulTypedName {
     pub r#type: String,
     pub name: String,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct YulExpressionStatement {
     pub expression: YulExpression,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulForLoop {
+    pub pre: YulBlock,
+    pub condition: YulExpression,
+    pub post: YulBlock,
+    pub body: YulBlock,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulFunctionDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub parameters: Vec<YulTypedName>,
+    #[serde(default)]
+    pub return_variables: Vec<YulTypedName>,
+    pub body: YulBlock,
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulBreak {
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulContinue {
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulLeave {
+    pub src: Option<String>,
+    pub native_src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulObject {
+    pub name: String,
+    pub code: YulBlock,
+    pub objects: Vec<YulObject>,
+    pub data: Vec<YulData>,
+    pub src: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct YulData {
+    pub name: String,
+    pub value: Vec<u8>,
+    pub src: Option<String>,
+}
+
+impl YulExpression {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            YulExpression::YulLiteral(node) => node,
+            YulExpression::YulIdentifier(node) => node,
+            YulExpression::YulFunctionCall(node) => node,
+            YulExpression::UnhandledYulExpression(node) => node,
+        }
+    }
+}
+
+impl AstNode for YulExpression {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl YulStatement {
+    fn as_ast_node(&self) -> &dyn AstNode {
+        match self {
+            YulStatement::YulForLoop(node) => node,
+            YulStatement::YulIf(node) => node,
+            YulStatement::YulSwitch(node) => node,
+            YulStatement::YulAssignment(node) => node,
+            YulStatement::YulVariableDeclaration(node) => node,
+            YulStatement::YulExpressionStatement(node) => node,
+            YulStatement::YulFunctionDefinition(node) => node,
+            YulStatement::YulBlock(node) => node,
+            YulStatement::YulBreak(node) => node,
+            YulStatement::YulContinue(node) => node,
+            YulStatement::YulLeave(node) => node,
+            YulStatement::UnhandledYulStatement(node) => node,
+        }
+    }
+}
+
+impl AstNode for YulStatement {
+    fn id(&self) -> Option<NodeID> {
+        self.as_ast_node().id()
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.as_ast_node().src()
+    }
+
+    fn node_type(&self) -> &str {
+        self.as_ast_node().node_type()
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.as_ast_node().children()
+    }
+}
+
+impl AstNode for YulLiteral {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulLiteral"
+    }
+}
+
+impl AstNode for YulIdentifier {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulIdentifier"
+    }
+}
+
+impl AstNode for YulFunctionCall {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulFunctionCall"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.function_name];
+        children.extend(self.arguments.iter().map(|argument| argument as &dyn AstNode));
+        children
+    }
+}
+
+impl AstNode for YulBlock {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulBlock"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        self.statements.iter().map(|statement| statement as &dyn AstNode).collect()
+    }
+}
+
+impl AstNode for YulIf {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulIf"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.condition, &self.body]
+    }
+}
+
+impl AstNode for YulSwitch {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulSwitch"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![&self.expression];
+        children.extend(self.cases.iter().map(|case| case as &dyn AstNode));
+        children
+    }
+}
+
+impl AstNode for YulCase {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulCase"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+
+        if let Some(value) = self.value.as_ref() {
+            children.push(value);
+        }
+
+        children.push(&self.body);
+        children
+    }
+}
+
+impl AstNode for YulAssignment {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulAssignment"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = self.variable_names.iter().map(|name| name as &dyn AstNode).collect();
+        children.push(&self.value);
+        children
+    }
+}
+
+impl AstNode for YulVariableDeclaration {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulVariableDeclaration"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = self.variables.iter().map(|variable| variable as &dyn AstNode).collect();
+
+        if let Some(value) = self.value.as_ref() {
+            children.push(value);
+        }
+
+        children
+    }
+}
+
+impl AstNode for YulTypedName {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulTypedName"
+    }
+}
+
+impl AstNode for YulExpressionStatement {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulExpressionStatement"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.expression]
+    }
+}
+
+impl AstNode for YulForLoop {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulForLoop"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        vec![&self.pre, &self.condition, &self.post, &self.body]
+    }
+}
+
+impl AstNode for YulFunctionDefinition {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulFunctionDefinition"
+    }
+
+    fn children(&self) -> Vec<&dyn AstNode> {
+        let mut children: Vec<&dyn AstNode> = vec![];
+        children.extend(self.parameters.iter().map(|parameter| parameter as &dyn AstNode));
+        children.extend(self.return_variables.iter().map(|return_variable| return_variable as &dyn AstNode));
+        children.push(&self.body);
+        children
+    }
+}
+
+impl AstNode for YulBreak {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulBreak"
+    }
+}
+
+impl AstNode for YulContinue {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulContinue"
+    }
+}
+
+impl AstNode for YulLeave {
+    fn id(&self) -> Option<NodeID> {
+        None
+    }
+
+    fn src(&self) -> Option<&str> {
+        self.src.as_deref()
+    }
+
+    fn node_type(&self) -> &str {
+        "YulLeave"
+    }
 }
@@ -0,0 +1,539 @@
+mod lexer;
+
+use self::lexer::{Lexer, Token, TokenKind};
+use crate::ast::*;
+use std::io;
+
+//
+// Parses the body of an assembly block, where `offset` is the position of `source` within its file
+//
+
+pub fn parse_block(source: &str, offset: usize, file_index: usize) -> io::Result<YulBlock> {
+    let mut parser = Parser::new(source, Some((offset, file_index)))?;
+    let block = parser.parse_block()?;
+    parser.expect_end_of_file()?;
+    Ok(block)
+}
+
+//
+// Parses the body of an assembly block whose text differs from its original source, such as the reformatted
+// `operations` of a legacy AST, so the parsed nodes have no source locations
+//
+
+pub fn parse_detached_block(source: &str) -> io::Result<YulBlock> {
+    let mut parser = Parser::new(source, None)?;
+    let block = parser.parse_block()?;
+    parser.expect_end_of_file()?;
+    Ok(block)
+}
+
+//
+// Parses a Yul object, or a single code block which is treated as an unnamed object
+//
+
+pub fn parse_object(source: &str, file_index: usize) -> io::Result<YulObject> {
+    let mut parser = Parser::new(source, Some((0, file_index)))?;
+
+    let object = if parser.at("object") {
+        parser.parse_object()?
+    } else {
+        let code = parser.parse_block()?;
+
+        YulObject {
+            name: String::new(),
+            src: code.src.clone(),
+            code,
+            objects: vec![],
+            data: vec![],
+        }
+    };
+
+    parser.expect_end_of_file()?;
+
+    Ok(object)
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    location: Option<(usize, usize)>,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str, location: Option<(usize, usize)>) -> io::Result<Self> {
+        Ok(Self {
+            source,
+            location,
+            tokens: Lexer::new(source).tokenize()?,
+            position: 0,
+        })
+    }
+
+    //
+    // Token helpers
+    //
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.position.min(self.tokens.len() - 1)]
+    }
+
+    fn peek(&self, offset: usize) -> &Token {
+        &self.tokens[(self.position + offset).min(self.tokens.len() - 1)]
+    }
+
+    fn at(&self, text: &str) -> bool {
+        let token = self.token();
+        matches!(token.kind, TokenKind::Identifier | TokenKind::Punctuation) && token.text == text
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.token().clone();
+
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        if self.at(text) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn previous_end(&self) -> usize {
+        match self.position {
+            0 => 0,
+            position => self.tokens[position - 1].end,
+        }
+    }
+
+    fn error(&self, message: String) -> io::Error {
+        let token = self.token();
+        let line = self.source[..token.start].chars().filter(|&c| c == '\n').count() + 1;
+        let column = token.start - self.source[..token.start].rfind('\n').map(|index| index + 1).unwrap_or(0) + 1;
+
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}:{}: {}", line, column, message))
+    }
+
+    fn unexpected(&self, expected: &str) -> io::Error {
+        let found = match self.token().kind {
+            TokenKind::EndOfFile => "end of file".to_string(),
+            _ => format!("'{}'", self.token().text),
+        };
+
+        self.error(format!("Expected {}, found {}", expected, found))
+    }
+
+    fn expect(&mut self, text: &str) -> io::Result<Token> {
+        if self.at(text) {
+            Ok(self.advance())
+        } else {
+            Err(self.unexpected(format!("'{}'", text).as_str()))
+        }
+    }
+
+    fn expect_identifier(&mut self) -> io::Result<Token> {
+        if self.token().kind == TokenKind::Identifier {
+            Ok(self.advance())
+        } else {
+            Err(self.unexpected("identifier"))
+        }
+    }
+
+    fn expect_string(&mut self) -> io::Result<Token> {
+        if self.token().kind == TokenKind::String {
+            Ok(self.advance())
+        } else {
+            Err(self.unexpected("string literal"))
+        }
+    }
+
+    fn expect_end_of_file(&self) -> io::Result<()> {
+        match self.token().kind {
+            TokenKind::EndOfFile => Ok(()),
+            _ => Err(self.unexpected("end of file")),
+        }
+    }
+
+    fn src(&self, start: usize) -> Option<String> {
+        let (offset, file_index) = self.location?;
+        Some(format!("{}:{}:{}", start + offset, self.previous_end().max(start) - start, file_index))
+    }
+
+    //
+    // Objects
+    //
+
+    fn parse_object(&mut self) -> io::Result<YulObject> {
+        let start = self.expect("object")?.start;
+        let name = String::from_utf8_lossy(self.expect_string()?.value.unwrap_or_default().as_slice()).to_string();
+
+        self.expect("{")?;
+        self.expect("code")?;
+
+        let code = self.parse_block()?;
+        let mut objects = vec![];
+        let mut data = vec![];
+
+        while !self.eat("}") {
+            if self.at("object") {
+                objects.push(self.parse_object()?);
+            } else if self.at("data") {
+                data.push(self.parse_data()?);
+            } else {
+                return Err(self.unexpected("'object', 'data' or '}'"));
+            }
+        }
+
+        Ok(YulObject {
+            name,
+            code,
+            objects,
+            data,
+            src: self.src(start),
+        })
+    }
+
+    fn parse_data(&mut self) -> io::Result<YulData> {
+        let start = self.expect("data")?.start;
+        let name = String::from_utf8_lossy(self.expect_string()?.value.unwrap_or_default().as_slice()).to_string();
+
+        let value = match self.token().kind {
+            TokenKind::String | TokenKind::HexString => self.advance().value.unwrap_or_default(),
+            _ => return Err(self.unexpected("string or hex literal")),
+        };
+
+        Ok(YulData {
+            name,
+            value,
+            src: self.src(start),
+        })
+    }
+
+    //
+    // Statements
+    //
+
+    fn parse_block(&mut self) -> io::Result<YulBlock> {
+        let start = self.expect("{")?.start;
+        let mut statements = vec![];
+
+        while !self.eat("}") {
+            if self.token().kind == TokenKind::EndOfFile {
+                return Err(self.unexpected("'}'"));
+            }
+
+            statements.push(self.parse_statement()?);
+        }
+
+        Ok(YulBlock {
+            statements,
+            src: self.src(start),
+            native_src: None,
+        })
+    }
+
+    fn parse_statement(&mut self) -> io::Result<YulStatement> {
+        let start = self.token().start;
+
+        match self.token().text.as_str() {
+            "{" => Ok(YulStatement::YulBlock(self.parse_block()?)),
+            "let" => self.parse_variable_declaration(),
+            "if" => self.parse_if(),
+            "switch" => self.parse_switch(),
+            "for" => self.parse_for_loop(),
+            "function" => self.parse_function_definition(),
+
+            "break" => {
+                self.advance();
+
+                Ok(YulStatement::YulBreak(YulBreak {
+                    src: self.src(start),
+                    native_src: None,
+                }))
+            }
+
+            "continue" => {
+                self.advance();
+
+                Ok(YulStatement::YulContinue(YulContinue {
+                    src: self.src(start),
+                    native_src: None,
+                }))
+            }
+
+            "leave" => {
+                self.advance();
+
+                Ok(YulStatement::YulLeave(YulLeave {
+                    src: self.src(start),
+                    native_src: None,
+                }))
+            }
+
+            _ if self.token().kind == TokenKind::Identifier && self.peek(1).text == "(" => {
+                Ok(YulStatement::YulExpressionStatement(YulExpressionStatement {
+                    expression: self.parse_expression()?,
+                    src: self.src(start),
+                    native_src: None,
+                }))
+            }
+
+            _ if self.token().kind == TokenKind::Identifier => self.parse_assignment(),
+
+            _ => Err(self.unexpected("statement")),
+        }
+    }
+
+    fn parse_typed_names(&mut self, terminator: &str) -> io::Result<Vec<YulTypedName>> {
+        let mut typed_names = vec![];
+
+        if self.at(terminator) {
+            return Ok(typed_names);
+        }
+
+        loop {
+            let name = self.expect_identifier()?;
+
+            let r#type = if self.eat(":") {
+                self.expect_identifier()?.text
+            } else {
+                String::new()
+            };
+
+            typed_names.push(YulTypedName {
+                r#type,
+                name: name.text,
+                src: self.src(name.start),
+                native_src: None,
+            });
+
+            if !self.eat(",") {
+                break;
+            }
+        }
+
+        Ok(typed_names)
+    }
+
+    fn parse_variable_declaration(&mut self) -> io::Result<YulStatement> {
+        let start = self.expect("let")?.start;
+        let variables = self.parse_typed_names(":=")?;
+
+        if variables.is_empty() {
+            return Err(self.unexpected("identifier"));
+        }
+
+        let value = if self.eat(":=") {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        Ok(YulStatement::YulVariableDeclaration(YulVariableDeclaration {
+            value,
+            variables,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_assignment(&mut self) -> io::Result<YulStatement> {
+        let start = self.token().start;
+        let mut variable_names = vec![];
+
+        loop {
+            variable_names.push(self.parse_identifier()?);
+
+            if !self.eat(",") {
+                break;
+            }
+        }
+
+        self.expect(":=")?;
+
+        Ok(YulStatement::YulAssignment(YulAssignment {
+            value: self.parse_expression()?,
+            variable_names,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_if(&mut self) -> io::Result<YulStatement> {
+        let start = self.expect("if")?.start;
+
+        Ok(YulStatement::YulIf(YulIf {
+            condition: self.parse_expression()?,
+            body: self.parse_block()?,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_for_loop(&mut self) -> io::Result<YulStatement> {
+        let start = self.expect("for")?.start;
+
+        Ok(YulStatement::YulForLoop(YulForLoop {
+            pre: self.parse_block()?,
+            condition: self.parse_expression()?,
+            post: self.parse_block()?,
+            body: self.parse_block()?,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_function_definition(&mut self) -> io::Result<YulStatement> {
+        let start = self.expect("function")?.start;
+        let name = self.expect_identifier()?.text;
+
+        self.expect("(")?;
+        let parameters = self.parse_typed_names(")")?;
+        self.expect(")")?;
+
+        let return_variables = if self.eat("->") {
+            self.parse_typed_names("{")?
+        } else {
+            vec![]
+        };
+
+        Ok(YulStatement::YulFunctionDefinition(YulFunctionDefinition {
+            name,
+            parameters,
+            return_variables,
+            body: self.parse_block()?,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_switch(&mut self) -> io::Result<YulStatement> {
+        let start = self.expect("switch")?.start;
+        let expression = self.parse_expression()?;
+        let mut cases = vec![];
+
+        loop {
+            let case_start = self.token().start;
+
+            let value = if self.eat("case") {
+                Some(self.parse_literal()?)
+            } else if self.eat("default") {
+                None
+            } else {
+                break;
+            };
+
+            cases.push(YulCase {
+                body: self.parse_block()?,
+                value,
+                src: self.src(case_start),
+                native_src: None,
+            });
+        }
+
+        if cases.is_empty() {
+            return Err(self.unexpected("'case' or 'default'"));
+        }
+
+        Ok(YulStatement::YulSwitch(YulSwitch {
+            cases,
+            expression,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    //
+    // Expressions
+    //
+
+    fn parse_identifier(&mut self) -> io::Result<YulIdentifier> {
+        let token = self.expect_identifier()?;
+
+        Ok(YulIdentifier {
+            name: token.text,
+            src: self.src(token.start),
+            native_src: None,
+        })
+    }
+
+    fn parse_expression(&mut self) -> io::Result<YulExpression> {
+        if self.token().kind != TokenKind::Identifier || self.at("true") || self.at("false") {
+            return self.parse_literal();
+        }
+
+        let start = self.token().start;
+        let function_name = self.parse_identifier()?;
+
+        if !self.eat("(") {
+            return Ok(YulExpression::YulIdentifier(function_name));
+        }
+
+        let mut arguments = vec![];
+
+        if !self.eat(")") {
+            loop {
+                arguments.push(self.parse_expression()?);
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+
+            self.expect(")")?;
+        }
+
+        Ok(YulExpression::YulFunctionCall(YulFunctionCall {
+            function_name,
+            arguments,
+            src: self.src(start),
+            native_src: None,
+        }))
+    }
+
+    fn parse_literal(&mut self) -> io::Result<YulExpression> {
+        let token = self.token().clone();
+
+        let (kind, value, hex_value) = match token.kind {
+            TokenKind::Number => (YulLiteralKind::Number, Some(token.text.clone()), None),
+
+            TokenKind::String | TokenKind::HexString => {
+                let bytes = token.value.clone().unwrap_or_default();
+                let value = String::from_utf8(bytes.clone()).ok();
+                (YulLiteralKind::String, value, Some(hex_encode(bytes.as_slice())))
+            }
+
+            TokenKind::Identifier if token.text == "true" || token.text == "false" => {
+                (YulLiteralKind::Bool, Some(token.text.clone()), None)
+            }
+
+            _ => return Err(self.unexpected("literal")),
+        };
+
+        self.advance();
+
+        if self.at(":") {
+            self.advance();
+            self.expect_identifier()?;
+        }
+
+        Ok(YulExpression::YulLiteral(YulLiteral {
+            kind,
+            value,
+            hex_value,
+            src: self.src(token.start),
+            native_src: None,
+        }))
+    }
+}
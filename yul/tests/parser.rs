@@ -0,0 +1,9 @@
+use yul::parser::parse_detached_block;
+
+#[test]
+fn hex_number_without_digits_is_an_error() {
+    let error = parse_detached_block("{ let x := 0x }").unwrap_err();
+    assert!(error.to_string().contains("Invalid hex number literal"), "{}", error);
+
+    assert!(parse_detached_block("{ let x := 0x1f }").is_ok());
+}